@@ -3,7 +3,7 @@ use url::Url;
 
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, ContractInfoResponse, Decimal, Deps, DepsMut, Empty, Env, Event,
-    MessageInfo, Response, StdError, StdResult, Storage, Timestamp, WasmQuery,
+    MessageInfo, Response, StdError, StdResult, Storage, Timestamp, Uint128, WasmQuery,
 };
 
 use cw721::{ContractInfoResponse as CW721ContractInfoResponse, Cw721Execute};
@@ -15,8 +15,11 @@ use sg721::{
     UpdateCollectionInfoMsg,
 };
 
-use crate::msg::{CollectionInfoResponse, NftParams, QueryMsg};
-use crate::{ContractError, Sg721Contract};
+use crate::msg::{
+    CheckRoyaltiesResponse, CollectionInfoResponse, Cw2981QueryMsg, NftParams, QueryMsg,
+    RoyaltiesInfoResponse,
+};
+use crate::{ContractError, Sg721Contract, Sg721ExecuteExtension};
 
 use crate::entry::{CONTRACT_NAME, CONTRACT_VERSION};
 
@@ -84,6 +87,7 @@ where
             external_link: msg.collection_info.external_link,
             explicit_content: msg.collection_info.explicit_content,
             start_trading_time: msg.collection_info.start_trading_time,
+            enforce_start_trading_time: msg.collection_info.enforce_start_trading_time,
             royalty_info,
         };
 
@@ -103,29 +107,50 @@ where
             .add_attribute("image", image.to_string()))
     }
 
-    pub fn execute(
+    pub fn execute<E>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg<T, E>,
+    ) -> Result<Response, E::Error>
+    where
+        E: Sg721ExecuteExtension<T>,
+    {
+        if let ExecuteMsg::Extension { msg } = msg {
+            return msg.execute_extension(self, deps, env, info);
+        }
+        self.execute_base(deps, env, info, msg)
+            .map_err(|e| e.into())
+    }
+
+    fn execute_base<E>(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: ExecuteMsg<T, Empty>,
+        msg: ExecuteMsg<T, E>,
     ) -> Result<Response, ContractError> {
         match msg {
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
-            } => self
-                .parent
-                .transfer_nft(deps, env, info, recipient, token_id)
-                .map_err(|e| e.into()),
+            } => {
+                self.assert_trading_started(deps.as_ref(), &env, &info.sender)?;
+                self.parent
+                    .transfer_nft(deps, env, info, recipient, token_id)
+                    .map_err(|e| e.into())
+            }
             ExecuteMsg::SendNft {
                 contract,
                 token_id,
                 msg,
-            } => self
-                .parent
-                .send_nft(deps, env, info, contract, token_id, msg)
-                .map_err(|e| e.into()),
+            } => {
+                self.assert_trading_started(deps.as_ref(), &env, &info.sender)?;
+                self.parent
+                    .send_nft(deps, env, info, contract, token_id, msg)
+                    .map_err(|e| e.into())
+            }
             ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -146,13 +171,14 @@ where
                 .parent
                 .revoke_all(deps, env, info, operator)
                 .map_err(|e| e.into()),
-            ExecuteMsg::Burn { token_id } => self
-                .parent
-                .burn(deps, env, info, token_id)
-                .map_err(|e| e.into()),
+            ExecuteMsg::Burn { token_id } => self.burn(deps, env, info, token_id),
             ExecuteMsg::UpdateCollectionInfo { collection_info } => {
                 self.update_collection_info(deps, env, info, collection_info)
             }
+            ExecuteMsg::UpdateTokenRoyaltyInfo {
+                token_id,
+                royalty_info,
+            } => self.update_token_royalty_info(deps, env, info, token_id, royalty_info),
             ExecuteMsg::UpdateStartTradingTime(start_time) => {
                 self.update_start_trading_time(deps, env, info, start_time)
             }
@@ -162,6 +188,7 @@ where
                 token_uri,
                 owner,
                 extension,
+                royalty_info,
             } => self.mint(
                 deps,
                 env,
@@ -171,9 +198,10 @@ where
                     owner,
                     token_uri,
                     extension,
+                    royalty_info,
                 },
             ),
-            ExecuteMsg::Extension { msg: _ } => todo!(),
+            ExecuteMsg::Extension { msg: _ } => Err(ContractError::UnsupportedExtension {}),
             sg721::ExecuteMsg::UpdateOwnership(msg) => self
                 .parent
                 .execute(
@@ -188,6 +216,29 @@ where
         }
     }
 
+    /// Collections enforcing their start trading time reject transfers and sends before it.
+    /// The minter can still move tokens, e.g. for airdrops.
+    fn assert_trading_started(
+        &self,
+        deps: Deps,
+        env: &Env,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        let collection = self.collection_info.load(deps.storage)?;
+        if collection.enforce_start_trading_time != Some(true) {
+            return Ok(());
+        }
+        match collection.start_trading_time {
+            Some(start_trading_time) if env.block.time < start_trading_time => {
+                if cw_ownable::is_owner(deps.storage, sender)? {
+                    return Ok(());
+                }
+                Err(ContractError::TradingNotStarted { start_trading_time })
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn update_collection_info(
         &self,
         deps: DepsMut,
@@ -232,6 +283,10 @@ where
 
         collection.explicit_content = collection_msg.explicit_content;
 
+        if let Some(enforce_start_trading_time) = collection_msg.enforce_start_trading_time {
+            collection.enforce_start_trading_time = Some(enforce_start_trading_time);
+        }
+
         if let Some(Some(new_royalty_info_response)) = collection_msg.royalty_info {
             let last_royalty_update = self.royalty_updated_at.load(deps.storage)?;
             if last_royalty_update.plus_seconds(24 * 60 * 60) > env.block.time {
@@ -248,20 +303,7 @@ where
             };
 
             if let Some(old_royalty_info) = collection.royalty_info {
-                if old_royalty_info.share < new_royalty_info.share {
-                    let share_delta = new_royalty_info.share.abs_diff(old_royalty_info.share);
-
-                    if share_delta > Decimal::percent(MAX_SHARE_DELTA_PCT) {
-                        return Err(ContractError::InvalidRoyalties(format!(
-                            "Share increase cannot be greater than {MAX_SHARE_DELTA_PCT}%"
-                        )));
-                    }
-                    if new_royalty_info.share > Decimal::percent(MAX_ROYALTY_SHARE_PCT) {
-                        return Err(ContractError::InvalidRoyalties(format!(
-                            "Share cannot be greater than {MAX_ROYALTY_SHARE_PCT}%"
-                        )));
-                    }
-                }
+                share_increase_validate(old_royalty_info.share, new_royalty_info.share)?;
             }
 
             collection.royalty_info = Some(new_royalty_info);
@@ -275,6 +317,77 @@ where
         Ok(Response::new().add_event(event))
     }
 
+    /// Overrides the collection royalty for a single token, or clears the override when
+    /// `royalty_info` is `None`. Follows the same rules as collection royalty updates.
+    pub fn update_token_royalty_info(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+        royalty_info: Option<RoyaltyInfoResponse>,
+    ) -> Result<Response, ContractError> {
+        let collection = self.collection_info.load(deps.storage)?;
+
+        if self.frozen_collection_info.load(deps.storage)? {
+            return Err(ContractError::CollectionInfoFrozen {});
+        }
+
+        // only creator can update token royalties
+        if collection.creator != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        // make sure the token exists
+        self.parent.tokens.load(deps.storage, &token_id)?;
+
+        if let Some(last_royalty_update) = self
+            .token_royalty_updated_at
+            .may_load(deps.storage, &token_id)?
+        {
+            if last_royalty_update.plus_seconds(24 * 60 * 60) > env.block.time {
+                return Err(ContractError::InvalidRoyalties(
+                    "Royalties can only be updated once per day".to_string(),
+                ));
+            }
+        }
+
+        let new_royalty_info = royalty_info
+            .map(|royalty_info| royalty_info_validate(deps.as_ref(), royalty_info))
+            .transpose()?;
+        if let Some(new_royalty_info) = &new_royalty_info {
+            token_share_validate(collection.royalty_info.as_ref(), new_royalty_info.share)?;
+        }
+
+        // the share increase is checked against the royalty that applies to the token now
+        let old_royalty_info = self
+            .token_royalties
+            .may_load(deps.storage, &token_id)?
+            .or(collection.royalty_info.clone());
+        let new_share = new_royalty_info
+            .as_ref()
+            .or(collection.royalty_info.as_ref())
+            .map(|royalty_info| royalty_info.share);
+        if let (Some(old_royalty_info), Some(new_share)) = (old_royalty_info, new_share) {
+            share_increase_validate(old_royalty_info.share, new_share)?;
+        }
+
+        match new_royalty_info {
+            Some(new_royalty_info) => {
+                self.token_royalties
+                    .save(deps.storage, &token_id, &new_royalty_info)?
+            }
+            None => self.token_royalties.remove(deps.storage, &token_id),
+        }
+        self.token_royalty_updated_at
+            .save(deps.storage, &token_id, &env.block.time)?;
+
+        let event = Event::new("update_token_royalty_info")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id);
+        Ok(Response::new().add_event(event))
+    }
+
     /// Called by the minter reply handler after custom validations on trading start time.
     /// Minter has start_time, default offset, makes sense to execute from minter.
     pub fn update_start_trading_time(
@@ -314,18 +427,19 @@ where
     pub fn mint(
         &self,
         deps: DepsMut,
-        _env: Env,
+        env: Env,
         info: MessageInfo,
         nft_data: NftParams<T>,
     ) -> Result<Response, ContractError> {
         assert_minter_owner(deps.storage, &info.sender)?;
-        let (token_id, owner, token_uri, extension) = match nft_data {
+        let (token_id, owner, token_uri, extension, royalty_info) = match nft_data {
             NftParams::NftData {
                 token_id,
                 owner,
                 token_uri,
                 extension,
-            } => (token_id, owner, token_uri, extension),
+                royalty_info,
+            } => (token_id, owner, token_uri, extension, royalty_info),
         };
 
         // create the token
@@ -344,6 +458,16 @@ where
 
         self.parent.increment_tokens(deps.storage)?;
 
+        if let Some(royalty_info) = royalty_info {
+            let royalty_info = royalty_info_validate(deps.as_ref(), royalty_info)?;
+            let collection = self.collection_info.load(deps.storage)?;
+            token_share_validate(collection.royalty_info.as_ref(), royalty_info.share)?;
+            self.token_royalties
+                .save(deps.storage, &token_id, &royalty_info)?;
+            self.token_royalty_updated_at
+                .save(deps.storage, &token_id, &env.block.time)?;
+        }
+
         let mut res = Response::new()
             .add_attribute("action", "mint")
             .add_attribute("minter", info.sender)
@@ -355,13 +479,63 @@ where
         Ok(res)
     }
 
+    pub fn burn(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+    ) -> Result<Response, ContractError> {
+        self.token_royalties.remove(deps.storage, &token_id);
+        self.token_royalty_updated_at
+            .remove(deps.storage, &token_id);
+        self.parent
+            .burn(deps, env, info, token_id)
+            .map_err(|e| e.into())
+    }
+
     pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
             QueryMsg::CollectionInfo {} => to_json_binary(&self.query_collection_info(deps)?),
+            QueryMsg::Extension {
+                msg:
+                    Cw2981QueryMsg::RoyaltyInfo {
+                        token_id,
+                        sale_price,
+                    },
+            } => to_json_binary(&self.query_royalty_info(deps, token_id, sale_price)?),
+            QueryMsg::Extension {
+                msg: Cw2981QueryMsg::CheckRoyalties {},
+            } => to_json_binary(&CheckRoyaltiesResponse {
+                royalty_payments: true,
+            }),
             _ => self.parent.query(deps, env, msg.into()),
         }
     }
 
+    pub fn query_royalty_info(
+        &self,
+        deps: Deps,
+        token_id: String,
+        sale_price: Uint128,
+    ) -> StdResult<RoyaltiesInfoResponse> {
+        let royalty_info = match self.token_royalties.may_load(deps.storage, &token_id)? {
+            Some(royalty_info) => Some(royalty_info),
+            None => self.collection_info.load(deps.storage)?.royalty_info,
+        };
+
+        Ok(match royalty_info {
+            Some(royalty_info) => RoyaltiesInfoResponse {
+                address: royalty_info.payment_address.to_string(),
+                royalty_amount: sale_price * royalty_info.share,
+            },
+            None => RoyaltiesInfoResponse {
+                address: String::new(),
+                royalty_amount: Uint128::zero(),
+            },
+        })
+    }
+
     pub fn query_collection_info(&self, deps: Deps) -> StdResult<CollectionInfoResponse> {
         let info = self.collection_info.load(deps.storage)?;
 
@@ -380,6 +554,7 @@ where
             external_link: info.external_link,
             explicit_content: info.explicit_content,
             start_trading_time: info.start_trading_time,
+            enforce_start_trading_time: info.enforce_start_trading_time,
             royalty_info: royalty_info_res,
         })
     }
@@ -433,6 +608,50 @@ pub fn share_validate(share: Decimal) -> Result<Decimal, ContractError> {
     Ok(share)
 }
 
+fn royalty_info_validate(
+    deps: Deps,
+    royalty_info: RoyaltyInfoResponse,
+) -> Result<RoyaltyInfo, ContractError> {
+    Ok(RoyaltyInfo {
+        payment_address: deps.api.addr_validate(&royalty_info.payment_address)?,
+        share: share_validate(royalty_info.share)?,
+    })
+}
+
+// A token royalty can't go above the maximum share, unless it stays within the collection royalty
+fn token_share_validate(
+    collection_royalty_info: Option<&RoyaltyInfo>,
+    share: Decimal,
+) -> Result<(), ContractError> {
+    let within_collection_share =
+        collection_royalty_info.is_some_and(|royalty_info| share <= royalty_info.share);
+    if share > Decimal::percent(MAX_ROYALTY_SHARE_PCT) && !within_collection_share {
+        return Err(ContractError::InvalidRoyalties(format!(
+            "Share cannot be greater than {MAX_ROYALTY_SHARE_PCT}%"
+        )));
+    }
+    Ok(())
+}
+
+fn share_increase_validate(old_share: Decimal, new_share: Decimal) -> Result<(), ContractError> {
+    if old_share < new_share {
+        let share_delta = new_share.abs_diff(old_share);
+
+        if share_delta > Decimal::percent(MAX_SHARE_DELTA_PCT) {
+            return Err(ContractError::InvalidRoyalties(format!(
+                "Share increase cannot be greater than {MAX_SHARE_DELTA_PCT}%"
+            )));
+        }
+        if new_share > Decimal::percent(MAX_ROYALTY_SHARE_PCT) {
+            return Err(ContractError::InvalidRoyalties(format!(
+                "Share cannot be greater than {MAX_ROYALTY_SHARE_PCT}%"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn get_owner_minter(storage: &mut dyn Storage) -> Result<Addr, ContractError> {
     let ownership = cw_ownable::get_ownership(storage)?;
     match ownership.owner {
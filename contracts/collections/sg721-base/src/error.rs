@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use cw_utils::PaymentError;
 use thiserror::Error;
 use url::ParseError;
@@ -44,9 +44,15 @@ pub enum ContractError {
     #[error("InvalidStartTradingTime")]
     InvalidStartTradingTime {},
 
+    #[error("Trading not started until {start_trading_time}")]
+    TradingNotStarted { start_trading_time: Timestamp },
+
     #[error("CollectionInfoFrozen")]
     CollectionInfoFrozen {},
 
+    #[error("UnsupportedExtension")]
+    UnsupportedExtension {},
+
     #[error("MinterNotFound")]
     MinterNotFound {},
 
@@ -0,0 +1,40 @@
+use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{ContractError, Sg721Contract};
+
+/// Extension messages of a collection built on `Sg721Contract`, the `E` in
+/// `sg721::ExecuteMsg<T, E>`. `Sg721Contract::execute` dispatches `ExecuteMsg::Extension`
+/// to the message itself.
+pub trait Sg721ExecuteExtension<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    type Error: From<ContractError>;
+
+    fn execute_extension(
+        self,
+        contract: &Sg721Contract<T>,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, Self::Error>;
+}
+
+/// Collections without extension messages
+impl<T> Sg721ExecuteExtension<T> for Empty
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    type Error = ContractError;
+
+    fn execute_extension(
+        self,
+        _contract: &Sg721Contract<T>,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        Err(ContractError::UnsupportedExtension {})
+    }
+}
@@ -1,10 +1,12 @@
 pub mod contract;
 mod error;
+mod extension;
 pub mod msg;
 mod state;
 pub mod upgrades;
 
 pub use crate::error::ContractError;
+pub use crate::extension::Sg721ExecuteExtension;
 pub use crate::state::Sg721Contract;
 use cosmwasm_std::Empty;
 use cw721_base::Extension;
@@ -58,6 +58,9 @@ pub enum ExecuteMsg<T, E> {
         token_uri: Option<String>,
         /// Any custom extension used by this contract
         extension: T,
+        /// Royalty override for this token, the collection royalty applies when not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
 
     /// Burn an NFT the sender has access to
@@ -120,6 +123,34 @@ pub enum QueryMsg {
     Minter {},
     #[returns(CollectionInfoResponse)]
     CollectionInfo {},
+    /// cw2981 royalty queries, wrapped like cw721 extension queries
+    #[returns(())]
+    Extension { msg: Cw2981QueryMsg },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum Cw2981QueryMsg {
+    /// Royalty owed on a sale of the token, from its override or the collection royalty
+    #[returns(RoyaltiesInfoResponse)]
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    /// Whether the contract implements royalties
+    #[returns(CheckRoyaltiesResponse)]
+    CheckRoyalties {},
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
 }
 
 impl From<QueryMsg> for Cw721QueryMsg<Empty> {
@@ -196,6 +227,8 @@ pub struct CollectionInfoResponse {
     pub external_link: Option<String>,
     pub explicit_content: Option<bool>,
     pub start_trading_time: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_start_trading_time: Option<bool>,
     pub royalty_info: Option<RoyaltyInfoResponse>,
 }
 
@@ -241,5 +274,6 @@ pub enum NftParams<T> {
         owner: String,
         token_uri: Option<String>,
         extension: T,
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
 }
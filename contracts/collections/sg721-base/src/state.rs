@@ -1,5 +1,5 @@
 use cosmwasm_std::{Empty, Timestamp};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use serde::{de::DeserializeOwned, Serialize};
 use sg721::{CollectionInfo, RoyaltyInfo};
 use std::ops::Deref;
@@ -15,6 +15,10 @@ where
     /// Instantiate set to false by the minter, then true by creator to freeze collection info
     pub frozen_collection_info: Item<'a, bool>,
     pub royalty_updated_at: Item<'a, Timestamp>,
+
+    /// Royalty overrides of single tokens, taking precedence over the collection royalty
+    pub token_royalties: Map<'a, &'a str, RoyaltyInfo>,
+    pub token_royalty_updated_at: Map<'a, &'a str, Timestamp>,
 }
 
 impl<T> Default for Sg721Contract<'_, T>
@@ -27,6 +31,8 @@ where
             collection_info: Item::new("collection_info"),
             frozen_collection_info: Item::new("frozen_collection_info"),
             royalty_updated_at: Item::new("royalty_updated_at"),
+            token_royalties: Map::new("token_royalties"),
+            token_royalty_updated_at: Map::new("token_royalty_updated_at"),
         }
     }
 }
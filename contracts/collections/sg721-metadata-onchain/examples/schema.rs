@@ -9,7 +9,8 @@ use cw721::{
 };
 use cw721_base::MinterResponse;
 use sg721_base::msg::CollectionInfoResponse;
-use sg721_metadata_onchain::{InstantiateMsg, QueryMsg};
+use sg721_metadata_onchain::msg::{EnableUpdatableResponse, FrozenTokenMetadataResponse};
+use sg721_metadata_onchain::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use sg_metadata::Metadata;
 
 fn main() {
@@ -19,6 +20,7 @@ fn main() {
     remove_schemas(&out_dir).unwrap();
 
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
+    export_schema_with_title(&schema_for!(ExecuteMsg), &out_dir, "ExecuteMsg");
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema_with_title(
         &schema_for!(AllNftInfoResponse<Metadata>),
@@ -45,4 +47,6 @@ fn main() {
     );
     export_schema_with_title(&schema_for!(TokensResponse), &out_dir, "AllTokensResponse");
     export_schema(&schema_for!(CollectionInfoResponse), &out_dir);
+    export_schema(&schema_for!(EnableUpdatableResponse), &out_dir);
+    export_schema(&schema_for!(FrozenTokenMetadataResponse), &out_dir);
 }
@@ -0,0 +1,222 @@
+use cosmwasm_std::{
+    to_json_string, Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult, Uint128,
+};
+use cw_utils::nonpayable;
+use sg1::{checked_fair_burn, query_minter_fee_routing};
+use sg721::ENABLE_UPDATABLE_FEE;
+use sg721_base::contract::get_owner_minter;
+use sg721_base::ContractError::Unauthorized;
+use sg721_base::Sg721ExecuteExtension;
+use sg_metadata::Metadata;
+
+use crate::error::ContractError;
+use crate::msg::{
+    EnableUpdatableResponse, FrozenTokenMetadataResponse, MetadataExtensionMsg, UpdateMetadataMsg,
+};
+use crate::state::{ENABLE_UPDATABLE, FROZEN_TOKENS, FROZEN_TOKEN_METADATA};
+use crate::Sg721MetadataContract;
+
+impl Sg721ExecuteExtension<Metadata> for MetadataExtensionMsg {
+    type Error = ContractError;
+
+    fn execute_extension(
+        self,
+        contract: &Sg721MetadataContract,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        match self {
+            MetadataExtensionMsg::EnableUpdatable {} => {
+                execute_enable_updatable(contract, deps, env, info)
+            }
+            MetadataExtensionMsg::FreezeTokenMetadata { token_ids } => {
+                execute_freeze_token_metadata(contract, deps, info, token_ids)
+            }
+            MetadataExtensionMsg::UpdateTokenMetadata { token_id, metadata } => {
+                execute_update_token_metadata(contract, deps, info, token_id, metadata)
+            }
+        }
+    }
+}
+
+fn only_creator(
+    contract: &Sg721MetadataContract,
+    deps: Deps,
+    info: &MessageInfo,
+) -> Result<(), ContractError> {
+    let collection_info = contract.query_collection_info(deps)?;
+    if info.sender != collection_info.creator {
+        return Err(ContractError::Base(Unauthorized {}));
+    }
+    Ok(())
+}
+
+pub fn execute_enable_updatable(
+    contract: &Sg721MetadataContract,
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // collections instantiated before on-chain updates existed have no flag stored
+    let enable_updates = ENABLE_UPDATABLE.may_load(deps.storage)?.unwrap_or(false);
+    if enable_updates {
+        return Err(ContractError::AlreadyEnableUpdatable {});
+    }
+    only_creator(contract, deps.as_ref(), &info)?;
+
+    // the fee is routed by the factory of the collection's minter, or by the default routing
+    // when the owner isn't a minter
+    let minter = get_owner_minter(deps.storage)?;
+    let fee_routing = query_minter_fee_routing(&deps.querier, minter).unwrap_or_default();
+    let mut res = Response::new();
+    checked_fair_burn(
+        &info,
+        &env,
+        ENABLE_UPDATABLE_FEE,
+        None,
+        &mut res,
+        &fee_routing,
+    )?;
+
+    ENABLE_UPDATABLE.save(deps.storage, &true)?;
+
+    Ok(res
+        .add_attribute("action", "enable_updates")
+        .add_attribute("enabled", "true"))
+}
+
+pub fn execute_freeze_token_metadata(
+    contract: &Sg721MetadataContract,
+    deps: DepsMut,
+    info: MessageInfo,
+    token_ids: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_creator(contract, deps.as_ref(), &info)?;
+
+    let Some(token_ids) = token_ids else {
+        FROZEN_TOKEN_METADATA.save(deps.storage, &true)?;
+        return Ok(Response::new()
+            .add_attribute("action", "freeze_token_metadata")
+            .add_attribute("frozen", "true"));
+    };
+
+    let mut event = Event::new("freeze_token_metadata").add_attribute("sender", info.sender);
+    for token_id in token_ids {
+        if !contract.parent.tokens.has(deps.storage, &token_id) {
+            return Err(ContractError::TokenIdNotFound {});
+        }
+        FROZEN_TOKENS.save(deps.storage, &token_id, &true)?;
+        event = event.add_attribute("token_id", token_id);
+    }
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_update_token_metadata(
+    contract: &Sg721MetadataContract,
+    deps: DepsMut,
+    info: MessageInfo,
+    token_id: String,
+    metadata: Box<UpdateMetadataMsg>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_creator(contract, deps.as_ref(), &info)?;
+
+    if !ENABLE_UPDATABLE.may_load(deps.storage)?.unwrap_or(false) {
+        return Err(ContractError::NotEnableUpdatable {});
+    }
+    if is_frozen(deps.as_ref(), &token_id)? {
+        return Err(ContractError::TokenMetadataFrozen {});
+    }
+
+    let mut token = contract
+        .parent
+        .tokens
+        .may_load(deps.storage, &token_id)?
+        .ok_or(ContractError::TokenIdNotFound {})?;
+
+    // every patched field is recorded so indexers can rebuild the token's history
+    let mut event = Event::new("update_token_metadata")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", &token_id);
+    let extension = &mut token.extension;
+    for (key, field, value) in [
+        ("image", &mut extension.image, metadata.image),
+        ("image_data", &mut extension.image_data, metadata.image_data),
+        (
+            "external_url",
+            &mut extension.external_url,
+            metadata.external_url,
+        ),
+        (
+            "description",
+            &mut extension.description,
+            metadata.description,
+        ),
+        ("name", &mut extension.name, metadata.name),
+        (
+            "background_color",
+            &mut extension.background_color,
+            metadata.background_color,
+        ),
+        (
+            "animation_url",
+            &mut extension.animation_url,
+            metadata.animation_url,
+        ),
+        (
+            "youtube_url",
+            &mut extension.youtube_url,
+            metadata.youtube_url,
+        ),
+    ] {
+        if let Some(value) = value {
+            event = event.add_attribute(key, value.clone().unwrap_or_default());
+            *field = value;
+        }
+    }
+    if let Some(attributes) = metadata.attributes {
+        event = event.add_attribute("attributes", to_json_string(&attributes)?);
+        extension.attributes = attributes;
+    }
+
+    contract
+        .parent
+        .tokens
+        .save(deps.storage, &token_id, &token)?;
+
+    Ok(Response::new().add_event(event))
+}
+
+fn is_frozen(deps: Deps, token_id: &str) -> StdResult<bool> {
+    if FROZEN_TOKEN_METADATA
+        .may_load(deps.storage)?
+        .unwrap_or(false)
+    {
+        return Ok(true);
+    }
+    Ok(FROZEN_TOKENS.has(deps.storage, token_id))
+}
+
+pub fn query_enable_updatable(deps: Deps) -> StdResult<EnableUpdatableResponse> {
+    let enabled = ENABLE_UPDATABLE.may_load(deps.storage)?.unwrap_or(false);
+    Ok(EnableUpdatableResponse { enabled })
+}
+
+pub fn query_enable_updatable_fee() -> StdResult<Uint128> {
+    Ok(Uint128::from(ENABLE_UPDATABLE_FEE))
+}
+
+pub fn query_frozen_token_metadata(
+    deps: Deps,
+    token_id: Option<String>,
+) -> StdResult<FrozenTokenMetadataResponse> {
+    let frozen = match token_id {
+        Some(token_id) => is_frozen(deps, &token_id)?,
+        None => FROZEN_TOKEN_METADATA
+            .may_load(deps.storage)?
+            .unwrap_or(false),
+    };
+    Ok(FrozenTokenMetadataResponse { frozen })
+}
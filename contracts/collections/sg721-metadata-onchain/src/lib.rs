@@ -1,12 +1,20 @@
 use cosmwasm_std::Empty;
 
-pub use sg721_base::ContractError;
 use sg_metadata::Metadata;
 
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
+
+use crate::msg::MetadataExtensionMsg;
+
 pub type Sg721MetadataContract<'a> = sg721_base::Sg721Contract<'a, Metadata>;
 pub type InstantiateMsg = sg721::InstantiateMsg;
-pub type ExecuteMsg = sg721::ExecuteMsg<Metadata, Empty>;
-pub type QueryMsg = sg721_base::msg::QueryMsg;
+pub type ExecuteMsg = sg721::ExecuteMsg<Metadata, MetadataExtensionMsg>;
+pub type QueryMsg = crate::msg::QueryMsg;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:sg721-metadata-onchain";
@@ -21,10 +29,15 @@ pub mod entry {
     use super::*;
 
     use cosmwasm_std::{
-        entry_point, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdError, StdResult,
+        entry_point, to_json_binary, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+        StdError, StdResult,
     };
     use semver::Version;
-    use sg721_base::{msg::QueryMsg, ContractError};
+
+    use crate::contract::{
+        query_enable_updatable, query_enable_updatable_fee, query_frozen_token_metadata,
+    };
+    use crate::state::{ENABLE_UPDATABLE, FROZEN_TOKENS, FROZEN_TOKEN_METADATA};
 
     #[entry_point]
     pub fn instantiate(
@@ -34,6 +47,9 @@ pub mod entry {
         msg: InstantiateMsg,
     ) -> Result<Response, ContractError> {
         cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        // metadata updates stay disabled until the creator pays the enable updatable fee
+        FROZEN_TOKEN_METADATA.save(deps.storage, &false)?;
+        ENABLE_UPDATABLE.save(deps.storage, &false)?;
 
         let res = Sg721MetadataContract::default().instantiate(deps, env, info, msg)?;
 
@@ -49,12 +65,22 @@ pub mod entry {
         info: MessageInfo,
         msg: ExecuteMsg,
     ) -> Result<Response, ContractError> {
+        if let ExecuteMsg::Burn { token_id } = &msg {
+            FROZEN_TOKENS.remove(deps.storage, token_id);
+        }
         Sg721MetadataContract::default().execute(deps, env, info, msg)
     }
 
     #[entry_point]
     pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-        Sg721MetadataContract::default().query(deps, env, msg)
+        match msg {
+            QueryMsg::EnableUpdatable {} => to_json_binary(&query_enable_updatable(deps)?),
+            QueryMsg::EnableUpdatableFee {} => to_json_binary(&query_enable_updatable_fee()?),
+            QueryMsg::FreezeTokenMetadata { token_id } => {
+                to_json_binary(&query_frozen_token_metadata(deps, token_id)?)
+            }
+            _ => Sg721MetadataContract::default().query(deps, env, msg.into()),
+        }
     }
 
     #[entry_point]
@@ -117,16 +143,40 @@ pub mod entry {
 mod tests {
     use super::*;
 
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::coins;
     use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
     use cosmwasm_std::{
         from_json, to_json_binary, ContractInfoResponse, ContractResult, Empty, OwnedDeps, Querier,
         QuerierResult, QueryRequest, SystemError, SystemResult, WasmQuery,
     };
     use cw721::Cw721Query;
-    use sg721::{CollectionInfo, ExecuteMsg, InstantiateMsg};
+    use sg1::FeeRouting;
+    use sg721::{CollectionInfo, InstantiateMsg};
+    use sg_metadata::Trait;
+    use sg_utils::NATIVE_DENOM;
+
+    use crate::contract::query_frozen_token_metadata;
+    use crate::msg::UpdateMetadataMsg;
     use std::marker::PhantomData;
 
     const CREATOR: &str = "creator";
+    const FACTORY: &str = "factory";
+
+    #[cw_serde]
+    struct MinterConfigResponse {
+        factory: String,
+    }
+
+    #[cw_serde]
+    struct FactoryParams {
+        fee_routing: FeeRouting,
+    }
+
+    #[cw_serde]
+    struct FactoryParamsResponse {
+        params: FactoryParams,
+    }
 
     pub fn mock_deps() -> OwnedDeps<MockStorage, MockApi, CustomMockQuerier, Empty> {
         OwnedDeps {
@@ -166,6 +216,21 @@ mod tests {
                     response.creator = CREATOR.to_string();
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
                 }
+                // the creator is also the minter, created by a factory with the default fee routing
+                QueryRequest::Wasm(WasmQuery::Smart { contract_addr, .. }) => {
+                    let response = if contract_addr == CREATOR {
+                        to_json_binary(&MinterConfigResponse {
+                            factory: FACTORY.to_string(),
+                        })
+                    } else {
+                        to_json_binary(&FactoryParamsResponse {
+                            params: FactoryParams {
+                                fee_routing: FeeRouting::default(),
+                            },
+                        })
+                    };
+                    SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+                }
                 _ => self.base.handle_query(request),
             }
         }
@@ -193,6 +258,7 @@ mod tests {
                 external_link: None,
                 explicit_content: Some(false),
                 start_trading_time: None,
+                enforce_start_trading_time: None,
                 royalty_info: None,
             },
         };
@@ -212,6 +278,7 @@ mod tests {
             owner: "john".to_string(),
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: extension.clone(),
+            royalty_info: None,
         };
 
         contract
@@ -229,4 +296,143 @@ mod tests {
         );
         assert_eq!(res.extension, extension);
     }
+
+    #[test]
+    fn update_onchain_metadata() {
+        let mut deps = mock_deps();
+        let contract = Sg721MetadataContract::default();
+
+        let info = mock_info(CREATOR, &[]);
+        let init_msg = InstantiateMsg {
+            name: "Heroes".to_string(),
+            symbol: "HERO".to_string(),
+            minter: CREATOR.to_string(),
+            collection_info: CollectionInfo {
+                creator: CREATOR.to_string(),
+                description: "this is a test".to_string(),
+                image: "https://larry.engineer".to_string(),
+                external_link: None,
+                explicit_content: Some(false),
+                start_trading_time: None,
+                enforce_start_trading_time: None,
+                royalty_info: None,
+            },
+        };
+        contract
+            .instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)
+            .unwrap();
+
+        let token_id = "1";
+        let mint_msg = ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: "john".to_string(),
+            token_uri: None,
+            extension: Metadata {
+                name: Some("Hero".to_string()),
+                description: Some("Level 1 hero".to_string()),
+                ..Metadata::default()
+            },
+            royalty_info: None,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), info.clone(), mint_msg)
+            .unwrap();
+
+        let level_up = ExecuteMsg::Extension {
+            msg: MetadataExtensionMsg::UpdateTokenMetadata {
+                token_id: token_id.to_string(),
+                metadata: Box::new(UpdateMetadataMsg {
+                    name: Some(Some("Hero Lv2".to_string())),
+                    description: Some(None),
+                    attributes: Some(Some(vec![Trait {
+                        display_type: None,
+                        trait_type: "level".to_string(),
+                        value: "2".to_string(),
+                    }])),
+                    ..UpdateMetadataMsg::default()
+                }),
+            },
+        };
+
+        // updates need the enable updatable fee to be paid first
+        let err = contract
+            .execute(deps.as_mut(), mock_env(), info.clone(), level_up.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::NotEnableUpdatable {}.to_string()
+        );
+
+        let enable_msg = ExecuteMsg::Extension {
+            msg: MetadataExtensionMsg::EnableUpdatable {},
+        };
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(CREATOR, &coins(1_500_000_000, NATIVE_DENOM)),
+                enable_msg,
+            )
+            .unwrap();
+
+        // only the creator can update
+        let err = contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("john", &[]),
+                level_up.clone(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            sg721_base::ContractError::Unauthorized {}.to_string()
+        );
+
+        let res = contract
+            .execute(deps.as_mut(), mock_env(), info.clone(), level_up.clone())
+            .unwrap();
+        let event = &res.events[0];
+        assert_eq!(event.ty, "update_token_metadata");
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "name" && attr.value == "Hero Lv2"));
+
+        // untouched fields are kept and cleared ones removed
+        let res = contract
+            .parent
+            .nft_info(deps.as_ref(), token_id.into())
+            .unwrap();
+        assert_eq!(res.extension.name, Some("Hero Lv2".to_string()));
+        assert_eq!(res.extension.description, None);
+        assert_eq!(res.extension.attributes.unwrap()[0].value, "2");
+
+        // a frozen token can no longer be updated
+        let freeze_msg = ExecuteMsg::Extension {
+            msg: MetadataExtensionMsg::FreezeTokenMetadata {
+                token_ids: Some(vec![token_id.to_string()]),
+            },
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), info.clone(), freeze_msg)
+            .unwrap();
+        assert!(
+            query_frozen_token_metadata(deps.as_ref(), Some(token_id.to_string()))
+                .unwrap()
+                .frozen
+        );
+        assert!(
+            !query_frozen_token_metadata(deps.as_ref(), None)
+                .unwrap()
+                .frozen
+        );
+        let err = contract
+            .execute(deps.as_mut(), mock_env(), info, level_up)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::TokenMetadataFrozen {}.to_string()
+        );
+    }
 }
@@ -0,0 +1,181 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NftInfoResponse,
+    NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+};
+use cw721_base::msg::MinterResponse;
+use cw_ownable::cw_ownable_query;
+use sg721_base::msg::{CollectionInfoResponse, Cw2981QueryMsg, QueryMsg as Sg721QueryMsg};
+use sg_metadata::{Metadata, Trait};
+
+#[cw_serde]
+pub enum MetadataExtensionMsg {
+    /// Enable updatable for updating token metadata, paid once by the creator
+    EnableUpdatable {},
+    /// Freeze the metadata of the given tokens, or of the whole collection when no ids are given
+    FreezeTokenMetadata { token_ids: Option<Vec<String>> },
+    /// Creator patches the on-chain metadata of a token
+    UpdateTokenMetadata {
+        token_id: String,
+        metadata: Box<UpdateMetadataMsg>,
+    },
+}
+
+/// Fields to patch on the token metadata. `None` keeps the current value,
+/// `Some(None)` clears it.
+#[cw_serde]
+#[derive(Default)]
+pub struct UpdateMetadataMsg {
+    pub image: Option<Option<String>>,
+    pub image_data: Option<Option<String>>,
+    pub external_url: Option<Option<String>>,
+    pub description: Option<Option<String>>,
+    pub name: Option<Option<String>>,
+    pub attributes: Option<Option<Vec<Trait>>>,
+    pub background_color: Option<Option<String>>,
+    pub animation_url: Option<Option<String>>,
+    pub youtube_url: Option<Option<String>>,
+}
+
+#[cw_ownable_query]
+#[derive(QueryResponses)]
+#[cw_serde]
+pub enum QueryMsg {
+    #[returns(EnableUpdatableResponse)]
+    EnableUpdatable {},
+    #[returns(Uint128)]
+    EnableUpdatableFee {},
+    /// Whether the collection, or the given token, has its metadata frozen
+    #[returns(FrozenTokenMetadataResponse)]
+    FreezeTokenMetadata { token_id: Option<String> },
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(ApprovalResponse)]
+    Approval {
+        token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(ApprovalsResponse)]
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(OperatorsResponse)]
+    AllOperators {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
+    #[returns(NftInfoResponse<Metadata>)]
+    NftInfo { token_id: String },
+    #[returns(AllNftInfoResponse<Metadata>)]
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TokensResponse)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(MinterResponse)]
+    Minter {},
+    #[returns(CollectionInfoResponse)]
+    CollectionInfo {},
+    #[returns(())]
+    Extension { msg: Cw2981QueryMsg },
+}
+
+impl From<QueryMsg> for Sg721QueryMsg {
+    fn from(msg: QueryMsg) -> Sg721QueryMsg {
+        match msg {
+            QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            } => Sg721QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            } => Sg721QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            },
+            QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            } => Sg721QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            } => Sg721QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            },
+            QueryMsg::NumTokens {} => Sg721QueryMsg::NumTokens {},
+            QueryMsg::ContractInfo {} => Sg721QueryMsg::ContractInfo {},
+            QueryMsg::NftInfo { token_id } => Sg721QueryMsg::NftInfo { token_id },
+            QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            } => Sg721QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            } => Sg721QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            },
+            QueryMsg::AllTokens { start_after, limit } => {
+                Sg721QueryMsg::AllTokens { start_after, limit }
+            }
+            QueryMsg::Minter {} => Sg721QueryMsg::Minter {},
+            QueryMsg::CollectionInfo {} => Sg721QueryMsg::CollectionInfo {},
+            QueryMsg::Extension { msg } => Sg721QueryMsg::Extension { msg },
+            QueryMsg::Ownership {} => Sg721QueryMsg::Ownership {},
+            _ => unreachable!("cannot convert {:?} to Sg721QueryMsg", msg),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct EnableUpdatableResponse {
+    pub enabled: bool,
+}
+
+#[cw_serde]
+pub struct FrozenTokenMetadataResponse {
+    pub frozen: bool,
+}
@@ -0,0 +1,6 @@
+use cw_storage_plus::{Item, Map};
+
+pub const FROZEN_TOKEN_METADATA: Item<bool> = Item::new("frozen_token_metadata");
+pub const ENABLE_UPDATABLE: Item<bool> = Item::new("enable_updatable");
+/// Tokens frozen individually, on top of the collection wide `FROZEN_TOKEN_METADATA` flag
+pub const FROZEN_TOKENS: Map<&str, bool> = Map::new("frozen_tokens");
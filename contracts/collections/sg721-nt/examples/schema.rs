@@ -11,6 +11,10 @@ use cw721::{
 };
 use cw721_base::MinterResponse;
 use sg721::InstantiateMsg;
+use sg721_nt::msg::{
+    ExecuteMsg, QueryMsg, RevocationResponse, SoulboundExtensionMsg, TokenExpirationResponse,
+};
+use sg721_nt::state::SoulboundPolicy;
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -20,6 +24,12 @@ fn main() {
 
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(Cw721QueryMsg), &out_dir);
+    export_schema_with_title(
+        &schema_for!(ExecuteMsg<Empty, SoulboundExtensionMsg>),
+        &out_dir,
+        "ExecuteMsg",
+    );
+    export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema_with_title(
         &schema_for!(AllNftInfoResponse<Empty>),
         &out_dir,
@@ -44,4 +54,7 @@ fn main() {
     export_schema(&schema_for!(NumTokensResponse), &out_dir);
     export_schema(&schema_for!(OwnerOfResponse), &out_dir);
     export_schema(&schema_for!(TokensResponse), &out_dir);
+    export_schema(&schema_for!(RevocationResponse), &out_dir);
+    export_schema(&schema_for!(TokenExpirationResponse), &out_dir);
+    export_schema(&schema_for!(SoulboundPolicy), &out_dir);
 }
@@ -0,0 +1,290 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdError, StdResult,
+    Timestamp,
+};
+use cw721_base::Extension;
+use sg721_base::contract::assert_minter_owner;
+use sg721_base::msg::NftParams;
+use sg721_base::Sg721ExecuteExtension;
+
+use crate::error::ContractError;
+use crate::msg::{QueryMsg, RevocationResponse, SoulboundExtensionMsg, TokenExpirationResponse};
+use crate::state::{
+    Revocation, SoulboundPolicy, RECOVERED_TOKENS, REVOCATIONS, SOULBOUND_POLICY, TOKEN_EXPIRATIONS,
+};
+use crate::Sg721NonTransferableContract;
+
+const MAX_REASON_LENGTH: usize = 256;
+
+impl Sg721ExecuteExtension<Extension> for SoulboundExtensionMsg {
+    type Error = ContractError;
+
+    fn execute_extension(
+        self,
+        _contract: &Sg721NonTransferableContract,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        match self {
+            SoulboundExtensionMsg::RevokeToken { token_id, reason } => {
+                execute_revoke_token(deps, env, info, token_id, reason)
+            }
+            SoulboundExtensionMsg::RecoverToken {
+                token_id,
+                recipient,
+            } => execute_recover_token(deps, env, info, token_id, recipient),
+            SoulboundExtensionMsg::SetTokenExpiration { token_id, expires } => {
+                execute_set_token_expiration(deps, env, info, token_id, expires)
+            }
+            SoulboundExtensionMsg::UpdateSoulboundPolicy { allow_recovery } => {
+                execute_update_soulbound_policy(deps, env, info, allow_recovery)
+            }
+        }
+    }
+}
+
+/// Tokens are issued by the collection minter or creator
+fn assert_issuer(deps: DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+    let collection_info =
+        Sg721NonTransferableContract::default().query_collection_info(deps.as_ref())?;
+    if info.sender == collection_info.creator {
+        return Ok(());
+    }
+    assert_minter_owner(deps.storage, &info.sender)?;
+    Ok(())
+}
+
+pub fn execute_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    nft_data: NftParams<Extension>,
+    expires: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let NftParams::NftData { ref token_id, .. } = nft_data;
+    let token_id = token_id.clone();
+    if let Some(expires) = expires {
+        if expires <= env.block.time {
+            return Err(ContractError::InvalidExpiration {});
+        }
+        TOKEN_EXPIRATIONS.save(deps.storage, &token_id, &expires)?;
+    }
+    let res = Sg721NonTransferableContract::default().mint(deps, env, info, nft_data)?;
+    Ok(match expires {
+        Some(expires) => res.add_attribute("expires", expires.to_string()),
+        None => res,
+    })
+}
+
+pub fn execute_burn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    TOKEN_EXPIRATIONS.remove(deps.storage, &token_id);
+    RECOVERED_TOKENS.remove(deps.storage, &token_id);
+    Ok(Sg721NonTransferableContract::default().burn(deps, env, info, token_id)?)
+}
+
+pub fn execute_revoke_token(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    assert_issuer(deps.branch(), &info)?;
+    if reason.is_empty() || reason.len() > MAX_REASON_LENGTH {
+        return Err(ContractError::InvalidRevocationReason {
+            max: MAX_REASON_LENGTH,
+        });
+    }
+
+    let contract = Sg721NonTransferableContract::default();
+    let token = contract
+        .parent
+        .tokens
+        .may_load(deps.storage, &token_id)?
+        .ok_or(ContractError::TokenIdNotFound {})?;
+
+    contract.parent.tokens.remove(deps.storage, &token_id)?;
+    contract.parent.decrement_tokens(deps.storage)?;
+    contract.token_royalties.remove(deps.storage, &token_id);
+    contract
+        .token_royalty_updated_at
+        .remove(deps.storage, &token_id);
+    TOKEN_EXPIRATIONS.remove(deps.storage, &token_id);
+    RECOVERED_TOKENS.remove(deps.storage, &token_id);
+
+    let revocation = Revocation {
+        reason,
+        revoked_by: info.sender,
+        revoked_at: env.block.time,
+    };
+    REVOCATIONS.save(deps.storage, &token_id, &revocation)?;
+
+    let event = Event::new("revoke_token")
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", token.owner)
+        .add_attribute("revoked_by", revocation.revoked_by)
+        .add_attribute("reason", revocation.reason);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_recover_token(
+    mut deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    assert_issuer(deps.branch(), &info)?;
+    let policy = SOULBOUND_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    if !policy.allow_recovery {
+        return Err(ContractError::RecoveryNotAllowed {});
+    }
+    if RECOVERED_TOKENS.has(deps.storage, &token_id) {
+        return Err(ContractError::AlreadyRecovered { token_id });
+    }
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let tokens = Sg721NonTransferableContract::default().parent.tokens;
+    let mut token = tokens
+        .may_load(deps.storage, &token_id)?
+        .ok_or(ContractError::TokenIdNotFound {})?;
+    let previous_owner = token.owner;
+    token.owner = recipient.clone();
+    token.approvals = vec![];
+    tokens.save(deps.storage, &token_id, &token)?;
+    RECOVERED_TOKENS.save(deps.storage, &token_id, &true)?;
+
+    let event = Event::new("recover_token")
+        .add_attribute("token_id", token_id)
+        .add_attribute("sender", info.sender)
+        .add_attribute("from", previous_owner)
+        .add_attribute("to", recipient);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_set_token_expiration(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    expires: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    assert_issuer(deps.branch(), &info)?;
+    if !Sg721NonTransferableContract::default()
+        .parent
+        .tokens
+        .has(deps.storage, &token_id)
+    {
+        return Err(ContractError::TokenIdNotFound {});
+    }
+
+    let mut event = Event::new("set_token_expiration")
+        .add_attribute("token_id", &token_id)
+        .add_attribute("sender", info.sender);
+    match expires {
+        Some(expires) => {
+            if expires <= env.block.time {
+                return Err(ContractError::InvalidExpiration {});
+            }
+            TOKEN_EXPIRATIONS.save(deps.storage, &token_id, &expires)?;
+            event = event.add_attribute("expires", expires.to_string());
+        }
+        None => TOKEN_EXPIRATIONS.remove(deps.storage, &token_id),
+    }
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_update_soulbound_policy(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    allow_recovery: bool,
+) -> Result<Response, ContractError> {
+    let contract = Sg721NonTransferableContract::default();
+    let collection_info = contract.query_collection_info(deps.as_ref())?;
+    if info.sender != collection_info.creator {
+        return Err(sg721_base::ContractError::Unauthorized {}.into());
+    }
+    if contract.frozen_collection_info.load(deps.storage)? {
+        return Err(sg721_base::ContractError::CollectionInfoFrozen {}.into());
+    }
+
+    SOULBOUND_POLICY.save(deps.storage, &SoulboundPolicy { allow_recovery })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_soulbound_policy")
+        .add_attribute("allow_recovery", allow_recovery.to_string()))
+}
+
+fn is_expired(deps: Deps, env: &Env, token_id: &str) -> StdResult<bool> {
+    Ok(TOKEN_EXPIRATIONS
+        .may_load(deps.storage, token_id)?
+        .is_some_and(|expires| expires <= env.block.time))
+}
+
+/// Expired tokens are reported as such unless `include_expired_token` is set
+fn assert_not_expired(
+    deps: Deps,
+    env: &Env,
+    token_id: &str,
+    include_expired_token: Option<bool>,
+) -> StdResult<()> {
+    if !include_expired_token.unwrap_or(false) && is_expired(deps, env, token_id)? {
+        return Err(StdError::generic_err(format!("Token {token_id} expired")));
+    }
+    Ok(())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Revocation { token_id } => to_json_binary(&query_revocation(deps, token_id)?),
+        QueryMsg::TokenExpiration { token_id } => {
+            to_json_binary(&query_token_expiration(deps, &env, token_id)?)
+        }
+        QueryMsg::SoulboundPolicy {} => to_json_binary(&query_soulbound_policy(deps)?),
+        QueryMsg::OwnerOf {
+            ref token_id,
+            include_expired_token,
+            ..
+        }
+        | QueryMsg::NftInfo {
+            ref token_id,
+            include_expired_token,
+            ..
+        }
+        | QueryMsg::AllNftInfo {
+            ref token_id,
+            include_expired_token,
+            ..
+        } => {
+            assert_not_expired(deps, &env, token_id, include_expired_token)?;
+            Sg721NonTransferableContract::default().query(deps, env, msg.into())
+        }
+        _ => Sg721NonTransferableContract::default().query(deps, env, msg.into()),
+    }
+}
+
+pub fn query_revocation(deps: Deps, token_id: String) -> StdResult<RevocationResponse> {
+    let revocation = REVOCATIONS.may_load(deps.storage, &token_id)?;
+    Ok(RevocationResponse { revocation })
+}
+
+pub fn query_token_expiration(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+) -> StdResult<TokenExpirationResponse> {
+    let expires = TOKEN_EXPIRATIONS.may_load(deps.storage, &token_id)?;
+    let expired = is_expired(deps, env, &token_id)?;
+    Ok(TokenExpirationResponse { expires, expired })
+}
+
+pub fn query_soulbound_policy(deps: Deps) -> StdResult<SoulboundPolicy> {
+    Ok(SOULBOUND_POLICY.may_load(deps.storage)?.unwrap_or_default())
+}
@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Base(#[from] sg721_base::ContractError),
+
+    #[error("TokenIdNotFound")]
+    TokenIdNotFound {},
+
+    #[error("Revocation reason must be between 1 and {max} characters")]
+    InvalidRevocationReason { max: usize },
+
+    #[error("RecoveryNotAllowed")]
+    RecoveryNotAllowed {},
+
+    #[error("Token {token_id} has already been recovered")]
+    AlreadyRecovered { token_id: String },
+
+    #[error("Expiration must be in the future")]
+    InvalidExpiration {},
+}
@@ -1,11 +1,15 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 
+pub mod contract;
+pub mod error;
 pub mod msg;
+pub mod state;
+pub use crate::error::ContractError;
+pub use crate::msg::QueryMsg;
 use cw721_base::Extension;
 use sg721::InstantiateMsg;
 use sg721_base::Sg721Contract;
-pub type QueryMsg = sg721_base::msg::QueryMsg;
 pub type Sg721NonTransferableContract<'a> = Sg721Contract<'a, Extension>;
 use sg721_base::msg::NftParams;
 
@@ -20,12 +24,12 @@ pub const TO_VERSION: &str = "3.0.0";
 pub mod entry {
     use super::*;
 
-    use crate::msg::ExecuteMsg;
+    use crate::contract::{execute_burn, execute_mint};
+    use crate::msg::{ExecuteMsg, SoulboundExtensionMsg};
+    use crate::state::{SoulboundPolicy, SOULBOUND_POLICY};
     use cosmwasm_std::{
         Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult,
     };
-    use cw721::Cw721Execute;
-    use sg721_base::ContractError;
 
     #[entry_point]
     pub fn instantiate(
@@ -35,6 +39,7 @@ pub mod entry {
         msg: InstantiateMsg,
     ) -> Result<Response, ContractError> {
         cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        SOULBOUND_POLICY.save(deps.storage, &SoulboundPolicy::default())?;
 
         let res = Sg721NonTransferableContract::default().instantiate(deps, env, info, msg)?;
 
@@ -48,19 +53,18 @@ pub mod entry {
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: ExecuteMsg<Extension>,
-    ) -> Result<Response, sg721_base::ContractError> {
+        msg: ExecuteMsg<Extension, SoulboundExtensionMsg>,
+    ) -> Result<Response, ContractError> {
         match msg {
-            ExecuteMsg::Burn { token_id } => Sg721NonTransferableContract::default()
-                .parent
-                .burn(deps, env, info, token_id)
-                .map_err(|e| e.into()),
+            ExecuteMsg::Burn { token_id } => execute_burn(deps, env, info, token_id),
             ExecuteMsg::Mint {
                 token_id,
                 token_uri,
                 owner,
                 extension,
-            } => Sg721NonTransferableContract::default().mint(
+                royalty_info,
+                expires,
+            } => execute_mint(
                 deps,
                 env,
                 info,
@@ -69,25 +73,17 @@ pub mod entry {
                     owner,
                     token_uri,
                     extension,
+                    royalty_info,
                 },
+                expires,
             ),
-            ExecuteMsg::UpdateCollectionInfo {
-                new_collection_info,
-            } => Sg721NonTransferableContract::default().update_collection_info(
-                deps,
-                env,
-                info,
-                new_collection_info,
-            ),
-            ExecuteMsg::FreezeCollectionInfo {} => {
-                Sg721NonTransferableContract::default().freeze_collection_info(deps, env, info)
-            }
+            _ => Sg721NonTransferableContract::default().execute(deps, env, info, msg.into()),
         }
     }
 
     #[entry_point]
     pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-        Sg721NonTransferableContract::default().query(deps, env, msg)
+        crate::contract::query(deps, env, msg)
     }
 
     #[entry_point]
@@ -1,10 +1,21 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Empty, Timestamp};
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NftInfoResponse,
+    NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+};
+use cw721_base::msg::MinterResponse;
+use cw_ownable::cw_ownable_query;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sg721::{RoyaltyInfoResponse, UpdateCollectionInfoMsg};
+use sg721::{ExecuteMsg as Sg721ExecuteMsg, RoyaltyInfoResponse, UpdateCollectionInfoMsg};
+use sg721_base::msg::{CollectionInfoResponse, Cw2981QueryMsg, QueryMsg as Sg721QueryMsg};
+
+use crate::state::{Revocation, SoulboundPolicy};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub enum ExecuteMsg<T> {
+pub enum ExecuteMsg<T, E> {
     /// Mint a new NFT, can only be called by the contract minter
     Mint {
         /// Unique ID of the NFT
@@ -17,6 +28,12 @@ pub enum ExecuteMsg<T> {
         token_uri: Option<String>,
         /// Any custom extension used by this contract
         extension: T,
+        /// Royalty override for this token, the collection royalty applies when not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
+        /// Time after which the token is reported as expired
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expires: Option<Timestamp>,
     },
     /// Burn an NFT the sender has access to
     Burn { token_id: String },
@@ -26,4 +43,211 @@ pub enum ExecuteMsg<T> {
     },
     /// Freeze collection info from further updates
     FreezeCollectionInfo {},
+    /// Extension msg
+    Extension { msg: E },
+}
+
+/// Extension messages of soulbound collections
+#[cw_serde]
+pub enum SoulboundExtensionMsg {
+    /// Issuer (minter or creator) burns a token, recording the reason on-chain
+    RevokeToken { token_id: String, reason: String },
+    /// Issuer moves a token to a new owner, e.g. after a lost wallet.
+    /// Allowed once per token when the soulbound policy enables recovery.
+    RecoverToken { token_id: String, recipient: String },
+    /// Issuer sets or clears the expiration of a token
+    SetTokenExpiration {
+        token_id: String,
+        expires: Option<Timestamp>,
+    },
+    /// Creator updates the soulbound policy until collection info is frozen
+    UpdateSoulboundPolicy { allow_recovery: bool },
+}
+
+impl<T, E> From<ExecuteMsg<T, E>> for Sg721ExecuteMsg<T, E> {
+    fn from(msg: ExecuteMsg<T, E>) -> Sg721ExecuteMsg<T, E> {
+        match msg {
+            ExecuteMsg::Mint {
+                token_id,
+                owner,
+                token_uri,
+                extension,
+                royalty_info,
+                ..
+            } => Sg721ExecuteMsg::Mint {
+                token_id,
+                owner,
+                token_uri,
+                extension,
+                royalty_info,
+            },
+            ExecuteMsg::Burn { token_id } => Sg721ExecuteMsg::Burn { token_id },
+            ExecuteMsg::UpdateCollectionInfo {
+                new_collection_info,
+            } => Sg721ExecuteMsg::UpdateCollectionInfo {
+                collection_info: new_collection_info,
+            },
+            ExecuteMsg::FreezeCollectionInfo {} => Sg721ExecuteMsg::FreezeCollectionInfo,
+            ExecuteMsg::Extension { msg } => Sg721ExecuteMsg::Extension { msg },
+        }
+    }
+}
+
+#[cw_ownable_query]
+#[derive(QueryResponses)]
+#[cw_serde]
+pub enum QueryMsg {
+    /// Fails for expired tokens unless `include_expired_token` is set
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+        /// Also return expired tokens. `include_expired` only applies to approvals.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        include_expired_token: Option<bool>,
+    },
+    #[returns(ApprovalResponse)]
+    Approval {
+        token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(ApprovalsResponse)]
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(OperatorsResponse)]
+    AllOperators {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
+    /// Fails for expired tokens unless `include_expired_token` is set
+    #[returns(NftInfoResponse<Empty>)]
+    NftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+        /// Also return expired tokens. `include_expired` only applies to approvals.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        include_expired_token: Option<bool>,
+    },
+    /// Fails for expired tokens unless `include_expired_token` is set
+    #[returns(AllNftInfoResponse<Empty>)]
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+        /// Also return expired tokens. `include_expired` only applies to approvals.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        include_expired_token: Option<bool>,
+    },
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TokensResponse)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(MinterResponse)]
+    Minter {},
+    #[returns(CollectionInfoResponse)]
+    CollectionInfo {},
+    #[returns(())]
+    Extension { msg: Cw2981QueryMsg },
+    #[returns(RevocationResponse)]
+    Revocation { token_id: String },
+    #[returns(TokenExpirationResponse)]
+    TokenExpiration { token_id: String },
+    #[returns(SoulboundPolicy)]
+    SoulboundPolicy {},
+}
+
+impl From<QueryMsg> for Sg721QueryMsg {
+    fn from(msg: QueryMsg) -> Sg721QueryMsg {
+        match msg {
+            QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+                ..
+            } => Sg721QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            } => Sg721QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            },
+            QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            } => Sg721QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            } => Sg721QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            },
+            QueryMsg::NumTokens {} => Sg721QueryMsg::NumTokens {},
+            QueryMsg::ContractInfo {} => Sg721QueryMsg::ContractInfo {},
+            QueryMsg::NftInfo { token_id, .. } => Sg721QueryMsg::NftInfo { token_id },
+            QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+                ..
+            } => Sg721QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            },
+            QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            } => Sg721QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            },
+            QueryMsg::AllTokens { start_after, limit } => {
+                Sg721QueryMsg::AllTokens { start_after, limit }
+            }
+            QueryMsg::Minter {} => Sg721QueryMsg::Minter {},
+            QueryMsg::CollectionInfo {} => Sg721QueryMsg::CollectionInfo {},
+            QueryMsg::Extension { msg } => Sg721QueryMsg::Extension { msg },
+            QueryMsg::Ownership {} => Sg721QueryMsg::Ownership {},
+            _ => unreachable!("cannot convert {:?} to Sg721QueryMsg", msg),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct RevocationResponse {
+    pub revocation: Option<Revocation>,
+}
+
+#[cw_serde]
+pub struct TokenExpirationResponse {
+    pub expires: Option<Timestamp>,
+    pub expired: bool,
 }
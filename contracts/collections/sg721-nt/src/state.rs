@@ -0,0 +1,24 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+/// What the issuer may do with tokens once minted
+#[cw_serde]
+#[derive(Default)]
+pub struct SoulboundPolicy {
+    /// Issuer can move each token to a new owner once, e.g. after a lost wallet
+    pub allow_recovery: bool,
+}
+
+/// Record of a token burned by its issuer
+#[cw_serde]
+pub struct Revocation {
+    pub reason: String,
+    pub revoked_by: Addr,
+    pub revoked_at: Timestamp,
+}
+
+pub const SOULBOUND_POLICY: Item<SoulboundPolicy> = Item::new("soulbound_policy");
+pub const TOKEN_EXPIRATIONS: Map<&str, Timestamp> = Map::new("token_expirations");
+pub const RECOVERED_TOKENS: Map<&str, bool> = Map::new("recovered_tokens");
+pub const REVOCATIONS: Map<&str, Revocation> = Map::new("revocations");
@@ -13,7 +13,9 @@ pub use cw721_base::MinterResponse;
 use sg721::InstantiateMsg;
 pub use sg721_base::msg::CollectionInfoResponse;
 use sg721_base::msg::QueryMsg;
-use sg721_updatable::msg::ExecuteMsg;
+use sg721_updatable::msg::{
+    ExecuteMsg, FrozenTokensResponse, MetadataUpdaterResponse, UpdatableExtensionMsg,
+};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -22,7 +24,10 @@ fn main() {
     remove_schemas(&out_dir).unwrap();
 
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
-    export_schema(&schema_for!(ExecuteMsg<Extension, Empty>), &out_dir);
+    export_schema(
+        &schema_for!(ExecuteMsg<Extension, UpdatableExtensionMsg>),
+        &out_dir,
+    );
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(CollectionInfoResponse), &out_dir);
     export_schema_with_title(
@@ -48,4 +53,6 @@ fn main() {
     export_schema(&schema_for!(NumTokensResponse), &out_dir);
     export_schema(&schema_for!(OwnerOfResponse), &out_dir);
     export_schema(&schema_for!(TokensResponse), &out_dir);
+    export_schema(&schema_for!(FrozenTokensResponse), &out_dir);
+    export_schema(&schema_for!(MetadataUpdaterResponse), &out_dir);
 }
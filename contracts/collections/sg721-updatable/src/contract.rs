@@ -1,6 +1,6 @@
 use crate::error::ContractError;
-use crate::state::FROZEN_TOKEN_METADATA;
-use cosmwasm_std::{Empty, StdError, Uint128};
+use crate::state::{MetadataUpdater, FROZEN_TOKENS, FROZEN_TOKEN_METADATA, METADATA_UPDATER};
+use cosmwasm_std::{Addr, Empty, Order, StdError, Uint128};
 
 use cosmwasm_std::{Deps, StdResult};
 
@@ -8,17 +8,22 @@ use cosmwasm_std::{Deps, StdResult};
 use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
 use cw2::set_contract_version;
 use semver::Version;
-use sg721::InstantiateMsg;
+use sg721::{InstantiateMsg, ENABLE_UPDATABLE_FEE};
 use sg721_base::msg::CollectionInfoResponse;
 
-use crate::msg::{EnableUpdatableResponse, FrozenTokenMetadataResponse};
+use crate::msg::{
+    EnableUpdatableResponse, FrozenTokenMetadataResponse, FrozenTokensResponse,
+    MetadataUpdaterResponse, UpdatableExtensionMsg,
+};
 use crate::state::ENABLE_UPDATABLE;
 
 use cw721_base::Extension;
-use cw_utils::nonpayable;
-use sg1::checked_fair_burn;
+use cw_storage_plus::Bound;
+use cw_utils::{nonpayable, Expiration};
+use sg1::{checked_fair_burn, query_minter_fee_routing};
+use sg721_base::contract::get_owner_minter;
 use sg721_base::ContractError::Unauthorized;
-use sg721_base::Sg721Contract;
+use sg721_base::{Sg721Contract, Sg721ExecuteExtension};
 pub type Sg721UpdatableContract<'a> = Sg721Contract<'a, Extension>;
 
 const CONTRACT_NAME: &str = "crates.io:sg721-updatable";
@@ -30,7 +35,10 @@ const COMPATIBLE_CONTRACT_NAMES_FOR_MIGRATION: [&str; 4] = [
     "sg721-updatable",
     "crates.io:sg721-updatable",
 ];
-const ENABLE_UPDATABLE_FEE: u128 = 1_500_000_000;
+
+// queries
+const PAGINATION_DEFAULT_LIMIT: u32 = 25;
+const PAGINATION_MAX_LIMIT: u32 = 100;
 
 pub fn _instantiate(
     deps: DepsMut,
@@ -66,8 +74,18 @@ pub fn execute_enable_updatable(
         return Err(ContractError::Base(Unauthorized {}));
     }
 
-    // Check fee matches enable updatable fee and add fairburn msg
-    checked_fair_burn(&info, &env, ENABLE_UPDATABLE_FEE, None, &mut res)?;
+    // Check fee matches enable updatable fee and add fairburn msg, routed by the minter's factory.
+    // Owners that aren't minters, e.g. after an ownership transfer, use the default routing.
+    let minter = get_owner_minter(deps.storage)?;
+    let fee_routing = query_minter_fee_routing(&deps.querier, minter).unwrap_or_default();
+    checked_fair_burn(
+        &info,
+        &env,
+        ENABLE_UPDATABLE_FEE,
+        None,
+        &mut res,
+        &fee_routing,
+    )?;
 
     ENABLE_UPDATABLE.save(deps.storage, &true)?;
 
@@ -80,6 +98,7 @@ pub fn execute_freeze_token_metadata(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    token_ids: Option<Vec<String>>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
     // Check if sender is creator
@@ -89,32 +108,123 @@ pub fn execute_freeze_token_metadata(
         return Err(ContractError::Base(Unauthorized {}));
     }
 
-    FROZEN_TOKEN_METADATA.save(deps.storage, &true)?;
+    let Some(token_ids) = token_ids else {
+        FROZEN_TOKEN_METADATA.save(deps.storage, &true)?;
+        return Ok(Response::new()
+            .add_attribute("action", "freeze_token_metadata")
+            .add_attribute("frozen", "true"));
+    };
 
-    Ok(Response::new()
-        .add_attribute("action", "freeze_token_metadata")
-        .add_attribute("frozen", "true"))
+    let tokens = Sg721UpdatableContract::default().parent.tokens;
+    let mut event = Event::new("freeze_token_metadata").add_attribute("sender", info.sender);
+    for token_id in token_ids {
+        if !tokens.has(deps.storage, &token_id) {
+            return Err(ContractError::TokenIdNotFound {});
+        }
+        FROZEN_TOKENS.save(deps.storage, &token_id, &true)?;
+        event = event.add_attribute("token_id", token_id);
+    }
+    Ok(Response::new().add_event(event))
 }
 
-pub fn execute_update_token_metadata(
+pub fn execute_set_metadata_updater(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    updater: String,
+    token_ids: Option<Vec<String>>,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    // Check if sender is creator
+    let collection_info: CollectionInfoResponse =
+        Sg721UpdatableContract::default().query_collection_info(deps.as_ref())?;
+    if info.sender != collection_info.creator {
+        return Err(ContractError::Base(Unauthorized {}));
+    }
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
+    let updater = MetadataUpdater {
+        address: deps.api.addr_validate(&updater)?,
+        token_ids,
+        expires,
+    };
+    METADATA_UPDATER.save(deps.storage, &updater)?;
+
+    let mut event = Event::new("set_metadata_updater")
+        .add_attribute("sender", info.sender)
+        .add_attribute("updater", updater.address)
+        .add_attribute("expires", updater.expires.to_string());
+    for token_id in updater.token_ids.unwrap_or_default() {
+        event = event.add_attribute("token_id", token_id);
+    }
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_metadata_updater(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    token_id: String,
-    token_uri: Option<String>,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
     // Check if sender is creator
-    let owner = deps.api.addr_validate(info.sender.as_ref())?;
     let collection_info: CollectionInfoResponse =
         Sg721UpdatableContract::default().query_collection_info(deps.as_ref())?;
-    if owner != collection_info.creator {
+    if info.sender != collection_info.creator {
+        return Err(ContractError::Base(Unauthorized {}));
+    }
+
+    METADATA_UPDATER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_metadata_updater")
+        .add_attribute("sender", info.sender))
+}
+
+/// The creator can update any token, the metadata updater only the tokens it was granted
+/// until its grant expires
+fn can_update_token_metadata(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    token_id: &str,
+) -> Result<bool, ContractError> {
+    let collection_info: CollectionInfoResponse =
+        Sg721UpdatableContract::default().query_collection_info(deps)?;
+    if *sender == collection_info.creator {
+        return Ok(true);
+    }
+
+    let Some(updater) = METADATA_UPDATER.may_load(deps.storage)? else {
+        return Ok(false);
+    };
+    let covers_token = match updater.token_ids {
+        Some(token_ids) => token_ids.iter().any(|id| id == token_id),
+        None => true,
+    };
+    Ok(updater.address == *sender && covers_token && !updater.expires.is_expired(&env.block))
+}
+
+pub fn execute_update_token_metadata(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    token_uri: Option<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    // Check if sender is creator or metadata updater
+    if !can_update_token_metadata(deps.as_ref(), &env, &info.sender, &token_id)? {
         return Err(ContractError::Base(Unauthorized {}));
     }
 
     // Check if token metadata is frozen
     let frozen = FROZEN_TOKEN_METADATA.load(deps.storage)?;
-    if frozen {
+    if frozen || FROZEN_TOKENS.has(deps.storage, &token_id) {
         return Err(ContractError::TokenMetadataFrozen {});
     }
 
@@ -146,6 +256,37 @@ pub fn execute_update_token_metadata(
     Ok(Response::new().add_event(event))
 }
 
+impl Sg721ExecuteExtension<Extension> for UpdatableExtensionMsg {
+    type Error = ContractError;
+
+    fn execute_extension(
+        self,
+        _contract: &Sg721UpdatableContract,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        match self {
+            UpdatableExtensionMsg::FreezeTokenMetadata { token_ids } => {
+                execute_freeze_token_metadata(deps, env, info, token_ids)
+            }
+            UpdatableExtensionMsg::UpdateTokenMetadata {
+                token_id,
+                token_uri,
+            } => execute_update_token_metadata(deps, env, info, token_id, token_uri),
+            UpdatableExtensionMsg::EnableUpdatable {} => execute_enable_updatable(deps, env, info),
+            UpdatableExtensionMsg::SetMetadataUpdater {
+                updater,
+                token_ids,
+                expires,
+            } => execute_set_metadata_updater(deps, env, info, updater, token_ids, expires),
+            UpdatableExtensionMsg::RemoveMetadataUpdater {} => {
+                execute_remove_metadata_updater(deps, env, info)
+            }
+        }
+    }
+}
+
 pub fn query_enable_updatable(deps: Deps) -> StdResult<EnableUpdatableResponse> {
     let enabled = ENABLE_UPDATABLE.load(deps.storage)?;
     Ok(EnableUpdatableResponse { enabled })
@@ -155,11 +296,38 @@ pub fn query_enable_updatable_fee() -> StdResult<Uint128> {
     Ok(Uint128::from(ENABLE_UPDATABLE_FEE))
 }
 
-pub fn query_frozen_token_metadata(deps: Deps) -> StdResult<FrozenTokenMetadataResponse> {
-    let frozen = FROZEN_TOKEN_METADATA.load(deps.storage)?;
+pub fn query_frozen_token_metadata(
+    deps: Deps,
+    token_id: Option<String>,
+) -> StdResult<FrozenTokenMetadataResponse> {
+    let mut frozen = FROZEN_TOKEN_METADATA.load(deps.storage)?;
+    if let Some(token_id) = token_id {
+        frozen = frozen || FROZEN_TOKENS.has(deps.storage, &token_id);
+    }
     Ok(FrozenTokenMetadataResponse { frozen })
 }
 
+pub fn query_frozen_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FrozenTokensResponse> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let tokens = FROZEN_TOKENS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<String>>>()?;
+    Ok(FrozenTokensResponse { tokens })
+}
+
+pub fn query_metadata_updater(deps: Deps) -> StdResult<MetadataUpdaterResponse> {
+    let updater = METADATA_UPDATER.may_load(deps.storage)?;
+    Ok(MetadataUpdaterResponse { updater })
+}
+
 pub fn _migrate(mut deps: DepsMut, env: Env, _msg: Empty) -> Result<Response, ContractError> {
     let prev_contract_info = cw2::get_contract_version(deps.storage)?;
     let prev_contract_name: String = prev_contract_info.contract;
@@ -234,11 +402,12 @@ mod tests {
     use crate::msg::ExecuteMsg;
     use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
     use cosmwasm_std::{
-        from_json, to_json_binary, ContractInfoResponse, ContractResult, Empty, OwnedDeps, Querier,
-        QuerierResult, QueryRequest, SystemError, SystemResult, WasmQuery,
+        coins, from_json, to_json_binary, ContractInfoResponse, ContractResult, Empty, OwnedDeps,
+        Querier, QuerierResult, QueryRequest, SystemError, SystemResult, WasmQuery,
     };
     use cw721::Cw721Query;
     use sg721::{CollectionInfo, InstantiateMsg};
+    use sg_utils::NATIVE_DENOM;
     use std::marker::PhantomData;
 
     const CREATOR: &str = "creator";
@@ -309,6 +478,7 @@ mod tests {
                 external_link: None,
                 explicit_content: None,
                 start_trading_time: None,
+                enforce_start_trading_time: None,
                 royalty_info: None,
             },
         };
@@ -321,6 +491,7 @@ mod tests {
             owner: "john".to_string(),
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: None,
+            royalty_info: None,
         };
         execute(deps.as_mut(), mock_env(), info.clone(), exec_msg).unwrap();
 
@@ -359,7 +530,7 @@ mod tests {
         assert_eq!(res.token_uri, updated_token_uri);
 
         // Update token metadata with None token_uri
-        let update_msg = ExecuteMsg::<Extension, Empty>::UpdateTokenMetadata {
+        let update_msg = ExecuteMsg::UpdateTokenMetadata {
             token_id: token_id.to_string(),
             token_uri: None,
         };
@@ -371,7 +542,7 @@ mod tests {
         assert_eq!(res.token_uri, None);
 
         // Freeze token metadata
-        let freeze_msg = ExecuteMsg::FreezeTokenMetadata {};
+        let freeze_msg = ExecuteMsg::FreezeTokenMetadata { token_ids: None };
         execute(deps.as_mut(), mock_env(), info.clone(), freeze_msg).unwrap();
 
         // Throws error trying to update token metadata
@@ -405,6 +576,7 @@ mod tests {
                 external_link: None,
                 explicit_content: None,
                 start_trading_time: None,
+                enforce_start_trading_time: None,
                 royalty_info: None,
             },
         };
@@ -412,7 +584,13 @@ mod tests {
 
         let enable_updatable_msg = ExecuteMsg::EnableUpdatable {};
 
-        let err = execute(deps.as_mut(), mock_env(), info, enable_updatable_msg).unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            enable_updatable_msg.clone(),
+        )
+        .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -421,5 +599,13 @@ mod tests {
 
         let res = query_enable_updatable(deps.as_ref()).unwrap();
         assert!(res.enabled);
+
+        // Owners that aren't minters pay the fee with the default fee routing
+        ENABLE_UPDATABLE.save(&mut deps.storage, &false).unwrap();
+        let info = mock_info(CREATOR, &coins(ENABLE_UPDATABLE_FEE, NATIVE_DENOM));
+        let res = execute(deps.as_mut(), mock_env(), info, enable_updatable_msg).unwrap();
+        assert!(!res.messages.is_empty());
+        let res = query_enable_updatable(deps.as_ref()).unwrap();
+        assert!(res.enabled);
     }
 }
@@ -28,4 +28,7 @@ pub enum ContractError {
 
     #[error("AlreadyEnableUpdatable")]
     AlreadyEnableUpdatable {},
+
+    #[error("InvalidExpiration")]
+    InvalidExpiration {},
 }
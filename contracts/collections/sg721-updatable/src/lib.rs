@@ -13,10 +13,13 @@ pub mod entry {
     use crate::{
         contract::{
             _instantiate, _migrate, execute_enable_updatable, execute_freeze_token_metadata,
+            execute_remove_metadata_updater, execute_set_metadata_updater,
             execute_update_token_metadata, query_enable_updatable, query_enable_updatable_fee,
-            query_frozen_token_metadata, Sg721UpdatableContract,
+            query_frozen_token_metadata, query_frozen_tokens, query_metadata_updater,
+            Sg721UpdatableContract,
         },
-        msg::ExecuteMsg,
+        msg::{ExecuteMsg, UpdatableExtensionMsg},
+        state::FROZEN_TOKENS,
     };
     use cosmwasm_std::{entry_point, to_json_binary, Empty};
     use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
@@ -37,18 +40,30 @@ pub mod entry {
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: ExecuteMsg<Extension, Empty>,
+        msg: ExecuteMsg<Extension, UpdatableExtensionMsg>,
     ) -> Result<Response, ContractError> {
         match msg {
-            ExecuteMsg::FreezeTokenMetadata {} => execute_freeze_token_metadata(deps, env, info),
+            ExecuteMsg::FreezeTokenMetadata { token_ids } => {
+                execute_freeze_token_metadata(deps, env, info, token_ids)
+            }
             ExecuteMsg::EnableUpdatable {} => execute_enable_updatable(deps, env, info),
             ExecuteMsg::UpdateTokenMetadata {
                 token_id,
                 token_uri,
             } => execute_update_token_metadata(deps, env, info, token_id, token_uri),
-            _ => Sg721UpdatableContract::default()
-                .execute(deps, env, info, msg.into())
-                .map_err(|e| e.into()),
+            ExecuteMsg::SetMetadataUpdater {
+                updater,
+                token_ids,
+                expires,
+            } => execute_set_metadata_updater(deps, env, info, updater, token_ids, expires),
+            ExecuteMsg::RemoveMetadataUpdater {} => {
+                execute_remove_metadata_updater(deps, env, info)
+            }
+            ExecuteMsg::Burn { ref token_id } => {
+                FROZEN_TOKENS.remove(deps.storage, token_id);
+                Sg721UpdatableContract::default().execute(deps, env, info, msg.into())
+            }
+            _ => Sg721UpdatableContract::default().execute(deps, env, info, msg.into()),
         }
     }
 
@@ -57,7 +72,13 @@ pub mod entry {
         match msg {
             QueryMsg::EnableUpdatable {} => to_json_binary(&query_enable_updatable(deps)?),
             QueryMsg::EnableUpdatableFee {} => to_json_binary(&query_enable_updatable_fee()?),
-            QueryMsg::FreezeTokenMetadata {} => to_json_binary(&query_frozen_token_metadata(deps)?),
+            QueryMsg::FreezeTokenMetadata { token_id } => {
+                to_json_binary(&query_frozen_token_metadata(deps, token_id)?)
+            }
+            QueryMsg::FrozenTokens { start_after, limit } => {
+                to_json_binary(&query_frozen_tokens(deps, start_after, limit)?)
+            }
+            QueryMsg::MetadataUpdater {} => to_json_binary(&query_metadata_updater(deps)?),
             _ => Sg721UpdatableContract::default().query(deps, env, msg.into()),
         }
     }
@@ -1,22 +1,37 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Binary;
 use cosmwasm_std::Timestamp;
+use cw721_base::Extension;
 use cw_utils::Expiration;
+use sg721::ExecuteMsg as Sg721ExecuteMsg;
 use sg721::{RoyaltyInfoResponse, UpdateCollectionInfoMsg};
-use sg721_base::msg::QueryMsg as Sg721QueryMsg;
-use sg721_base::ExecuteMsg as Sg721ExecuteMsg;
+use sg721_base::msg::{Cw2981QueryMsg, QueryMsg as Sg721QueryMsg};
+
+use crate::state::MetadataUpdater;
 
 #[cw_serde]
 pub enum ExecuteMsg<T, E> {
-    /// Freeze token metadata so creator can no longer update token uris
-    FreezeTokenMetadata {},
-    /// Creator calls can update token uris
+    /// Freeze token metadata so creator can no longer update token uris.
+    /// Only the given tokens are frozen when `token_ids` is set.
+    FreezeTokenMetadata {
+        token_ids: Option<Vec<String>>,
+    },
+    /// Creator or metadata updater calls can update token uris
     UpdateTokenMetadata {
         token_id: String,
         token_uri: Option<String>,
     },
     /// Enable updatable for updating token metadata. One time migration fee for sg721-base to sg721-updatable.
     EnableUpdatable {},
+    /// Creator grants an address the right to update token metadata,
+    /// optionally limited to a set of tokens and until an expiration
+    SetMetadataUpdater {
+        updater: String,
+        token_ids: Option<Vec<String>>,
+        expires: Option<Expiration>,
+    },
+    /// Creator revokes the metadata updater
+    RemoveMetadataUpdater {},
     // Sg721Base msgs
     TransferNft {
         recipient: String,
@@ -49,6 +64,10 @@ pub enum ExecuteMsg<T, E> {
     UpdateCollectionInfo {
         collection_info: UpdateCollectionInfoMsg<RoyaltyInfoResponse>,
     },
+    UpdateTokenRoyaltyInfo {
+        token_id: String,
+        royalty_info: Option<RoyaltyInfoResponse>,
+    },
     UpdateStartTradingTime(Option<Timestamp>),
     FreezeCollectionInfo {},
     Mint {
@@ -62,18 +81,40 @@ pub enum ExecuteMsg<T, E> {
         token_uri: Option<String>,
         /// Any custom extension used by this contract
         extension: T,
+        /// Royalty override for this token, the collection royalty applies when not set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
     Extension {
         msg: E,
     },
 }
 
-impl<T, E> From<ExecuteMsg<T, E>> for Sg721ExecuteMsg
+/// Extension messages of updatable collections, also accepted as top level messages
+#[cw_serde]
+pub enum UpdatableExtensionMsg {
+    FreezeTokenMetadata {
+        token_ids: Option<Vec<String>>,
+    },
+    UpdateTokenMetadata {
+        token_id: String,
+        token_uri: Option<String>,
+    },
+    EnableUpdatable {},
+    SetMetadataUpdater {
+        updater: String,
+        token_ids: Option<Vec<String>>,
+        expires: Option<Expiration>,
+    },
+    RemoveMetadataUpdater {},
+}
+
+impl<T, E> From<ExecuteMsg<T, E>> for Sg721ExecuteMsg<Extension, E>
 where
     T: Clone + PartialEq + Into<Option<cosmwasm_std::Empty>>,
     Option<cosmwasm_std::Empty>: From<T>,
 {
-    fn from(msg: ExecuteMsg<T, E>) -> Sg721ExecuteMsg {
+    fn from(msg: ExecuteMsg<T, E>) -> Sg721ExecuteMsg<Extension, E> {
         match msg {
             ExecuteMsg::TransferNft {
                 recipient,
@@ -111,21 +152,31 @@ where
             ExecuteMsg::UpdateCollectionInfo { collection_info } => {
                 Sg721ExecuteMsg::UpdateCollectionInfo { collection_info }
             }
+            ExecuteMsg::UpdateTokenRoyaltyInfo {
+                token_id,
+                royalty_info,
+            } => Sg721ExecuteMsg::UpdateTokenRoyaltyInfo {
+                token_id,
+                royalty_info,
+            },
             ExecuteMsg::FreezeCollectionInfo {} => Sg721ExecuteMsg::FreezeCollectionInfo {},
             ExecuteMsg::Mint {
                 token_id,
                 owner,
                 token_uri,
                 extension,
+                royalty_info,
             } => Sg721ExecuteMsg::Mint {
                 token_id,
                 owner,
                 token_uri,
                 extension: extension.into(),
+                royalty_info,
             },
             ExecuteMsg::UpdateStartTradingTime(start_trading_time) => {
                 Sg721ExecuteMsg::UpdateStartTradingTime(start_trading_time)
             }
+            ExecuteMsg::Extension { msg } => Sg721ExecuteMsg::Extension { msg },
             _ => unreachable!("Invalid ExecuteMsg"),
         }
     }
@@ -135,7 +186,16 @@ where
 pub enum QueryMsg {
     EnableUpdatable {},
     EnableUpdatableFee {},
-    FreezeTokenMetadata {},
+    /// Whether the collection, or the given token, has its metadata frozen
+    FreezeTokenMetadata {
+        token_id: Option<String>,
+    },
+    /// Tokens frozen individually
+    FrozenTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    MetadataUpdater {},
     OwnerOf {
         token_id: String,
         include_expired: Option<bool>,
@@ -175,6 +235,9 @@ pub enum QueryMsg {
     },
     Minter {},
     CollectionInfo {},
+    Extension {
+        msg: Cw2981QueryMsg,
+    },
 }
 
 impl From<QueryMsg> for Sg721QueryMsg {
@@ -238,6 +301,7 @@ impl From<QueryMsg> for Sg721QueryMsg {
             }
             QueryMsg::Minter {} => Sg721QueryMsg::Minter {},
             QueryMsg::CollectionInfo {} => Sg721QueryMsg::CollectionInfo {},
+            QueryMsg::Extension { msg } => Sg721QueryMsg::Extension { msg },
             _ => unreachable!("cannot convert {:?} to Sg721QueryMsg", msg),
         }
     }
@@ -252,3 +316,13 @@ pub struct EnableUpdatableResponse {
 pub struct FrozenTokenMetadataResponse {
     pub frozen: bool,
 }
+
+#[cw_serde]
+pub struct FrozenTokensResponse {
+    pub tokens: Vec<String>,
+}
+
+#[cw_serde]
+pub struct MetadataUpdaterResponse {
+    pub updater: Option<MetadataUpdater>,
+}
@@ -1,4 +1,20 @@
-use cw_storage_plus::Item;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
 pub const FROZEN_TOKEN_METADATA: Item<bool> = Item::new("frozen_token_metadata");
 pub const ENABLE_UPDATABLE: Item<bool> = Item::new("enable_updatable");
+/// Tokens frozen individually, on top of the collection wide `FROZEN_TOKEN_METADATA` flag
+pub const FROZEN_TOKENS: Map<&str, bool> = Map::new("frozen_tokens");
+
+/// Address allowed to update token metadata on behalf of the creator
+#[cw_serde]
+pub struct MetadataUpdater {
+    pub address: Addr,
+    /// Tokens the updater may update, all tokens when not set
+    pub token_ids: Option<Vec<String>>,
+    pub expires: Expiration,
+}
+
+pub const METADATA_UPDATER: Item<MetadataUpdater> = Item::new("metadata_updater");
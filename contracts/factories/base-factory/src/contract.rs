@@ -1,16 +1,22 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, ensure_eq, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult, WasmMsg,
+    ensure, ensure_eq, to_json_binary, Addr, Api, Binary, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdError, StdResult, SubMsg, SubMsgResult, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw_utils::must_pay;
+use cw_storage_plus::Bound;
+use cw_utils::{maybe_addr, must_pay, parse_reply_instantiate_data};
 use semver::Version;
 use sg1::{checked_fair_burn, transfer_funds_to_launchpad_dao};
-use sg2::msg::UpdateMinterParamsMsg;
-use sg2::query::{AllowedCollectionCodeIdResponse, AllowedCollectionCodeIdsResponse, Sg2QueryMsg};
-use sg2::MinterParams;
+use sg2::msg::{SplitsParams, UpdateMinterParamsMsg};
+use sg2::query::{
+    AllowedCollectionCodeIdResponse, AllowedCollectionCodeIdsResponse, MinterInfo, MinterResponse,
+    MintersResponse, Sg2QueryMsg,
+};
+use sg2::{CodeId, MinterParams};
+use sg_controllers::{Admin, ContractInstantiateMsg};
+use sg_splits::msg::{Group, InstantiateMsg as SplitsInstantiateMsg};
 use sg_utils::NATIVE_DENOM;
 
 use crate::error::ContractError;
@@ -18,12 +24,19 @@ use crate::msg::{
     BaseMinterCreateMsg, BaseSudoMsg, BaseUpdateParamsMsg, ExecuteMsg, InstantiateMsg,
     ParamsResponse, SudoMsg,
 };
-use crate::state::SUDO_PARAMS;
+use crate::state::{minters, PENDING_MINTER, SUDO_PARAMS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:sg-base-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const INSTANTIATE_MINTER_REPLY_ID: u64 = 1;
+pub const INSTANTIATE_SPLITS_REPLY_ID: u64 = 2;
+
+// pagination info for the minter registry queries
+const PAGINATION_DEFAULT_LIMIT: u32 = 25;
+const PAGINATION_MAX_LIMIT: u32 = 100;
+
 /// Can only be called by governance
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -34,6 +47,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    msg.params.fee_routing.validate(deps.api)?;
     SUDO_PARAMS.save(deps.storage, &msg.params)?;
 
     Ok(Response::new())
@@ -62,6 +76,8 @@ pub fn execute_create_minter(
     must_be_allowed_collection(deps.as_ref(), msg.collection_params.code_id)?;
 
     must_not_be_frozen(&params)?;
+    // Base minters don't collect proceeds to split
+    ensure!(msg.splits.is_none(), ContractError::SplitsNotSupported {});
 
     let mut res = Response::new();
     if params.creation_fee.denom == NATIVE_DENOM {
@@ -71,6 +87,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             None,
             &mut res,
+            &params.fee_routing,
         )?;
     } else {
         transfer_funds_to_launchpad_dao(
@@ -78,6 +95,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             &params.creation_fee.denom,
             &mut res,
+            &params.fee_routing,
         )?;
     }
 
@@ -95,7 +113,129 @@ pub fn execute_create_minter(
 
     Ok(res
         .add_attribute("action", "create_minter")
-        .add_message(msg))
+        .add_submessage(instantiate_minter_submsg(
+            deps,
+            info.sender,
+            params.code_id,
+            msg,
+        )?))
+}
+
+/// Wraps the minter instantiate message so the created minter is registered in the reply
+pub fn instantiate_minter_submsg(
+    deps: DepsMut,
+    creator: Addr,
+    code_id: CodeId,
+    msg: WasmMsg,
+) -> StdResult<SubMsg> {
+    PENDING_MINTER.save(deps.storage, &(creator, code_id))?;
+    Ok(SubMsg::reply_on_success(msg, INSTANTIATE_MINTER_REPLY_ID))
+}
+
+// Reply callback triggered from minter contract instantiation
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    register_minter(deps, env, msg)
+}
+
+/// Registers the minter instantiated by `instantiate_minter_submsg` along with its collection
+pub fn register_minter(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id != INSTANTIATE_MINTER_REPLY_ID {
+        return Err(ContractError::InvalidReplyID {});
+    }
+
+    let events = match &msg.result {
+        SubMsgResult::Ok(res) => res.events.clone(),
+        SubMsgResult::Err(_) => return Err(ContractError::InstantiateMinterError {}),
+    };
+    let minter = parse_reply_instantiate_data(msg)
+        .map_err(|_| ContractError::InstantiateMinterError {})?
+        .contract_address;
+
+    // Every minter reports the collection it instantiated in its sg721 instantiate reply
+    let collection = events
+        .iter()
+        .filter(|event| {
+            event.ty == "wasm"
+                && event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "_contract_address" && attr.value == minter)
+        })
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "sg721_address")
+        .ok_or(ContractError::InstantiateMinterError {})?
+        .value
+        .clone();
+
+    let (creator, code_id) = PENDING_MINTER.load(deps.storage)?;
+    PENDING_MINTER.remove(deps.storage);
+
+    let minter = deps.api.addr_validate(&minter)?;
+    let info = MinterInfo {
+        minter: minter.clone(),
+        creator,
+        collection: deps.api.addr_validate(&collection)?,
+        code_id,
+        created_at: env.block.time,
+    };
+    minters().save(deps.storage, minter.clone(), &info)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "register_minter")
+        .add_attribute("minter", minter)
+        .add_attribute("collection", collection))
+}
+
+/// Instantiates a splits contract and its group to receive the proceeds of a minter being created.
+/// The minter is instantiated in the reply, once the splits contract address is known.
+pub fn instantiate_splits_submsg<T>(
+    params: &MinterParams<T>,
+    creator: &Addr,
+    splits: SplitsParams,
+    label: &str,
+) -> Result<SubMsg, ContractError> {
+    let code_ids = params
+        .splits_code_ids
+        .as_ref()
+        .ok_or(ContractError::SplitsNotEnabled {})?;
+    ensure!(!splits.members.is_empty(), ContractError::InvalidSplits {});
+
+    // The group has no admin, so the weights the proceeds are split by can't change
+    let group_msg = cw4_group::msg::InstantiateMsg {
+        admin: None,
+        members: splits.members,
+    };
+    let splits_msg = SplitsInstantiateMsg {
+        admin: splits.admin,
+        group: Group::Cw4Instantiate(ContractInstantiateMsg {
+            code_id: code_ids.group,
+            msg: to_json_binary(&group_msg)?,
+            admin: Some(Admin::Address {
+                addr: creator.to_string(),
+            }),
+            label: format!("Group-{label}"),
+        }),
+        distribution_threshold: splits.distribution_threshold,
+        cw20_tokens: None,
+    };
+    let msg = WasmMsg::Instantiate {
+        admin: Some(creator.to_string()),
+        code_id: code_ids.splits,
+        msg: to_json_binary(&splits_msg)?,
+        funds: vec![],
+        label: format!("Splits-{label}"),
+    };
+
+    Ok(SubMsg::reply_on_success(msg, INSTANTIATE_SPLITS_REPLY_ID))
+}
+
+/// Address of the splits contract instantiated by `instantiate_splits_submsg`
+pub fn parse_splits_reply(deps: Deps, msg: Reply) -> Result<Addr, ContractError> {
+    let splits = parse_reply_instantiate_data(msg)
+        .map_err(|_| ContractError::InstantiateSplitsError {})?
+        .contract_address;
+    Ok(deps.api.addr_validate(&splits)?)
 }
 
 pub fn must_not_be_frozen<T>(params: &MinterParams<T>) -> Result<(), ContractError> {
@@ -140,7 +280,7 @@ pub fn sudo_update_params(
 ) -> Result<Response, ContractError> {
     let mut params = SUDO_PARAMS.load(deps.storage)?;
 
-    update_params(&mut params, param_msg)?;
+    update_params(deps.api, &mut params, param_msg)?;
 
     SUDO_PARAMS.save(deps.storage, &params)?;
 
@@ -149,6 +289,7 @@ pub fn sudo_update_params(
 
 /// Base update params that can be used by other minter factories
 pub fn update_params<T, C>(
+    api: &dyn Api,
     params: &mut MinterParams<C>,
     param_msg: UpdateMinterParamsMsg<T>,
 ) -> Result<(), ContractError> {
@@ -171,6 +312,21 @@ pub fn update_params<T, C>(
         params.min_mint_price = min_mint_price;
     }
 
+    if let Some(additional_min_mint_prices) = param_msg.additional_min_mint_prices {
+        // Each denom can only have one floor, and the main denom already has one
+        let mut denoms = vec![params.min_mint_price.denom.clone()];
+        for price in additional_min_mint_prices.iter() {
+            ensure!(
+                !denoms.contains(&price.denom),
+                ContractError::DuplicateDenom {
+                    denom: price.denom.clone()
+                }
+            );
+            denoms.push(price.denom.clone());
+        }
+        params.additional_min_mint_prices = additional_min_mint_prices;
+    }
+
     // add new code ids, then rm code ids
     if let Some(add_sg721_code_ids) = param_msg.add_sg721_code_ids {
         for code_id in add_sg721_code_ids {
@@ -190,6 +346,19 @@ pub fn update_params<T, C>(
         .max_trading_offset_secs
         .unwrap_or(params.max_trading_offset_secs);
 
+    if let Some(fee_routing) = param_msg.fee_routing {
+        fee_routing.validate(api)?;
+        params.fee_routing = fee_routing;
+    }
+
+    params.max_whitelist_stages = param_msg
+        .max_whitelist_stages
+        .unwrap_or(params.max_whitelist_stages);
+
+    if let Some(splits_code_ids) = param_msg.splits_code_ids {
+        params.splits_code_ids = Some(splits_code_ids);
+    }
+
     Ok(())
 }
 
@@ -203,6 +372,22 @@ pub fn query(deps: Deps, _env: Env, msg: Sg2QueryMsg) -> StdResult<Binary> {
         Sg2QueryMsg::AllowedCollectionCodeId(code_id) => {
             to_json_binary(&query_allowed_collection_code_id(deps, code_id)?)
         }
+        Sg2QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
+        Sg2QueryMsg::MintersByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_minters_by_creator(
+            deps,
+            creator,
+            start_after,
+            limit,
+        )?),
+        Sg2QueryMsg::MinterByCollection { collection } => {
+            to_json_binary(&query_minter_by_collection(deps, collection)?)
+        }
     }
 }
 
@@ -227,6 +412,60 @@ fn query_allowed_collection_code_id(
     Ok(AllowedCollectionCodeIdResponse { allowed })
 }
 
+pub fn query_minters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MintersResponse> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start = maybe_addr(deps.api, start_after)?.map(Bound::exclusive);
+
+    let minters = minters()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, info)| info))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MintersResponse { minters })
+}
+
+pub fn query_minters_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MintersResponse> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start = maybe_addr(deps.api, start_after)?.map(Bound::exclusive);
+
+    let minters = minters()
+        .idx
+        .creator
+        .prefix(creator)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, info)| info))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MintersResponse { minters })
+}
+
+pub fn query_minter_by_collection(deps: Deps, collection: String) -> StdResult<MinterResponse> {
+    let collection = deps.api.addr_validate(&collection)?;
+    let minter = minters()
+        .idx
+        .collection
+        .item(deps.storage, collection)?
+        .map(|(_, info)| info);
+
+    Ok(MinterResponse { minter })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
     deps: DepsMut,
@@ -255,7 +494,7 @@ pub fn migrate(
     if let Some(msg) = msg {
         let mut params = SUDO_PARAMS.load(deps.storage)?;
 
-        update_params(&mut params, msg)?;
+        update_params(deps.api, &mut params, msg)?;
 
         SUDO_PARAMS.save(deps.storage, &params)?;
     }
@@ -20,6 +20,9 @@ pub enum ContractError {
     #[error("InvalidDenom")]
     InvalidDenom {},
 
+    #[error("Duplicate denom {denom}")]
+    DuplicateDenom { denom: String },
+
     #[error("Invalid Creation Fee amount received to create the minter.")]
     InvalidCreationFeeAmount {},
 
@@ -28,4 +31,22 @@ pub enum ContractError {
 
     #[error("InvalidCollectionCodeId {code_id}")]
     InvalidCollectionCodeId { code_id: u64 },
+
+    #[error("Invalid reply ID")]
+    InvalidReplyID {},
+
+    #[error("Instantiate minter error")]
+    InstantiateMinterError {},
+
+    #[error("Instantiate splits error")]
+    InstantiateSplitsError {},
+
+    #[error("Splits can't be created by this factory")]
+    SplitsNotEnabled {},
+
+    #[error("Splits need at least one member")]
+    InvalidSplits {},
+
+    #[error("Minters of this factory don't support splits")]
+    SplitsNotSupported {},
 }
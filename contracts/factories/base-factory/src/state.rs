@@ -1,9 +1,38 @@
-use cosmwasm_std::Empty;
-use cw_storage_plus::Item;
-use sg2::MinterParams;
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex, UniqueIndex};
+use sg2::query::MinterInfo;
+use sg2::{CodeId, MinterParams};
 
 pub type Extension = Option<Empty>;
 
 pub type BaseMinterParams = MinterParams<Extension>;
 
 pub const SUDO_PARAMS: Item<BaseMinterParams> = Item::new("sudo-params");
+
+/// Creator and code id of the minter being instantiated, read back in the instantiate reply
+pub const PENDING_MINTER: Item<(Addr, CodeId)> = Item::new("pending-minter");
+
+pub struct MinterIndexes<'a> {
+    pub creator: MultiIndex<'a, Addr, MinterInfo, Addr>,
+    pub collection: UniqueIndex<'a, Addr, MinterInfo, Addr>,
+}
+
+impl<'a> IndexList<MinterInfo> for MinterIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<MinterInfo>> + '_> {
+        let v: Vec<&dyn Index<MinterInfo>> = vec![&self.creator, &self.collection];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Every minter created by the factory, keyed by minter address
+pub fn minters<'a>() -> IndexedMap<'a, Addr, MinterInfo, MinterIndexes<'a>> {
+    let indexes = MinterIndexes {
+        creator: MultiIndex::new(
+            |_pk: &[u8], d: &MinterInfo| d.creator.clone(),
+            "minters",
+            "minters__creator",
+        ),
+        collection: UniqueIndex::new(|d: &MinterInfo| d.collection.clone(), "minters__collection"),
+    };
+    IndexedMap::new("minters", indexes)
+}
@@ -1,9 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Addr, Coin};
 use cw_storage_plus::Item;
 
 use sg2::MinterParams;
 
+use crate::msg::OpenEditionMinterCreateMsg;
+
 #[cw_serde]
 pub struct ParamsExtension {
     pub max_token_limit: u32,
@@ -15,3 +17,7 @@ pub struct ParamsExtension {
 pub type OpenEditionMinterParams = MinterParams<ParamsExtension>;
 
 pub const SUDO_PARAMS: Item<OpenEditionMinterParams> = Item::new("sudo-params");
+
+/// Creator and create message of the minter waiting for its splits contract to be instantiated
+pub const PENDING_SPLITS_MINTER: Item<(Addr, OpenEditionMinterCreateMsg)> =
+    Item::new("pending-splits-minter");
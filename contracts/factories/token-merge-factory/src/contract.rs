@@ -1,9 +1,13 @@
+use base_factory::contract::{
+    instantiate_minter_submsg, query_minter_by_collection, query_minters, query_minters_by_creator,
+    register_minter,
+};
 use base_factory::ContractError as BaseContractError;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, ensure_eq, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult, WasmMsg,
+    ensure, ensure_eq, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdError, StdResult, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::must_pay;
@@ -32,6 +36,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    msg.params.fee_routing.validate(deps.api)?;
     SUDO_PARAMS.save(deps.storage, &msg.params)?;
 
     Ok(Response::new())
@@ -74,6 +79,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             None,
             &mut res,
+            &params.fee_routing,
         )?;
     } else {
         transfer_funds_to_launchpad_dao(
@@ -81,6 +87,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             &params.creation_fee.denom,
             &mut res,
+            &params.fee_routing,
         )?;
     }
 
@@ -113,7 +120,18 @@ pub fn execute_create_minter(
 
     Ok(res
         .add_attribute("action", "create_minter")
-        .add_message(wasm_msg))
+        .add_submessage(instantiate_minter_submsg(
+            deps,
+            info.sender,
+            params.code_id,
+            wasm_msg,
+        )?))
+}
+
+// Reply callback triggered from minter contract instantiation
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    Ok(register_minter(deps, env, msg)?)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -163,6 +181,11 @@ pub fn sudo_update_params(
         params.shuffle_fee = shuffle_fee;
     }
 
+    if let Some(fee_routing) = param_msg.fee_routing {
+        fee_routing.validate(deps.api)?;
+        params.fee_routing = fee_routing;
+    }
+
     SUDO_PARAMS.save(deps.storage, &params)?;
 
     Ok(Response::new().add_attribute("action", "sudo_update_params"))
@@ -178,6 +201,22 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::AllowedCollectionCodeId(code_id) => {
             to_json_binary(&query_allowed_collection_code_id(deps, code_id)?)
         }
+        QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
+        QueryMsg::MintersByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_minters_by_creator(
+            deps,
+            creator,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::MinterByCollection { collection } => {
+            to_json_binary(&query_minter_by_collection(deps, collection)?)
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::state::TokenMergeFactoryParams;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Coin, Timestamp};
+use sg1::FeeRouting;
 use sg721::{CollectionInfo, RoyaltyInfoResponse};
 
 #[cw_serde]
@@ -43,6 +44,21 @@ pub enum QueryMsg {
     Params {},
     AllowedCollectionCodeIds {},
     AllowedCollectionCodeId(u64),
+    /// Returns `MintersResponse`, all minters created by the factory
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns `MintersResponse`, all minters created by the given creator
+    MintersByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns `MinterResponse`, the minter of the given collection
+    MinterByCollection {
+        collection: String,
+    },
 }
 
 #[cw_serde]
@@ -62,6 +78,7 @@ pub struct UpdateMinterParamsMsg<T> {
     pub frozen: Option<bool>,
     pub creation_fee: Option<Coin>,
     pub max_trading_offset_secs: Option<u64>,
+    pub fee_routing: Option<FeeRouting>,
     pub extension: T,
 }
 pub type TokenMergeUpdateParamsMsg = UpdateMinterParamsMsg<TokenMergeUpdateParamsExtension>;
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Coin;
 use cw_storage_plus::Item;
+use sg1::FeeRouting;
 
 #[cw_serde]
 pub struct TokenMergeFactoryParams {
@@ -14,6 +15,9 @@ pub struct TokenMergeFactoryParams {
     pub airdrop_mint_price: Coin,
     pub airdrop_mint_fee_bps: u64,
     pub shuffle_fee: Coin,
+    /// Where minters send their fees
+    #[serde(default)]
+    pub fee_routing: FeeRouting,
 }
 
 pub const SUDO_PARAMS: Item<TokenMergeFactoryParams> = Item::new("sudo-params");
@@ -1,10 +1,14 @@
-use base_factory::contract::{must_be_allowed_collection, must_not_be_frozen, update_params};
+use base_factory::contract::{
+    instantiate_minter_submsg, instantiate_splits_submsg, must_be_allowed_collection,
+    must_not_be_frozen, parse_splits_reply, query_minter_by_collection, query_minters,
+    query_minters_by_creator, register_minter, update_params, INSTANTIATE_SPLITS_REPLY_ID,
+};
 use base_factory::ContractError as BaseContractError;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, ensure_eq, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult, WasmMsg,
+    ensure, ensure_eq, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::must_pay;
@@ -18,7 +22,7 @@ use crate::msg::{
     ExecuteMsg, InstantiateMsg, ParamsResponse, SudoMsg, VendingMinterCreateMsg,
     VendingUpdateParamsMsg,
 };
-use crate::state::SUDO_PARAMS;
+use crate::state::{VendingMinterParams, PENDING_SPLITS_MINTER, SUDO_PARAMS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:vending-factory";
@@ -34,6 +38,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    msg.params.fee_routing.validate(deps.api)?;
     SUDO_PARAMS.save(deps.storage, &msg.params)?;
 
     Ok(Response::new())
@@ -55,7 +60,7 @@ pub fn execute_create_minter(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: VendingMinterCreateMsg,
+    mut msg: VendingMinterCreateMsg,
 ) -> Result<Response, ContractError> {
     let params = SUDO_PARAMS.load(deps.storage)?;
     must_pay(&info, &params.creation_fee.denom)?;
@@ -71,6 +76,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             None,
             &mut res,
+            &params.fee_routing,
         )?;
     } else {
         transfer_funds_to_launchpad_dao(
@@ -78,6 +84,7 @@ pub fn execute_create_minter(
             params.creation_fee.amount.u128(),
             &params.creation_fee.denom,
             &mut res,
+            &params.fee_routing,
         )?;
     }
 
@@ -112,17 +119,85 @@ pub fn execute_create_minter(
         });
     }
 
+    // Each additional price needs its own denom and must meet the floor governance set for it
+    let mut denoms = vec![&msg.init_msg.mint_price.denom];
+    for price in msg.init_msg.additional_mint_prices.iter() {
+        ensure!(
+            !denoms.contains(&&price.denom),
+            ContractError::DuplicateDenom {
+                denom: price.denom.clone()
+            }
+        );
+        let min_mint_price = params
+            .min_mint_price_for(&price.denom)
+            .ok_or(ContractError::DenomMismatch {})?;
+        if min_mint_price.amount > price.amount {
+            return Err(ContractError::InsufficientMintPrice {
+                expected: min_mint_price.amount.u128(),
+                got: price.amount.u128(),
+            });
+        }
+        denoms.push(&price.denom);
+    }
+
+    // The minter is created once the splits contract receiving its proceeds is
+    if let Some(splits) = msg.splits.take() {
+        let splits_submsg = instantiate_splits_submsg(
+            &params,
+            &info.sender,
+            splits,
+            msg.collection_params.name.trim(),
+        )?;
+        PENDING_SPLITS_MINTER.save(deps.storage, &(info.sender, msg))?;
+        return Ok(res
+            .add_attribute("action", "create_minter")
+            .add_submessage(splits_submsg));
+    }
+
+    Ok(res
+        .add_attribute("action", "create_minter")
+        .add_submessage(minter_submsg(deps, &params, info.sender, &msg)?))
+}
+
+fn minter_submsg(
+    deps: DepsMut,
+    params: &VendingMinterParams,
+    creator: Addr,
+    msg: &VendingMinterCreateMsg,
+) -> Result<SubMsg, ContractError> {
     let wasm_msg = WasmMsg::Instantiate {
-        admin: Some(info.sender.to_string()),
+        admin: Some(creator.to_string()),
         code_id: params.code_id,
-        msg: to_json_binary(&msg)?,
+        msg: to_json_binary(msg)?,
         funds: vec![],
         label: format!("VendingMinter-{}", msg.collection_params.name.trim()),
     };
+    Ok(instantiate_minter_submsg(
+        deps,
+        creator,
+        params.code_id,
+        wasm_msg,
+    )?)
+}
 
-    Ok(res
-        .add_attribute("action", "create_minter")
-        .add_message(wasm_msg))
+// Reply callback triggered from minter or splits contract instantiation
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id != INSTANTIATE_SPLITS_REPLY_ID {
+        return Ok(register_minter(deps, env, msg)?);
+    }
+
+    let splits = parse_splits_reply(deps.as_ref(), msg)?;
+    let (creator, mut create_msg) = PENDING_SPLITS_MINTER.load(deps.storage)?;
+    PENDING_SPLITS_MINTER.remove(deps.storage);
+
+    create_msg.init_msg.payment_address = Some(splits.to_string());
+    let params = SUDO_PARAMS.load(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate_splits")
+        .add_attribute("splits", splits)
+        .add_submessage(minter_submsg(deps, &params, creator, &create_msg)?))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -140,7 +215,7 @@ pub fn sudo_update_params(
 ) -> Result<Response, ContractError> {
     let mut params = SUDO_PARAMS.load(deps.storage)?;
 
-    update_params(&mut params, param_msg.clone())?;
+    update_params(deps.api, &mut params, param_msg.clone())?;
 
     params.extension.max_token_limit = param_msg
         .extension
@@ -189,6 +264,22 @@ pub fn query(deps: Deps, _env: Env, msg: Sg2QueryMsg) -> StdResult<Binary> {
         Sg2QueryMsg::AllowedCollectionCodeId(code_id) => {
             to_json_binary(&query_allowed_collection_code_id(deps, code_id)?)
         }
+        Sg2QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
+        Sg2QueryMsg::MintersByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_minters_by_creator(
+            deps,
+            creator,
+            start_after,
+            limit,
+        )?),
+        Sg2QueryMsg::MinterByCollection { collection } => {
+            to_json_binary(&query_minter_by_collection(deps, collection)?)
+        }
     }
 }
 
@@ -241,7 +332,7 @@ pub fn migrate(
     if let Some(msg) = msg {
         let mut params = SUDO_PARAMS.load(deps.storage)?;
 
-        update_params(&mut params, msg.clone())?;
+        update_params(deps.api, &mut params, msg.clone())?;
 
         params.extension.max_token_limit = msg
             .extension
@@ -21,6 +21,9 @@ pub enum ContractError {
     #[error("DenomMismatch")]
     DenomMismatch {},
 
+    #[error("Duplicate denom {denom}")]
+    DuplicateDenom { denom: String },
+
     #[error("InvalidNumTokens {max}, min: 1")]
     InvalidNumTokens { max: u32, min: u32 },
 
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Timestamp};
+use cosmwasm_std::{Coin, Timestamp, Uint128};
 use sg2::msg::{CreateMinterMsg, Sg2ExecuteMsg, UpdateMinterParamsMsg};
 
 use crate::state::VendingMinterParams;
@@ -18,6 +18,63 @@ pub struct VendingMinterInitMsgExtension {
     pub mint_price: Coin,
     pub per_address_limit: u32,
     pub whitelist: Option<String>,
+    /// Sells tokens at a declining price instead of the fixed `mint_price`
+    pub dutch_auction: Option<DutchAuctionParams>,
+    /// Public mint prices in other denoms, so buyers can pay in any of them instead of `mint_price`
+    #[serde(default)]
+    pub additional_mint_prices: Vec<Coin>,
+    /// Where the randomness that picks each buyer's token comes from
+    #[serde(default)]
+    pub randomness: RandomnessSource,
+    /// Holds sale proceeds in the minter until a milestone, so buyers can be refunded otherwise
+    #[serde(default)]
+    pub escrow: Option<EscrowParams>,
+}
+
+/// Public mint price starts at `start_price` at the mint start time and drops by `decay_step`
+/// every `decay_interval` seconds until it reaches `floor_price`
+#[cw_serde]
+pub struct DutchAuctionParams {
+    pub start_price: Coin,
+    pub floor_price: Coin,
+    pub decay_step: Uint128,
+    /// Seconds between price drops
+    pub decay_interval: u64,
+    /// Lets buyers claim back the difference between what they paid and the final clearing price
+    pub rebate: bool,
+}
+
+/// Sale proceeds stay in the minter until the creator releases them after `milestone`. If they
+/// aren't released by `refund_deadline`, token holders can burn their tokens for a refund.
+#[cw_serde]
+pub struct EscrowParams {
+    pub milestone: EscrowMilestone,
+    pub refund_deadline: Timestamp,
+}
+
+#[cw_serde]
+pub enum EscrowMilestone {
+    /// Proceeds can be released from this time on, e.g. the reveal time
+    Time(Timestamp),
+    /// Proceeds can be released once this percent of tokens is sold
+    PercentSold(u32),
+}
+
+/// Source of the randomness that picks which token a buyer receives
+#[cw_serde]
+#[derive(Default)]
+pub enum RandomnessSource {
+    /// Seeded with the buyer, block height and tx index of the mint itself
+    #[default]
+    BlockData,
+    /// Buyers pay and commit to a mint, then the tokens are assigned by a reveal at least
+    /// `reveal_delay_blocks` blocks later, seeded with data of the revealing block. Reveals after
+    /// the minter's reveal window use a seed fixed at commit time instead.
+    CommitReveal { reveal_delay_blocks: u64 },
+    /// Buyers pay and commit to a mint, then the tokens are assigned when the randomness beacon
+    /// at `address` delivers a seed through a nois compatible callback. If it doesn't in time,
+    /// anyone can reveal the mint with a seed fixed at commit time.
+    Beacon { address: String },
 }
 pub type VendingMinterCreateMsg = CreateMinterMsg<VendingMinterInitMsgExtension>;
 
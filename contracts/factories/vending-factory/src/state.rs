@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Addr, Coin};
 use cw_storage_plus::Item;
 use sg2::MinterParams;
+
+use crate::msg::VendingMinterCreateMsg;
 /// Parameters common to all vending minters, as determined by governance
 #[cw_serde]
 pub struct ParamsExtension {
@@ -14,3 +16,7 @@ pub struct ParamsExtension {
 pub type VendingMinterParams = MinterParams<ParamsExtension>;
 
 pub const SUDO_PARAMS: Item<VendingMinterParams> = Item::new("sudo-params");
+
+/// Creator and create message of the minter waiting for its splits contract to be instantiated
+pub const PENDING_SPLITS_MINTER: Item<(Addr, VendingMinterCreateMsg)> =
+    Item::new("pending-splits-minter");
@@ -14,7 +14,9 @@ use cw_utils::{must_pay, nonpayable, parse_reply_instantiate_data};
 use sg1::checked_fair_burn;
 use sg2::query::Sg2QueryMsg;
 use sg4::{QueryMsg, Status, StatusResponse, SudoMsg};
-use sg721::{ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
+use sg721::{
+    ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg, RoyaltyInfoResponse,
+};
 use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
 use sg_utils::NATIVE_DENOM;
 use url::Url;
@@ -102,7 +104,10 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint { token_uri } => execute_mint_sender(deps, env, info, token_uri),
+        ExecuteMsg::Mint {
+            token_uri,
+            royalty_info,
+        } => execute_mint_sender(deps, env, info, token_uri, royalty_info),
         ExecuteMsg::UpdateStartTradingTime(time) => {
             execute_update_start_trading_time(deps, env, info, time)
         }
@@ -114,6 +119,7 @@ pub fn execute_mint_sender(
     env: Env,
     info: MessageInfo,
     token_uri: String,
+    royalty_info: Option<RoyaltyInfoResponse>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let collection_address = COLLECTION_ADDRESS.load(deps.storage)?;
@@ -150,7 +156,14 @@ pub fn execute_mint_sender(
     if network_fee != funds_sent {
         return Err(ContractError::InvalidMintPrice {});
     }
-    checked_fair_burn(&info, &env, network_fee.u128(), None, &mut res)?;
+    checked_fair_burn(
+        &info,
+        &env,
+        network_fee.u128(),
+        None,
+        &mut res,
+        &factory_params.fee_routing,
+    )?;
 
     // Create mint msgs
     let mint_msg = Sg721ExecuteMsg::<Extension, Empty>::Mint {
@@ -158,6 +171,7 @@ pub fn execute_mint_sender(
         owner: info.sender.to_string(),
         token_uri: Some(token_uri.clone()),
         extension: None,
+        royalty_info,
     };
     let msg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: collection_address.to_string(),
@@ -2,6 +2,7 @@ use base_factory::{msg::BaseMinterCreateMsg, state::BaseMinterParams};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Empty, Timestamp};
 use sg4::MinterConfigResponse;
+use sg721::RoyaltyInfoResponse;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -11,7 +12,11 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint { token_uri: String },
+    Mint {
+        token_uri: String,
+        /// Royalty override for the token, the collection royalty applies when not set
+        royalty_info: Option<RoyaltyInfoResponse>,
+    },
     UpdateStartTradingTime(Option<Timestamp>),
 }
 
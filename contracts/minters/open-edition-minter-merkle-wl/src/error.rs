@@ -55,6 +55,9 @@ pub enum ContractError {
     #[error("Sold out")]
     SoldOut {},
 
+    #[error("Invalid mint quantity {got}, must be between 1 and {max}")]
+    InvalidMintQuantity { max: u32, got: u32 },
+
     #[error("Not Sold out")]
     NotSoldOut {},
 
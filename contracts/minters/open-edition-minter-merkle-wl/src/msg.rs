@@ -16,6 +16,7 @@ pub enum ExecuteMsg {
         stage: Option<u32>,
         proof_hashes: Option<Vec<String>>,
         allocation: Option<u32>,
+        quantity: Option<u32>,
     },
     SetWhitelist {
         whitelist: String,
@@ -33,6 +34,7 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
     },
     BurnRemaining {},
 }
@@ -5,16 +5,16 @@ use crate::msg::{
     MintableNumTokensResponse, QueryMsg, StartTimeResponse, TotalMintCountResponse,
 };
 use crate::state::{
-    increment_token_index, Config, ConfigExtension, AIRDROP_COUNT, CONFIG, MINTABLE_NUM_TOKENS,
-    MINTER_ADDRS, SG721_ADDRESS, STATUS, TOTAL_MINT_COUNT, WHITELIST_FS_MINTER_ADDRS,
-    WHITELIST_FS_MINT_COUNT, WHITELIST_MINTER_ADDRS, WHITELIST_SS_MINTER_ADDRS,
-    WHITELIST_SS_MINT_COUNT, WHITELIST_TS_MINTER_ADDRS, WHITELIST_TS_MINT_COUNT,
+    increment_token_index, migrate_whitelist_stage_mint_counts, Config, ConfigExtension,
+    AIRDROP_COUNT, CONFIG, MINTABLE_NUM_TOKENS, MINTER_ADDRS, SG721_ADDRESS, STATUS,
+    TOTAL_MINT_COUNT, WHITELIST_IS_TIERED, WHITELIST_MINTER_ADDRS, WHITELIST_STAGE_MINTER_ADDRS,
+    WHITELIST_STAGE_MINT_COUNT,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env,
-    Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, Timestamp,
+    coin, ensure, to_json_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, Event,
+    MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, Timestamp, Uint128,
     WasmMsg,
 };
 use cw2::set_contract_version;
@@ -23,8 +23,9 @@ use open_edition_factory::msg::{OpenEditionMinterCreateMsg, ParamsResponse};
 use open_edition_factory::state::OpenEditionMinterParams;
 use open_edition_factory::types::NftMetadataType;
 use semver::Version;
-use sg1::distribute_mint_fees;
+use sg1::{distribute_mint_fees, payment_msg};
 use sg2::query::Sg2QueryMsg;
+use sg2::{is_tiered_whitelist, validate_whitelist_stages};
 use sg4::{MinterConfig, Status, StatusResponse, SudoMsg};
 use sg721::{ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
 use sg_tiered_whitelist_flex::msg::{QueryMsg as TieredWhitelistQueryMsg, StageResponse};
@@ -102,6 +103,12 @@ pub fn instantiate(
         .and_then(|w| deps.api.addr_validate(w.as_str()).ok());
 
     if let Some(whitelist) = whitelist_addr.clone() {
+        let is_tiered_whitelist = validate_whitelist_stages(
+            &deps.querier,
+            &whitelist,
+            factory_params.max_whitelist_stages,
+        )?;
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
         // check the whitelist exists
         let res: WhitelistConfigResponse = deps
             .querier
@@ -195,7 +202,9 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint {} => execute_mint_sender(deps, env, info),
+        ExecuteMsg::Mint { quantity } => {
+            execute_mint_sender(deps, env, info, quantity.unwrap_or(1))
+        }
         ExecuteMsg::Purge {} => execute_purge(deps, env, info),
         ExecuteMsg::UpdateMintPrice { price } => execute_update_mint_price(deps, env, info, price),
         ExecuteMsg::UpdateStartTime(time) => execute_update_start_time(deps, env, info, time),
@@ -206,7 +215,10 @@ pub fn execute(
         ExecuteMsg::UpdatePerAddressLimit { per_address_limit } => {
             execute_update_per_address_limit(deps, env, info, per_address_limit)
         }
-        ExecuteMsg::MintTo { recipient } => execute_mint_to(deps, env, info, recipient),
+        ExecuteMsg::MintTo {
+            recipient,
+            quantity,
+        } => execute_mint_to(deps, env, info, recipient, quantity.unwrap_or(1)),
         ExecuteMsg::SetWhitelist { whitelist } => {
             execute_set_whitelist(deps, env, info, &whitelist)
         }
@@ -304,7 +316,7 @@ pub fn execute_set_whitelist(
         ..
     } = deps
         .querier
-        .query_wasm_smart(new_wl, &WhitelistQueryMsg::Config {})?;
+        .query_wasm_smart(&new_wl, &WhitelistQueryMsg::Config {})?;
 
     ensure!(!wl_is_active, ContractError::WhitelistAlreadyStarted {});
 
@@ -321,11 +333,15 @@ pub fn execute_set_whitelist(
         params:
             OpenEditionMinterParams {
                 min_mint_price: factory_min_mint_price,
+                max_whitelist_stages,
                 ..
             },
     } = deps
         .querier
         .query_wasm_smart(factory, &Sg2QueryMsg::Params {})?;
+    let is_tiered_whitelist =
+        validate_whitelist_stages(&deps.querier, &new_wl, max_whitelist_stages)?;
+    WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
 
     ensure!(
         factory_min_mint_price.amount <= wl_mint_price.amount,
@@ -355,13 +371,14 @@ pub fn execute_mint_sender(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let action = "mint_sender";
 
     // If there is no active whitelist right now, check public mint
     // Check start and end time (if not optional)
-    let is_public = is_public_mint(deps.as_ref(), &info)?;
+    let is_public = is_public_mint(deps.as_ref(), &info, quantity)?;
     if is_public && (env.block.time < config.extension.start_time) {
         return Err(ContractError::BeforeMintStartTime {});
     }
@@ -372,18 +389,18 @@ pub fn execute_mint_sender(
         }
     }
 
-    // Check if already minted max per address limit
+    // Check if the batch would exceed the max per address limit
     let mint_count = public_mint_count(deps.as_ref(), &info)?;
-    if is_public && mint_count >= config.extension.per_address_limit {
+    if is_public && mint_count + quantity > config.extension.per_address_limit {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    _execute_mint(deps, env, info, action, false, None, is_public)
+    _execute_mint(deps, env, info, action, false, None, is_public, quantity)
 }
 
 // Check if a whitelist exists and not ended
 // Sender has to be whitelisted to mint
-fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError> {
+fn is_public_mint(deps: Deps, info: &MessageInfo, quantity: u32) -> Result<bool, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     // If there is no whitelist, there's only a public mint
@@ -417,7 +434,7 @@ fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError>
     let wl_mint_count = whitelist_mint_count(deps, info, whitelist.clone())?;
     if config.extension.num_tokens.is_none() {
         ensure!(
-            wl_mint_count.0 < config.extension.per_address_limit,
+            wl_mint_count.0 + quantity <= config.extension.per_address_limit,
             ContractError::MaxPerAddressLimitExceeded {}
         );
     }
@@ -428,11 +445,11 @@ fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError>
             member: info.sender.to_string(),
         },
     )?;
-    if wl_mint_count.0 >= wl_limit.mint_count {
+    if wl_mint_count.0 + quantity > wl_limit.mint_count {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    // Check if whitelist stage mint count limit is reached
+    // Check if whitelist stage mint count limit would be exceeded
     if wl_mint_count.1 && wl_mint_count.2.is_some() {
         let active_stage: StageResponse = deps.querier.query_wasm_smart(
             whitelist.clone(),
@@ -441,13 +458,10 @@ fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError>
             },
         )?;
         if active_stage.stage.mint_count_limit.is_some() {
-            let stage_mint_count = match wl_mint_count.2.unwrap() {
-                1 => WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                2 => WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                3 => WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                _ => return Err(ContractError::InvalidStageID {}),
-            };
-            if stage_mint_count >= active_stage.stage.mint_count_limit.unwrap() {
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, wl_mint_count.2.unwrap())?
+                .unwrap_or(0);
+            if stage_mint_count + quantity > active_stage.stage.mint_count_limit.unwrap() {
                 return Err(ContractError::WhitelistMintCountLimitReached {});
             }
         }
@@ -467,41 +481,25 @@ fn whitelist_mint_count(
     info: &MessageInfo,
     whitelist_addr: Addr,
 ) -> Result<(u32, bool, Option<u32>), StdError> {
-    let is_tiered_whitelist = cw2::query_contract_info(&deps.querier, whitelist_addr.clone())
-        .map(|info| info.contract.contains("tiered-whitelist"))
-        .unwrap_or(false);
+    let is_tiered_whitelist = WHITELIST_IS_TIERED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
 
     if is_tiered_whitelist {
-        let active_stage_id = deps
+        let active_stage_id: u32 = deps
             .querier
-            .query_wasm_smart(whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
-        match active_stage_id {
-            1 => Ok((
-                WHITELIST_FS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(1),
-            )),
-            2 => Ok((
-                WHITELIST_SS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(2),
-            )),
-            3 => Ok((
-                WHITELIST_TS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(3),
-            )),
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+            .query_wasm_smart(&whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
+        if active_stage_id == 0 {
+            return Err(StdError::generic_err("Invalid stage ID"));
         }
+
+        Ok((
+            WHITELIST_STAGE_MINTER_ADDRS
+                .may_load(deps.storage, (&info.sender, active_stage_id))?
+                .unwrap_or(0),
+            true,
+            Some(active_stage_id),
+        ))
     } else {
         Ok((
             WHITELIST_MINTER_ADDRS
@@ -520,34 +518,17 @@ fn save_whitelist_mint_count(
     is_tiered_whitelist: bool,
     stage_id: Option<u32>,
     count: u32,
+    quantity: u32,
 ) -> StdResult<()> {
-    if is_tiered_whitelist & stage_id.is_some() {
-        match stage_id {
-            Some(1) => {
-                let _ = WHITELIST_FS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_fs_mint_count =
-                    WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_fs_mint_count += 1;
-                WHITELIST_FS_MINT_COUNT.save(deps.storage, &wl_fs_mint_count)
-            }
-            Some(2) => {
-                let _ = WHITELIST_SS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ss_mint_count =
-                    WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ss_mint_count += 1;
-                WHITELIST_SS_MINT_COUNT.save(deps.storage, &wl_ss_mint_count)
-            }
-            Some(3) => {
-                let _ = WHITELIST_TS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ts_mint_count =
-                    WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ts_mint_count += 1;
-                WHITELIST_TS_MINT_COUNT.save(deps.storage, &wl_ts_mint_count)
-            }
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+    match stage_id {
+        Some(stage_id) if is_tiered_whitelist => {
+            WHITELIST_STAGE_MINTER_ADDRS.save(deps.storage, (&info.sender, stage_id), &count)?;
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, stage_id)?
+                .unwrap_or(0);
+            WHITELIST_STAGE_MINT_COUNT.save(deps.storage, stage_id, &(stage_mint_count + quantity))
         }
-    } else {
-        WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count)
+        _ => WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count),
     }
 }
 
@@ -556,6 +537,7 @@ pub fn execute_mint_to(
     env: Env,
     info: MessageInfo,
     recipient: String,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let recipient = deps.api.addr_validate(&recipient)?;
     let config = CONFIG.load(deps.storage)?;
@@ -574,12 +556,23 @@ pub fn execute_mint_to(
         }
     }
 
-    _execute_mint(deps, env, info, action, true, Some(recipient), true)
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        Some(recipient),
+        true,
+        quantity,
+    )
 }
 
 // Generalize checks and mint message creation
 // mint -> _execute_mint(recipient: None, token_id: None)
 // mint_to(recipient: "friend") -> _execute_mint(Some(recipient), token_id: None)
+// A quantity > 1 mints a batch of tokens for a single payment of quantity * mint price
+#[allow(clippy::too_many_arguments)]
 fn _execute_mint(
     mut deps: DepsMut,
     _env: Env,
@@ -588,6 +581,7 @@ fn _execute_mint(
     is_admin: bool,
     recipient: Option<Addr>,
     is_public: bool,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let mintable_num_tokens = MINTABLE_NUM_TOKENS.may_load(deps.storage)?;
     if let Some(mintable_nb_tokens) = mintable_num_tokens {
@@ -595,6 +589,13 @@ fn _execute_mint(
             return Err(ContractError::SoldOut {});
         }
     }
+    let max_quantity = mintable_num_tokens.unwrap_or(u32::MAX);
+    if quantity == 0 || quantity > max_quantity {
+        return Err(ContractError::InvalidMintQuantity {
+            max: max_quantity,
+            got: quantity,
+        });
+    }
     let config = CONFIG.load(deps.storage)?;
 
     let sg721_address = SG721_ADDRESS.load(deps.storage)?;
@@ -604,7 +605,14 @@ fn _execute_mint(
         None => info.sender.clone(),
     };
 
-    let mint_price: Coin = mint_price(deps.as_ref(), is_admin)?;
+    let unit_price: Coin = mint_price(deps.as_ref(), is_admin)?;
+    let mint_price = coin(
+        unit_price
+            .amount
+            .checked_mul(Uint128::from(quantity))?
+            .u128(),
+        unit_price.denom,
+    );
     // Exact payment only accepted
     let payment = may_pay(&info, &mint_price.denom)?;
     if payment != mint_price.amount {
@@ -640,31 +648,36 @@ fn _execute_mint(
                 deps.api
                     .addr_validate(&factory_params.extension.dev_fee_address)?,
             ),
+            &factory_params.fee_routing,
         )?;
     }
 
-    // Token ID to mint + update the config counter
-    let token_id = increment_token_index(deps.storage)?.to_string();
-
-    // Create mint msg -> dependents on the NFT data type
-    let msg = mint_nft_msg(
-        sg721_address,
-        token_id.clone(),
-        recipient_addr.clone(),
-        match config.extension.nft_data.nft_data_type {
-            NftMetadataType::OnChainMetadata => config.extension.nft_data.extension,
-            NftMetadataType::OffChainMetadata => None,
-        },
-        match config.extension.nft_data.nft_data_type {
-            NftMetadataType::OnChainMetadata => None,
-            NftMetadataType::OffChainMetadata => config.extension.nft_data.token_uri,
-        },
-    )?;
-    res = res.add_message(msg);
+    let mut minted_token_ids = Vec::with_capacity(quantity as usize);
+    for _ in 0..quantity {
+        // Token ID to mint + update the config counter
+        let token_id = increment_token_index(deps.storage)?.to_string();
+
+        // Create mint msg -> dependents on the NFT data type
+        let msg = mint_nft_msg(
+            sg721_address.clone(),
+            token_id.clone(),
+            recipient_addr.clone(),
+            match config.extension.nft_data.nft_data_type {
+                NftMetadataType::OnChainMetadata => config.extension.nft_data.extension.clone(),
+                NftMetadataType::OffChainMetadata => None,
+            },
+            match config.extension.nft_data.nft_data_type {
+                NftMetadataType::OnChainMetadata => None,
+                NftMetadataType::OffChainMetadata => config.extension.nft_data.token_uri.clone(),
+            },
+        )?;
+        res = res.add_message(msg);
+        minted_token_ids.push(token_id);
+    }
 
     // Save the new mint count for the sender's address
     if is_public {
-        let new_mint_count = public_mint_count(deps.as_ref(), &info)? + 1;
+        let new_mint_count = public_mint_count(deps.as_ref(), &info)? + quantity;
         MINTER_ADDRS.save(deps.storage, &info.sender, &new_mint_count)?;
     } else {
         let whitelist_addr = config.extension.whitelist.unwrap();
@@ -675,7 +688,8 @@ fn _execute_mint(
             &info,
             wl_mint_count_response.1,
             wl_mint_count_response.2,
-            wl_mint_count_response.0 + 1,
+            wl_mint_count_response.0 + quantity,
+            quantity,
         )?
     }
 
@@ -683,7 +697,7 @@ fn _execute_mint(
     TOTAL_MINT_COUNT.update(
         deps.storage,
         |mut updated_mint_count| -> Result<_, ContractError> {
-            updated_mint_count += 1u32;
+            updated_mint_count += quantity;
             Ok(updated_mint_count)
         },
     )?;
@@ -691,12 +705,12 @@ fn _execute_mint(
     // Update the airdrop count if is_admin
     if is_admin {
         let current_airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or(0);
-        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + 1))?;
+        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + quantity))?;
     }
 
     // Update mintable count (optional)
     if let Some(mintable_nb_tokens) = mintable_num_tokens {
-        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_nb_tokens - 1))?;
+        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_nb_tokens - quantity))?;
     }
 
     let seller_amount = {
@@ -706,10 +720,11 @@ fn _execute_mint(
         let seller = config.extension.admin;
         // Sending 0 coins fails, so only send if amount is non-zero
         if !amount.is_zero() {
-            let msg = BankMsg::Send {
-                to_address: payment_address.unwrap_or(seller).to_string(),
-                amount: vec![coin(amount.u128(), mint_price.clone().denom)],
-            };
+            let msg = payment_msg(
+                &deps.querier,
+                &payment_address.unwrap_or(seller),
+                vec![coin(amount.u128(), mint_price.clone().denom)],
+            )?;
             res = res.add_message(msg);
         }
         amount
@@ -719,7 +734,12 @@ fn _execute_mint(
         .add_attribute("action", action)
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient_addr)
-        .add_attribute("token_id", token_id)
+        .add_attributes(
+            minted_token_ids
+                .into_iter()
+                .map(|token_id| ("token_id", token_id)),
+        )
+        .add_attribute("quantity", quantity.to_string())
         .add_attribute(
             "network_fee",
             coin(network_fee.into(), mint_price.clone().denom).to_string(),
@@ -1112,18 +1132,11 @@ fn query_mint_count_per_address(deps: Deps, address: String) -> StdResult<MintCo
     let mint_count = (MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
     let standard_wl_count =
         (WHITELIST_MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
-    let tiered_wl_count = (WHITELIST_FS_MINTER_ADDRS
-        .key(&addr)
-        .may_load(deps.storage)?)
-    .unwrap_or(0)
-        + (WHITELIST_SS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0)
-        + (WHITELIST_TS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0);
+    let tiered_wl_count = WHITELIST_STAGE_MINTER_ADDRS
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, count)| count))
+        .sum::<StdResult<u32>>()?;
 
     Ok(MintCountResponse {
         address: addr.to_string(),
@@ -1235,6 +1248,14 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Ok(Response::new());
     }
 
+    // tiered whitelist stage mint counts are keyed by stage id
+    migrate_whitelist_stage_mint_counts(deps.storage)?;
+    // whether the whitelist is tiered is decided when it is set
+    if let Some(whitelist) = CONFIG.load(deps.storage)?.extension.whitelist {
+        let is_tiered_whitelist = is_tiered_whitelist(&deps.querier, &whitelist);
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::new())
@@ -78,6 +78,7 @@ pub fn mint_nft_msg(
                 owner: recipient_addr.to_string(),
                 token_uri: None,
                 extension,
+                royalty_info: None,
             })?,
             funds: vec![],
         })
@@ -89,6 +90,7 @@ pub fn mint_nft_msg(
                 owner: recipient_addr.to_string(),
                 token_uri,
                 extension: None,
+                royalty_info: None,
             })?,
             funds: vec![],
         })
@@ -12,7 +12,9 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint {},
+    Mint {
+        quantity: Option<u32>,
+    },
     SetWhitelist {
         whitelist: String,
     },
@@ -29,6 +31,7 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
     },
     BurnRemaining {},
 }
@@ -4,6 +4,7 @@ use thiserror::Error;
 use url::ParseError;
 
 use sg1::FeeError;
+use sg_mint_hooks::MintHookError;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -22,6 +23,9 @@ pub enum ContractError {
     #[error("{0}")]
     Fee(#[from] FeeError),
 
+    #[error("{0}")]
+    MintHook(#[from] MintHookError),
+
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
@@ -55,6 +59,9 @@ pub enum ContractError {
     #[error("Sold out")]
     SoldOut {},
 
+    #[error("Invalid mint quantity {got}, must be between 1 and {max}")]
+    InvalidMintQuantity { max: u32, got: u32 },
+
     #[error("Not Sold out")]
     NotSoldOut {},
 
@@ -4,7 +4,7 @@ use cosmwasm_std::{
     QuerierWrapper, StdError, StdResult, WasmMsg, WasmQuery,
 };
 use cw721_base::Extension;
-use sg721::ExecuteMsg as Sg721ExecuteMsg;
+use sg721::{ExecuteMsg as Sg721ExecuteMsg, RoyaltyInfoResponse};
 use sg_metadata::Metadata;
 
 use crate::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
@@ -69,6 +69,7 @@ pub fn mint_nft_msg(
     recipient_addr: Addr,
     extension: Option<Metadata>,
     token_uri: Option<String>,
+    royalty_info: Option<RoyaltyInfoResponse>,
 ) -> Result<CosmosMsg, StdError> {
     let mint_msg = if let Some(extension) = extension {
         CosmosMsg::Wasm(WasmMsg::Execute {
@@ -78,6 +79,7 @@ pub fn mint_nft_msg(
                 owner: recipient_addr.to_string(),
                 token_uri: None,
                 extension,
+                royalty_info,
             })?,
             funds: vec![],
         })
@@ -89,6 +91,7 @@ pub fn mint_nft_msg(
                 owner: recipient_addr.to_string(),
                 token_uri,
                 extension: None,
+                royalty_info,
             })?,
             funds: vec![],
         })
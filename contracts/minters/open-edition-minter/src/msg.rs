@@ -1,5 +1,9 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Coin, Timestamp};
+use sg4::StatusResponse;
+use sg721::RoyaltyInfoResponse;
+use sg_controllers::HooksResponse;
+use sg_mint_hooks::{sg_mint_hooks_execute, sg_mint_hooks_query};
 
 use open_edition_factory::types::NftData;
 use open_edition_factory::{msg::OpenEditionMinterCreateMsg, state::OpenEditionMinterParams};
@@ -10,9 +14,12 @@ pub struct InstantiateMsg {
     pub params: OpenEditionMinterParams,
 }
 
+#[sg_mint_hooks_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint {},
+    Mint {
+        quantity: Option<u32>,
+    },
     SetWhitelist {
         whitelist: String,
     },
@@ -29,19 +36,33 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
+        /// Royalty of the minted tokens, overriding the collection royalty
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
     BurnRemaining {},
 }
 
+#[sg_mint_hooks_query]
 #[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(ConfigResponse)]
     Config {},
+    #[returns(StartTimeResponse)]
     StartTime {},
+    #[returns(EndTimeResponse)]
     EndTime {},
+    #[returns(MintPriceResponse)]
     MintPrice {},
+    #[returns(MintCountResponse)]
     MintCount { address: String },
+    #[returns(TotalMintCountResponse)]
     TotalMintCount {},
+    #[returns(StatusResponse)]
     Status {},
+    #[returns(MintableNumTokensResponse)]
     MintableNumTokens {},
 }
 
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdResult, Storage, Timestamp};
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Item, Map};
 
 use open_edition_factory::types::NftData;
@@ -23,13 +23,12 @@ pub const SG721_ADDRESS: Item<Addr> = Item::new("sg721_address");
 pub const MINTER_ADDRS: Map<&Addr, u32> = Map::new("ma");
 // Keep track of the number of tokens minted by each address for regular whitelists
 pub const WHITELIST_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlma");
-// Keep track of the number of tokens minted by each address for first, second & third tiered whitelist stages
-pub const WHITELIST_FS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlfsma");
-pub const WHITELIST_SS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlssma");
-pub const WHITELIST_TS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wltsma");
-pub const WHITELIST_FS_MINT_COUNT: Item<u32> = Item::new("wlfsmc");
-pub const WHITELIST_SS_MINT_COUNT: Item<u32> = Item::new("wlssmc");
-pub const WHITELIST_TS_MINT_COUNT: Item<u32> = Item::new("wltsmc");
+// Keep track of the number of tokens minted by each address in each tiered whitelist stage
+pub const WHITELIST_STAGE_MINTER_ADDRS: Map<(&Addr, u32), u32> = Map::new("wlsma");
+// Keep track of the number of tokens minted in each tiered whitelist stage
+pub const WHITELIST_STAGE_MINT_COUNT: Map<u32, u32> = Map::new("wlsmc");
+// Whether the whitelist is a tiered whitelist, decided when the whitelist is set
+pub const WHITELIST_IS_TIERED: Item<bool> = Item::new("wlit");
 
 /// This keeps track of the mint count
 pub const TOTAL_MINT_COUNT: Item<u32> = Item::new("total_mint_count");
@@ -49,3 +48,36 @@ pub fn increment_token_index(store: &mut dyn Storage) -> StdResult<u64> {
     TOKEN_INDEX.save(store, &val)?;
     Ok(val)
 }
+
+// Mint counts of the first, second & third tiered whitelist stages, from before stages were
+// keyed by id
+const LEGACY_WHITELIST_STAGE_MINTER_ADDRS: [Map<&Addr, u32>; 3] =
+    [Map::new("wlfsma"), Map::new("wlssma"), Map::new("wltsma")];
+const LEGACY_WHITELIST_STAGE_MINT_COUNTS: [Item<u32>; 3] = [
+    Item::new("wlfsmc"),
+    Item::new("wlssmc"),
+    Item::new("wltsmc"),
+];
+
+/// Moves the mint counts of the first three tiered whitelist stages to the maps keyed by stage id
+pub fn migrate_whitelist_stage_mint_counts(storage: &mut dyn Storage) -> StdResult<()> {
+    for (i, (minter_addrs, mint_count)) in LEGACY_WHITELIST_STAGE_MINTER_ADDRS
+        .iter()
+        .zip(LEGACY_WHITELIST_STAGE_MINT_COUNTS.iter())
+        .enumerate()
+    {
+        let stage_id = i as u32 + 1;
+        let counts = minter_addrs
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (addr, count) in counts {
+            WHITELIST_STAGE_MINTER_ADDRS.save(storage, (&addr, stage_id), &count)?;
+            minter_addrs.remove(storage, &addr);
+        }
+        if let Some(count) = mint_count.may_load(storage)? {
+            WHITELIST_STAGE_MINT_COUNT.save(storage, stage_id, &count)?;
+            mint_count.remove(storage);
+        }
+    }
+    Ok(())
+}
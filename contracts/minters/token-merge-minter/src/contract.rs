@@ -356,6 +356,7 @@ pub fn execute_shuffle(
         factory_params.shuffle_fee.amount.u128(),
         None,
         &mut res,
+        &factory_params.fee_routing,
     )?;
 
     // Check not sold out
@@ -521,6 +522,7 @@ fn _execute_mint(
             &mut res,
             false,
             None,
+            &factory_params.fee_routing,
         )?;
     }
 
@@ -554,6 +556,7 @@ fn _execute_mint(
             config.extension.base_token_uri, mintable_token_mapping.token_id
         )),
         extension: None,
+        royalty_info: None,
     };
     let msg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: sg721_address.to_string(),
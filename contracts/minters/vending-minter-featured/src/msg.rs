@@ -10,7 +10,9 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint {},
+    Mint {
+        quantity: Option<u32>,
+    },
     SetWhitelist {
         whitelist: String,
     },
@@ -26,6 +28,7 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
     },
     MintFor {
         token_id: u32,
@@ -14,6 +14,7 @@ pub enum ExecuteMsg {
         stage: Option<u32>,
         proof_hashes: Option<Vec<String>>,
         allocation: Option<u32>,
+        quantity: Option<u32>,
     },
     SetWhitelist {
         whitelist: String,
@@ -30,6 +31,7 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
     },
     MintFor {
         token_id: u32,
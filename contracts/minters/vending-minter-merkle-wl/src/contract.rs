@@ -4,17 +4,17 @@ use crate::msg::{
     QueryMsg, StartTimeResponse,
 };
 use crate::state::{
-    Config, ConfigExtension, AIRDROP_COUNT, CONFIG, LAST_DISCOUNT_TIME, MINTABLE_NUM_TOKENS,
-    MINTABLE_TOKEN_POSITIONS, MINTER_ADDRS, SG721_ADDRESS, STATUS, WHITELIST_FS_MINTER_ADDRS,
-    WHITELIST_FS_MINT_COUNT, WHITELIST_MINTER_ADDRS, WHITELIST_SS_MINTER_ADDRS,
-    WHITELIST_SS_MINT_COUNT, WHITELIST_TS_MINTER_ADDRS, WHITELIST_TS_MINT_COUNT,
+    migrate_whitelist_stage_mint_counts, Config, ConfigExtension, AIRDROP_COUNT, CONFIG,
+    LAST_DISCOUNT_TIME, MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_POSITIONS, MINTER_ADDRS, SG721_ADDRESS,
+    STATUS, WHITELIST_IS_TIERED, WHITELIST_MINTER_ADDRS, WHITELIST_STAGE_MINTER_ADDRS,
+    WHITELIST_STAGE_MINT_COUNT,
 };
 use crate::validation::{check_dynamic_per_address_limit, get_three_percent_of_tokens};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut,
-    Empty, Env, Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
+    coin, ensure, to_json_binary, Addr, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty,
+    Env, Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
     Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
@@ -22,8 +22,9 @@ use cw721_base::Extension;
 use cw_utils::{may_pay, maybe_addr, nonpayable, parse_reply_instantiate_data};
 use nois::{int_in_range, shuffle};
 use semver::Version;
-use sg1::{checked_fair_burn, distribute_mint_fees};
+use sg1::{checked_fair_burn, distribute_mint_fees, payment_msg};
 use sg2::query::Sg2QueryMsg;
+use sg2::{is_tiered_whitelist, validate_whitelist_stages};
 use sg4::{MinterConfig, Status, StatusResponse, SudoMsg};
 use sg721::{ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
 use sg_utils::GENESIS_MINT_START_TIME;
@@ -34,7 +35,7 @@ use sha2::{Digest, Sha256};
 use std::convert::TryInto;
 use tiered_whitelist_merkletree::msg::{QueryMsg as TieredWhitelistQueryMsg, StageResponse};
 use url::Url;
-use vending_factory::msg::{ParamsResponse, VendingMinterCreateMsg};
+use vending_factory::msg::{ParamsResponse, RandomnessSource, VendingMinterCreateMsg};
 use vending_factory::state::VendingMinterParams;
 use whitelist_mtree::msg::QueryMsg as WhitelistMtreeQueryMsg;
 
@@ -67,6 +68,21 @@ pub fn instantiate(
         .query_wasm_smart(factory.clone(), &Sg2QueryMsg::Params {})?;
     let factory_params = factory_response.params;
 
+    // Dutch auctions, additional mint prices, deferred randomness and escrow are only supported by
+    // the base vending minter
+    if msg.init_msg.dutch_auction.is_some() {
+        return Err(ContractError::DutchAuctionNotSupported {});
+    }
+    if !msg.init_msg.additional_mint_prices.is_empty() {
+        return Err(ContractError::AdditionalMintPricesNotSupported {});
+    }
+    if msg.init_msg.randomness != RandomnessSource::BlockData {
+        return Err(ContractError::RandomnessSourceNotSupported {});
+    }
+    if msg.init_msg.escrow.is_some() {
+        return Err(ContractError::EscrowNotSupported {});
+    }
+
     // set default status so it can be queried without failing
     STATUS.save(deps.storage, &Status::default())?;
 
@@ -114,6 +130,9 @@ pub fn instantiate(
         .and_then(|w| deps.api.addr_validate(w.as_str()).ok());
 
     if let Some(wl) = whitelist_addr.clone() {
+        let is_tiered_whitelist =
+            validate_whitelist_stages(&deps.querier, &wl, factory_params.max_whitelist_stages)?;
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
         // check the whitelist exists
         let res: WhitelistConfigResponse = deps
             .querier
@@ -220,7 +239,16 @@ pub fn execute(
             stage,
             proof_hashes,
             allocation,
-        } => execute_mint_sender(deps, env, info, stage, proof_hashes, allocation),
+            quantity,
+        } => execute_mint_sender(
+            deps,
+            env,
+            info,
+            stage,
+            proof_hashes,
+            allocation,
+            quantity.unwrap_or(1),
+        ),
         ExecuteMsg::Purge {} => execute_purge(deps, env, info),
         ExecuteMsg::UpdateMintPrice { price } => execute_update_mint_price(deps, env, info, price),
         ExecuteMsg::UpdateStartTime(time) => execute_update_start_time(deps, env, info, time),
@@ -230,7 +258,10 @@ pub fn execute(
         ExecuteMsg::UpdatePerAddressLimit { per_address_limit } => {
             execute_update_per_address_limit(deps, env, info, per_address_limit)
         }
-        ExecuteMsg::MintTo { recipient } => execute_mint_to(deps, env, info, recipient),
+        ExecuteMsg::MintTo {
+            recipient,
+            quantity,
+        } => execute_mint_to(deps, env, info, recipient, quantity.unwrap_or(1)),
         ExecuteMsg::MintFor {
             token_id,
             recipient,
@@ -375,6 +406,7 @@ pub fn execute_shuffle(
         factory_params.extension.shuffle_fee.amount.u128(),
         None,
         &mut res,
+        &factory_params.fee_routing,
     )?;
 
     // Check not sold out
@@ -447,7 +479,7 @@ pub fn execute_set_whitelist(
         ..
     } = deps
         .querier
-        .query_wasm_smart(new_wl, &WhitelistQueryMsg::Config {})?;
+        .query_wasm_smart(&new_wl, &WhitelistQueryMsg::Config {})?;
 
     ensure!(!wl_is_active, ContractError::WhitelistAlreadyStarted {});
 
@@ -464,11 +496,15 @@ pub fn execute_set_whitelist(
         params:
             VendingMinterParams {
                 min_mint_price: factory_min_mint_price,
+                max_whitelist_stages,
                 ..
             },
     } = deps
         .querier
         .query_wasm_smart(factory, &Sg2QueryMsg::Params {})?;
+    let is_tiered_whitelist =
+        validate_whitelist_stages(&deps.querier, &new_wl, max_whitelist_stages)?;
+    WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
 
     ensure!(
         factory_min_mint_price.amount <= wl_mint_price.amount,
@@ -501,12 +537,20 @@ pub fn execute_mint_sender(
     stage: Option<u32>,
     proof_hashes: Option<Vec<String>>,
     allocation: Option<u32>,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let action = "mint_sender";
 
     // If there is no active whitelist right now, check public mint
-    let is_public_mint = is_public_mint(deps.as_ref(), &info, stage, proof_hashes, allocation)?;
+    let is_public_mint = is_public_mint(
+        deps.as_ref(),
+        &info,
+        stage,
+        proof_hashes,
+        allocation,
+        quantity,
+    )?;
     // Check if after start_time
     if is_public_mint && (env.block.time < config.extension.start_time) {
         return Err(ContractError::BeforeMintStartTime {});
@@ -515,11 +559,21 @@ pub fn execute_mint_sender(
     // Check if already minted max per address limit
     let mint_count = mint_count(deps.as_ref(), &info)?;
 
-    if is_public_mint && mint_count >= config.extension.per_address_limit {
+    if is_public_mint && mint_count + quantity > config.extension.per_address_limit {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    _execute_mint(deps, env, info, action, false, None, None, is_public_mint)
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        false,
+        None,
+        None,
+        is_public_mint,
+        quantity,
+    )
 }
 
 // Check if a whitelist exists and not ended
@@ -530,6 +584,7 @@ fn is_public_mint(
     stage: Option<u32>,
     proof_hashes: Option<Vec<String>>,
     allocation: Option<u32>,
+    quantity: u32,
 ) -> Result<bool, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -584,11 +639,11 @@ fn is_public_mint(
         Some(allocation) => allocation,
         None => wl_config.per_address_limit,
     };
-    if wl_mint_count.0 >= max_count {
+    if wl_mint_count.0 + quantity > max_count {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    // Check if whitelist stage mint count limit is reached
+    // Check if whitelist stage mint count limit would be exceeded
     if wl_mint_count.1 && wl_mint_count.2.is_some() {
         let active_stage: StageResponse = deps.querier.query_wasm_smart(
             whitelist.clone(),
@@ -597,13 +652,10 @@ fn is_public_mint(
             },
         )?;
         if active_stage.stage.mint_count_limit.is_some() {
-            let stage_mint_count = match wl_mint_count.2.unwrap() {
-                1 => WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                2 => WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                3 => WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                _ => return Err(ContractError::InvalidStageID {}),
-            };
-            if stage_mint_count >= active_stage.stage.mint_count_limit.unwrap() {
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, wl_mint_count.2.unwrap())?
+                .unwrap_or(0);
+            if stage_mint_count + quantity > active_stage.stage.mint_count_limit.unwrap() {
                 return Err(ContractError::WhitelistMintCountLimitReached {});
             }
         }
@@ -621,6 +673,7 @@ pub fn execute_mint_to(
     env: Env,
     info: MessageInfo,
     recipient: String,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let recipient = deps.api.addr_validate(&recipient)?;
     let config = CONFIG.load(deps.storage)?;
@@ -633,7 +686,17 @@ pub fn execute_mint_to(
         ));
     }
 
-    _execute_mint(deps, env, info, action, true, Some(recipient), None, true)
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        Some(recipient),
+        None,
+        true,
+        quantity,
+    )
 }
 
 pub fn execute_mint_for(
@@ -663,6 +726,7 @@ pub fn execute_mint_for(
         Some(recipient),
         Some(token_id),
         true,
+        1,
     )
 }
 
@@ -670,9 +734,10 @@ pub fn execute_mint_for(
 // mint -> _execute_mint(recipient: None, token_id: None)
 // mint_to(recipient: "friend") -> _execute_mint(Some(recipient), token_id: None)
 // mint_for(recipient: "friend2", token_id: 420) -> _execute_mint(recipient, token_id)
+// A quantity > 1 mints a batch of random tokens for a single payment of quantity * mint price
 #[allow(clippy::too_many_arguments)]
 fn _execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     action: &str,
@@ -680,11 +745,18 @@ fn _execute_mint(
     recipient: Option<Addr>,
     token_id: Option<u32>,
     is_public: bool,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
     if mintable_num_tokens == 0 {
         return Err(ContractError::SoldOut {});
     }
+    if quantity == 0 || quantity > mintable_num_tokens || (token_id.is_some() && quantity != 1) {
+        return Err(ContractError::InvalidMintQuantity {
+            max: mintable_num_tokens,
+            got: quantity,
+        });
+    }
 
     let config = CONFIG.load(deps.storage)?;
 
@@ -701,7 +773,15 @@ fn _execute_mint(
         None => info.sender.clone(),
     };
 
-    let mint_price: Coin = mint_price(deps.as_ref(), is_admin)?;
+    let unit_price: Coin = mint_price(deps.as_ref(), is_admin)?;
+    let mint_price = coin(
+        unit_price
+            .amount
+            .checked_mul(Uint128::from(quantity))
+            .map_err(StdError::overflow)?
+            .u128(),
+        unit_price.denom,
+    );
     // Exact payment only accepted
     let payment = may_pay(&info, &mint_price.denom)?;
     if payment != mint_price.amount {
@@ -733,72 +813,81 @@ fn _execute_mint(
             &mut res,
             false,
             None,
+            &factory_params.fee_routing,
         )?;
     }
 
-    let mintable_token_mapping = match token_id {
-        Some(token_id) => {
-            // set position to invalid value, iterate to find matching token_id
-            // if token_id not found, token_id is already sold, position is unchanged and throw err
-            // otherwise return position and token_id
-            let mut position = 0;
-            for res in MINTABLE_TOKEN_POSITIONS.range(deps.storage, None, None, Order::Ascending) {
-                let (pos, id) = res?;
-                if id == token_id {
-                    position = pos;
-                    break;
+    let mut minted_token_ids = Vec::with_capacity(quantity as usize);
+    for _ in 0..quantity {
+        let mintable_token_mapping = match token_id {
+            Some(token_id) => {
+                // set position to invalid value, iterate to find matching token_id
+                // if token_id not found, token_id is already sold, position is unchanged and throw err
+                // otherwise return position and token_id
+                let mut position = 0;
+                for res in
+                    MINTABLE_TOKEN_POSITIONS.range(deps.storage, None, None, Order::Ascending)
+                {
+                    let (pos, id) = res?;
+                    if id == token_id {
+                        position = pos;
+                        break;
+                    }
                 }
+                if position == 0 {
+                    return Err(ContractError::TokenIdAlreadySold { token_id });
+                }
+                TokenPositionMapping { position, token_id }
             }
-            if position == 0 {
-                return Err(ContractError::TokenIdAlreadySold { token_id });
-            }
-            TokenPositionMapping { position, token_id }
-        }
-        None => random_mintable_token_mapping(deps.as_ref(), env, info.sender.clone())?,
-    };
-
-    // Create mint msgs
-    let mint_msg = Sg721ExecuteMsg::<Extension, Empty>::Mint {
-        token_id: mintable_token_mapping.token_id.to_string(),
-        owner: recipient_addr.to_string(),
-        token_uri: Some(format!(
-            "{}/{}",
-            config.extension.base_token_uri, mintable_token_mapping.token_id
-        )),
-        extension: None,
-    };
-    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: sg721_address.to_string(),
-        msg: to_json_binary(&mint_msg)?,
-        funds: vec![],
-    });
-    res = res.add_message(msg);
+            None => random_mintable_token_mapping(deps.as_ref(), env.clone(), info.sender.clone())?,
+        };
+
+        // Create mint msgs
+        let mint_msg = Sg721ExecuteMsg::<Extension, Empty>::Mint {
+            token_id: mintable_token_mapping.token_id.to_string(),
+            owner: recipient_addr.to_string(),
+            token_uri: Some(format!(
+                "{}/{}",
+                config.extension.base_token_uri, mintable_token_mapping.token_id
+            )),
+            extension: None,
+            royalty_info: None,
+        };
+        let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: sg721_address.to_string(),
+            msg: to_json_binary(&mint_msg)?,
+            funds: vec![],
+        });
+        res = res.add_message(msg);
 
-    // Remove mintable token position from map
-    MINTABLE_TOKEN_POSITIONS.remove(deps.storage, mintable_token_mapping.position);
-    let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
-    // Decrement mintable num tokens
-    MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+        // Remove mintable token position from map
+        MINTABLE_TOKEN_POSITIONS.remove(deps.storage, mintable_token_mapping.position);
+        let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+        // Decrement mintable num tokens
+        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+        minted_token_ids.push(mintable_token_mapping.token_id);
+    }
 
     // Update the airdrop count if is_admin
     if is_admin {
         let current_airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or(0);
-        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + 1))?;
+        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + quantity))?;
     }
 
     if is_public {
-        let new_mint_count = mint_count(deps.as_ref(), &info)? + 1;
+        let new_mint_count = mint_count(deps.as_ref(), &info)? + quantity;
         MINTER_ADDRS.save(deps.storage, &info.sender, &new_mint_count)?;
     } else {
         let whitelist_addr = config.extension.whitelist.unwrap();
         // Fetch and increment the mint count for the current whitelist stage
         let wl_mint_count_response = whitelist_mint_count(deps.as_ref(), &info, whitelist_addr)?;
         save_whitelist_mint_count(
-            deps,
+            deps.branch(),
             &info,
             wl_mint_count_response.1,
             wl_mint_count_response.2,
-            wl_mint_count_response.0 + 1,
+            wl_mint_count_response.0 + quantity,
+            quantity,
         )?
     }
 
@@ -808,10 +897,11 @@ fn _execute_mint(
         let seller = config.extension.admin;
         // Sending 0 coins fails, so only send if amount is non-zero
         if !amount.is_zero() {
-            let msg = BankMsg::Send {
-                to_address: payment_address.unwrap_or(seller).to_string(),
-                amount: vec![coin(amount.u128(), mint_price.clone().denom)],
-            };
+            let msg = payment_msg(
+                &deps.querier,
+                &payment_address.unwrap_or(seller),
+                vec![coin(amount.u128(), mint_price.clone().denom)],
+            )?;
             res = res.add_message(msg);
         }
         amount
@@ -823,7 +913,12 @@ fn _execute_mint(
         .add_attribute("action", action)
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient_addr)
-        .add_attribute("token_id", mintable_token_mapping.token_id.to_string())
+        .add_attributes(
+            minted_token_ids
+                .iter()
+                .map(|token_id| ("token_id", token_id.to_string())),
+        )
+        .add_attribute("quantity", quantity.to_string())
         .add_attribute(
             "network_fee",
             coin(network_fee.u128(), mint_price.clone().denom).to_string(),
@@ -1164,41 +1259,25 @@ fn whitelist_mint_count(
     info: &MessageInfo,
     whitelist_addr: Addr,
 ) -> Result<(u32, bool, Option<u32>), StdError> {
-    let is_tiered_whitelist = cw2::query_contract_info(&deps.querier, whitelist_addr.clone())
-        .map(|info| info.contract.contains("tiered-whitelist"))
-        .unwrap_or(false);
+    let is_tiered_whitelist = WHITELIST_IS_TIERED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
 
     if is_tiered_whitelist {
-        let active_stage_id = deps
+        let active_stage_id: u32 = deps
             .querier
-            .query_wasm_smart(whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
-        match active_stage_id {
-            1 => Ok((
-                WHITELIST_FS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(1),
-            )),
-            2 => Ok((
-                WHITELIST_SS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(2),
-            )),
-            3 => Ok((
-                WHITELIST_TS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(3),
-            )),
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+            .query_wasm_smart(&whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
+        if active_stage_id == 0 {
+            return Err(StdError::generic_err("Invalid stage ID"));
         }
+
+        Ok((
+            WHITELIST_STAGE_MINTER_ADDRS
+                .may_load(deps.storage, (&info.sender, active_stage_id))?
+                .unwrap_or(0),
+            true,
+            Some(active_stage_id),
+        ))
     } else {
         Ok((
             WHITELIST_MINTER_ADDRS
@@ -1217,34 +1296,17 @@ fn save_whitelist_mint_count(
     is_tiered_whitelist: bool,
     stage_id: Option<u32>,
     count: u32,
+    quantity: u32,
 ) -> StdResult<()> {
-    if is_tiered_whitelist & stage_id.is_some() {
-        match stage_id {
-            Some(1) => {
-                let _ = WHITELIST_FS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_fs_mint_count =
-                    WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_fs_mint_count += 1;
-                WHITELIST_FS_MINT_COUNT.save(deps.storage, &wl_fs_mint_count)
-            }
-            Some(2) => {
-                let _ = WHITELIST_SS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ss_mint_count =
-                    WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ss_mint_count += 1;
-                WHITELIST_SS_MINT_COUNT.save(deps.storage, &wl_ss_mint_count)
-            }
-            Some(3) => {
-                let _ = WHITELIST_TS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ts_mint_count =
-                    WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ts_mint_count += 1;
-                WHITELIST_TS_MINT_COUNT.save(deps.storage, &wl_ts_mint_count)
-            }
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+    match stage_id {
+        Some(stage_id) if is_tiered_whitelist => {
+            WHITELIST_STAGE_MINTER_ADDRS.save(deps.storage, (&info.sender, stage_id), &count)?;
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, stage_id)?
+                .unwrap_or(0);
+            WHITELIST_STAGE_MINT_COUNT.save(deps.storage, stage_id, &(stage_mint_count + quantity))
         }
-    } else {
-        WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count)
+        _ => WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count),
     }
 }
 
@@ -1333,18 +1395,11 @@ fn query_mint_count(deps: Deps, address: String) -> StdResult<MintCountResponse>
     let mint_count = (MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
     let standard_wl_count =
         (WHITELIST_MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
-    let tiered_wl_count = (WHITELIST_FS_MINTER_ADDRS
-        .key(&addr)
-        .may_load(deps.storage)?)
-    .unwrap_or(0)
-        + (WHITELIST_SS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0)
-        + (WHITELIST_TS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0);
+    let tiered_wl_count = WHITELIST_STAGE_MINTER_ADDRS
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, count)| count))
+        .sum::<StdResult<u32>>()?;
     Ok(MintCountResponse {
         address: addr.to_string(),
         count: mint_count + standard_wl_count + tiered_wl_count,
@@ -1443,6 +1498,14 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: Empty) -> Result<Response, Contrac
         LAST_DISCOUNT_TIME.save(deps.storage, &last_discount_time)?;
     }
 
+    // tiered whitelist stage mint counts are keyed by stage id
+    migrate_whitelist_stage_mint_counts(deps.storage)?;
+    // whether the whitelist is tiered is decided when it is set
+    if let Some(whitelist) = CONFIG.load(deps.storage)?.extension.whitelist {
+        let is_tiered_whitelist = is_tiered_whitelist(&deps.querier, &whitelist);
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let event = Event::new("migrate")
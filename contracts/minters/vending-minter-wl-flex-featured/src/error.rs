@@ -44,6 +44,21 @@ pub enum ContractError {
     #[error("Sold out")]
     SoldOut {},
 
+    #[error("Invalid mint quantity {got}, must be between 1 and {max}")]
+    InvalidMintQuantity { max: u32, got: u32 },
+
+    #[error("Dutch auction pricing is not supported by this minter")]
+    DutchAuctionNotSupported {},
+
+    #[error("Additional mint prices are not supported by this minter")]
+    AdditionalMintPricesNotSupported {},
+
+    #[error("Commit-reveal and beacon randomness are not supported by this minter")]
+    RandomnessSourceNotSupported {},
+
+    #[error("Escrowed proceeds are not supported by this minter")]
+    EscrowNotSupported {},
+
     #[error("Not sold out")]
     NotSoldOut {},
 
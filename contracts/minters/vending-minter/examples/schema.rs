@@ -5,8 +5,9 @@ use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use sg4::StatusResponse;
 use vending_minter::msg::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MintCountResponse, MintPriceResponse,
-    MintableNumTokensResponse, QueryMsg, StartTimeResponse,
+    ConfigResponse, EscrowResponse, ExecuteMsg, InstantiateMsg, MintCountResponse,
+    MintPriceResponse, MintableNumTokensResponse, PendingMintResponse, QueryMsg, RebateResponse,
+    StartTimeResponse, TokenEscrowResponse,
 };
 use vending_minter::state::Config;
 
@@ -26,4 +27,8 @@ fn main() {
     export_schema(&schema_for!(StartTimeResponse), &out_dir);
     export_schema(&schema_for!(MintPriceResponse), &out_dir);
     export_schema(&schema_for!(StatusResponse), &out_dir);
+    export_schema(&schema_for!(RebateResponse), &out_dir);
+    export_schema(&schema_for!(PendingMintResponse), &out_dir);
+    export_schema(&schema_for!(EscrowResponse), &out_dir);
+    export_schema(&schema_for!(TokenEscrowResponse), &out_dir);
 }
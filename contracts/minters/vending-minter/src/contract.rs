@@ -1,32 +1,45 @@
 use crate::error::ContractError;
 use crate::msg::{
-    ConfigResponse, ExecuteMsg, MintCountResponse, MintPriceResponse, MintableNumTokensResponse,
-    QueryMsg, StartTimeResponse,
+    BeaconExecuteMsg, ConfigResponse, EscrowResponse, ExecuteMsg, MintCountResponse,
+    MintPriceResponse, MintableNumTokensResponse, NoisCallback, PendingMintResponse, QueryMsg,
+    RebateResponse, StartTimeResponse, TokenEscrowResponse,
 };
 use crate::state::{
-    Config, ConfigExtension, AIRDROP_COUNT, CONFIG, LAST_DISCOUNT_TIME, MINTABLE_NUM_TOKENS,
-    MINTABLE_TOKEN_POSITIONS, MINTER_ADDRS, SG721_ADDRESS, STATUS, WHITELIST_FS_MINTER_ADDRS,
-    WHITELIST_FS_MINT_COUNT, WHITELIST_MINTER_ADDRS, WHITELIST_SS_MINTER_ADDRS,
-    WHITELIST_SS_MINT_COUNT, WHITELIST_TS_MINTER_ADDRS, WHITELIST_TS_MINT_COUNT,
+    migrate_whitelist_stage_mint_counts, AuctionBid, Config, ConfigExtension, Escrow, PendingMint,
+    AIRDROP_COUNT, AUCTION_BIDS, AUCTION_LAST_PRICE, CONFIG, ESCROW, LAST_DISCOUNT_TIME,
+    MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_POSITIONS, MINTER_ADDRS, PENDING_MINTS, PENDING_MINT_ID,
+    PENDING_NUM_TOKENS, SG721_ADDRESS, STATUS, TOKEN_ESCROWS, WHITELIST_IS_TIERED,
+    WHITELIST_MINTER_ADDRS, WHITELIST_STAGE_MINTER_ADDRS, WHITELIST_STAGE_MINT_COUNT,
+};
+use crate::validation::{
+    check_dynamic_per_address_limit, get_three_percent_of_tokens, validate_dutch_auction,
+    validate_escrow, validate_randomness_source,
 };
-use crate::validation::{check_dynamic_per_address_limit, get_three_percent_of_tokens};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     coin, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut,
-    Empty, Env, Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
-    Timestamp, Uint128, WasmMsg,
+    Empty, Env, Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, Storage,
+    SubMsg, Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw721::{Cw721QueryMsg, OwnerOfResponse};
 use cw721_base::Extension;
+use cw_storage_plus::Bound;
 use cw_utils::{may_pay, maybe_addr, nonpayable, parse_reply_instantiate_data};
-use nois::{int_in_range, shuffle};
+use nois::{int_in_range, shuffle, sub_randomness};
 
 use semver::Version;
-use sg1::{checked_fair_burn, distribute_mint_fees};
+use sg1::{checked_fair_burn, distribute_mint_fees, payment_msg};
 use sg2::query::Sg2QueryMsg;
+use sg2::{is_tiered_whitelist, validate_whitelist_stages};
 use sg4::{MinterConfig, Status, StatusResponse, SudoMsg};
-use sg721::{ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
+use sg721::{
+    ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg, RoyaltyInfoResponse,
+};
+use sg_mint_hooks::post::{add_postmint_hook, prepare_postmint_hooks, query_postmint_hooks};
+use sg_mint_hooks::pre::{add_premint_hook, prepare_premint_hooks, query_premint_hooks};
+use sg_mint_hooks::{handle_reply, remove_hook};
 use sg_tiered_whitelist::msg::{QueryMsg as TieredWhitelistQueryMsg, StageResponse};
 use sg_utils::GENESIS_MINT_START_TIME;
 use sg_whitelist::msg::{
@@ -35,7 +48,10 @@ use sg_whitelist::msg::{
 use sha2::{Digest, Sha256};
 use std::convert::TryInto;
 use url::Url;
-use vending_factory::msg::{ParamsResponse, VendingMinterCreateMsg};
+use vending_factory::msg::{
+    DutchAuctionParams, EscrowMilestone, EscrowParams, ParamsResponse, RandomnessSource,
+    VendingMinterCreateMsg,
+};
 use vending_factory::state::VendingMinterParams;
 
 pub struct TokenPositionMapping {
@@ -49,6 +65,12 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const INSTANTIATE_SG721_REPLY_ID: u64 = 1;
 
+// Blocks after the reveal height during which a commit-reveal pending mint is seeded with the
+// revealing block's data, bounding how many outcomes a revealer can choose from
+pub const REVEAL_WINDOW_BLOCKS: u64 = 20;
+// Blocks after a beacon pending mint was committed before it can be revealed without the beacon
+pub const BEACON_TIMEOUT_BLOCKS: u64 = 1_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -114,6 +136,9 @@ pub fn instantiate(
         .and_then(|w| deps.api.addr_validate(w.as_str()).ok());
 
     if let Some(wl) = whitelist_addr.clone() {
+        let is_tiered_whitelist =
+            validate_whitelist_stages(&deps.querier, &wl, factory_params.max_whitelist_stages)?;
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
         // check the whitelist exists
         let res: WhitelistConfigResponse = deps
             .querier
@@ -123,6 +148,43 @@ pub fn instantiate(
         }
     }
 
+    if let Some(auction) = &msg.init_msg.dutch_auction {
+        validate_dutch_auction(auction, &factory_params.min_mint_price)?;
+        if !msg.init_msg.additional_mint_prices.is_empty() {
+            return Err(ContractError::InvalidDutchAuction(
+                "additional mint prices are not supported".to_string(),
+            ));
+        }
+    }
+    validate_randomness_source(deps.api, &msg.init_msg.randomness)?;
+
+    if let Some(escrow) = &msg.init_msg.escrow {
+        validate_escrow(escrow, msg.init_msg.start_time)?;
+        // Proceeds are escrowed per token, which isn't known at mint time with deferred randomness
+        if msg.init_msg.randomness != RandomnessSource::BlockData {
+            return Err(ContractError::InvalidEscrow(
+                "deferred randomness is not supported".to_string(),
+            ));
+        }
+        if msg
+            .init_msg
+            .dutch_auction
+            .as_ref()
+            .is_some_and(|a| a.rebate)
+        {
+            return Err(ContractError::InvalidEscrow(
+                "dutch auction rebates are not supported".to_string(),
+            ));
+        }
+        ESCROW.save(deps.storage, &Escrow::default())?;
+    }
+
+    // A dutch auction starts selling at its start price
+    let mint_price = match &msg.init_msg.dutch_auction {
+        Some(auction) => auction.start_price.clone(),
+        None => msg.init_msg.mint_price,
+    };
+
     // Use default start trading time if not provided
     let mut collection_info = msg.collection_params.info.clone();
     let offset = factory_params.max_trading_offset_secs;
@@ -157,8 +219,12 @@ pub fn instantiate(
             whitelist: whitelist_addr,
             start_time: msg.init_msg.start_time,
             discount_price: None,
+            dutch_auction: msg.init_msg.dutch_auction,
+            additional_mint_prices: msg.init_msg.additional_mint_prices,
+            randomness: msg.init_msg.randomness,
+            escrow: msg.init_msg.escrow,
         },
-        mint_price: msg.init_msg.mint_price,
+        mint_price,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -216,7 +282,9 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint {} => execute_mint_sender(deps, env, info),
+        ExecuteMsg::Mint { quantity } => {
+            execute_mint_sender(deps, env, info, quantity.unwrap_or(1))
+        }
         ExecuteMsg::Purge {} => execute_purge(deps, env, info),
         ExecuteMsg::UpdateMintPrice { price } => execute_update_mint_price(deps, env, info, price),
         ExecuteMsg::UpdateStartTime(time) => execute_update_start_time(deps, env, info, time),
@@ -226,11 +294,23 @@ pub fn execute(
         ExecuteMsg::UpdatePerAddressLimit { per_address_limit } => {
             execute_update_per_address_limit(deps, env, info, per_address_limit)
         }
-        ExecuteMsg::MintTo { recipient } => execute_mint_to(deps, env, info, recipient),
+        ExecuteMsg::MintTo {
+            recipient,
+            quantity,
+            royalty_info,
+        } => execute_mint_to(
+            deps,
+            env,
+            info,
+            recipient,
+            quantity.unwrap_or(1),
+            royalty_info,
+        ),
         ExecuteMsg::MintFor {
             token_id,
             recipient,
-        } => execute_mint_for(deps, env, info, token_id, recipient),
+            royalty_info,
+        } => execute_mint_for(deps, env, info, token_id, recipient, royalty_info),
         ExecuteMsg::SetWhitelist { whitelist } => {
             execute_set_whitelist(deps, env, info, &whitelist)
         }
@@ -240,9 +320,66 @@ pub fn execute(
             execute_update_discount_price(deps, env, info, price)
         }
         ExecuteMsg::RemoveDiscountPrice {} => execute_remove_discount_price(deps, env, info),
+        ExecuteMsg::AddPreMintHook { hook } => execute_add_premint_hook(deps, info, hook),
+        ExecuteMsg::AddPostMintHook { hook } => execute_add_postmint_hook(deps, info, hook),
+        ExecuteMsg::RemoveHook { hook } => execute_remove_hook(deps, info, hook),
+        ExecuteMsg::ClaimRebate { address } => execute_claim_rebate(deps, env, info, address),
+        ExecuteMsg::RevealMint { id } => execute_reveal_mint(deps, env, info, id),
+        ExecuteMsg::CancelPendingMint { id } => execute_cancel_pending_mint(deps, env, info, id),
+        ExecuteMsg::NoisReceive { callback } => execute_nois_receive(deps, info, callback),
+        ExecuteMsg::ReleaseEscrow {} => execute_release_escrow(deps, env, info),
+        ExecuteMsg::ClaimRefund { token_id } => execute_claim_refund(deps, env, info, token_id),
     }
 }
 
+pub fn execute_add_premint_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hook: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+
+    Ok(add_premint_hook(deps, hook)?)
+}
+
+pub fn execute_add_postmint_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hook: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+
+    Ok(add_postmint_hook(deps, hook)?)
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hook: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+
+    Ok(remove_hook(deps, hook)?)
+}
+
 pub fn execute_update_discount_price(
     deps: DepsMut,
     env: Env,
@@ -256,6 +393,9 @@ pub fn execute_update_discount_price(
             "Sender is not an admin".to_owned(),
         ));
     }
+    if config.extension.dutch_auction.is_some() {
+        return Err(ContractError::DutchAuctionPriceLocked {});
+    }
     if env.block.time < config.extension.start_time {
         return Err(ContractError::BeforeMintStartTime {});
     }
@@ -371,6 +511,7 @@ pub fn execute_shuffle(
         factory_params.extension.shuffle_fee.amount.u128(),
         None,
         &mut res,
+        &factory_params.fee_routing,
     )?;
 
     // Check not sold out
@@ -443,7 +584,7 @@ pub fn execute_set_whitelist(
         ..
     } = deps
         .querier
-        .query_wasm_smart(new_wl, &WhitelistQueryMsg::Config {})?;
+        .query_wasm_smart(&new_wl, &WhitelistQueryMsg::Config {})?;
 
     ensure!(!wl_is_active, ContractError::WhitelistAlreadyStarted {});
 
@@ -460,11 +601,15 @@ pub fn execute_set_whitelist(
         params:
             VendingMinterParams {
                 min_mint_price: factory_min_mint_price,
+                max_whitelist_stages,
                 ..
             },
     } = deps
         .querier
         .query_wasm_smart(factory, &Sg2QueryMsg::Params {})?;
+    let is_tiered_whitelist =
+        validate_whitelist_stages(&deps.querier, &new_wl, max_whitelist_stages)?;
+    WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
 
     ensure!(
         factory_min_mint_price.amount <= wl_mint_price.amount,
@@ -494,29 +639,32 @@ pub fn execute_mint_sender(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    quantity: u32,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let action = "mint_sender";
 
     // If there is no active whitelist right now, check public mint
     // Check if after start_time
-    let is_public = is_public_mint(deps.as_ref(), &info)?;
+    let is_public = is_public_mint(deps.as_ref(), &info, quantity)?;
     if is_public && (env.block.time < config.extension.start_time) {
         return Err(ContractError::BeforeMintStartTime {});
     }
 
-    // Check if already minted max per address limit
+    // Check if the batch would exceed the max per address limit
     let mint_count = mint_count(deps.as_ref(), &info)?;
-    if is_public && mint_count >= config.extension.per_address_limit {
+    if is_public && mint_count + quantity > config.extension.per_address_limit {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    _execute_mint(deps, env, info, action, false, None, None, is_public)
+    _execute_mint(
+        deps, env, info, action, false, None, None, is_public, quantity, None,
+    )
 }
 
 // Check if a whitelist exists and not ended
 // Sender has to be whitelisted to mint
-fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError> {
+fn is_public_mint(deps: Deps, info: &MessageInfo, quantity: u32) -> Result<bool, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     // If there is no whitelist, there's only a public mint
@@ -548,12 +696,12 @@ fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError>
 
     let wl_mint_count = whitelist_mint_count(deps, info, whitelist.clone())?;
 
-    // Check if whitelist per address limit is reached
-    if wl_mint_count.0 >= wl_config.per_address_limit {
+    // Check if whitelist per address limit would be exceeded
+    if wl_mint_count.0 + quantity > wl_config.per_address_limit {
         return Err(ContractError::MaxPerAddressLimitExceeded {});
     }
 
-    // Check if whitelist stage mint count limit is reached
+    // Check if whitelist stage mint count limit would be exceeded
     if wl_mint_count.1 && wl_mint_count.2.is_some() {
         let active_stage: StageResponse = deps.querier.query_wasm_smart(
             whitelist.clone(),
@@ -562,13 +710,10 @@ fn is_public_mint(deps: Deps, info: &MessageInfo) -> Result<bool, ContractError>
             },
         )?;
         if active_stage.stage.mint_count_limit.is_some() {
-            let stage_mint_count = match wl_mint_count.2.unwrap() {
-                1 => WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                2 => WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                3 => WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0),
-                _ => return Err(ContractError::InvalidStageID {}),
-            };
-            if stage_mint_count >= active_stage.stage.mint_count_limit.unwrap() {
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, wl_mint_count.2.unwrap())?
+                .unwrap_or(0);
+            if stage_mint_count + quantity > active_stage.stage.mint_count_limit.unwrap() {
                 return Err(ContractError::WhitelistMintCountLimitReached {});
             }
         }
@@ -582,6 +727,8 @@ pub fn execute_mint_to(
     env: Env,
     info: MessageInfo,
     recipient: String,
+    quantity: u32,
+    royalty_info: Option<RoyaltyInfoResponse>,
 ) -> Result<Response, ContractError> {
     let recipient = deps.api.addr_validate(&recipient)?;
     let config = CONFIG.load(deps.storage)?;
@@ -594,7 +741,18 @@ pub fn execute_mint_to(
         ));
     }
 
-    _execute_mint(deps, env, info, action, true, Some(recipient), None, true)
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        Some(recipient),
+        None,
+        true,
+        quantity,
+        royalty_info,
+    )
 }
 
 pub fn execute_mint_for(
@@ -603,6 +761,7 @@ pub fn execute_mint_for(
     info: MessageInfo,
     token_id: u32,
     recipient: String,
+    royalty_info: Option<RoyaltyInfoResponse>,
 ) -> Result<Response, ContractError> {
     let recipient = deps.api.addr_validate(&recipient)?;
     let config = CONFIG.load(deps.storage)?;
@@ -624,6 +783,8 @@ pub fn execute_mint_for(
         Some(recipient),
         Some(token_id),
         true,
+        1,
+        royalty_info,
     )
 }
 
@@ -631,9 +792,11 @@ pub fn execute_mint_for(
 // mint -> _execute_mint(recipient: None, token_id: None)
 // mint_to(recipient: "friend") -> _execute_mint(Some(recipient), token_id: None)
 // mint_for(recipient: "friend2", token_id: 420) -> _execute_mint(recipient, token_id)
+// A quantity > 1 mints a batch of random tokens for a single payment of quantity * mint price
+// Admin mints can override the collection royalty of the minted tokens
 #[allow(clippy::too_many_arguments)]
 fn _execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     action: &str,
@@ -641,14 +804,34 @@ fn _execute_mint(
     recipient: Option<Addr>,
     token_id: Option<u32>,
     is_public: bool,
+    quantity: u32,
+    royalty_info: Option<RoyaltyInfoResponse>,
 ) -> Result<Response, ContractError> {
     let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
     if mintable_num_tokens == 0 {
         return Err(ContractError::SoldOut {});
     }
+    if quantity == 0 || quantity > mintable_num_tokens || (token_id.is_some() && quantity != 1) {
+        return Err(ContractError::InvalidMintQuantity {
+            max: mintable_num_tokens,
+            got: quantity,
+        });
+    }
 
     let config = CONFIG.load(deps.storage)?;
 
+    // Proceeds are held back until the escrow is released, and sales stop once refunds open
+    let holds_proceeds = match &config.extension.escrow {
+        Some(params) => {
+            let escrow = ESCROW.load(deps.storage)?;
+            if !is_admin && escrow_refunds_open(params, &escrow, env.block.time) {
+                return Err(ContractError::RefundsOpen {});
+            }
+            !escrow.released
+        }
+        None => false,
+    };
+
     if let Some(token_id) = token_id {
         if token_id == 0 || token_id > config.extension.num_tokens {
             return Err(ContractError::InvalidTokenId {});
@@ -662,12 +845,24 @@ fn _execute_mint(
         None => info.sender.clone(),
     };
 
-    let mint_price: Coin = mint_price(deps.as_ref(), is_admin)?;
+    // Public buyers can pay in any of the additional denoms instead of the mint price denom
+    let unit_price: Coin = match additional_mint_price(&config, &info) {
+        Some(price) if is_public && !is_admin => price,
+        _ => mint_price(deps.as_ref(), &env, is_admin)?,
+    };
+    let mint_price = coin(
+        unit_price
+            .amount
+            .checked_mul(Uint128::from(quantity))
+            .map_err(StdError::overflow)?
+            .u128(),
+        unit_price.denom.clone(),
+    );
     // Exact payment only accepted
     let payment = may_pay(&info, &mint_price.denom)?;
     if payment != mint_price.amount {
         return Err(ContractError::IncorrectPaymentAmount(
-            coin(payment.u128(), &config.mint_price.denom),
+            coin(payment.u128(), &mint_price.denom),
             mint_price,
         ));
     }
@@ -676,7 +871,7 @@ fn _execute_mint(
 
     let factory: ParamsResponse = deps
         .querier
-        .query_wasm_smart(config.factory, &Sg2QueryMsg::Params {})?;
+        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
     let factory_params = factory.params;
 
     // Create network fee msgs
@@ -688,93 +883,152 @@ fn _execute_mint(
 
     let network_fee = mint_price.amount * mint_fee;
 
-    if !network_fee.is_zero() {
+    // With commit-reveal or beacon randomness, tokens are assigned later by the reveal or the
+    // beacon callback instead of at mint time, and the payment is held until then
+    let is_deferred = token_id.is_none()
+        && !is_admin
+        && config.extension.randomness != RandomnessSource::BlockData;
+
+    if !network_fee.is_zero() && !is_deferred {
         distribute_mint_fees(
             coin(network_fee.u128(), mint_price.clone().denom),
             &mut res,
             false,
             None,
+            &factory_params.fee_routing,
         )?;
     }
 
-    let mintable_token_mapping = match token_id {
-        Some(token_id) => {
-            // set position to invalid value, iterate to find matching token_id
-            // if token_id not found, token_id is already sold, position is unchanged and throw err
-            // otherwise return position and token_id
-            let mut position = 0;
-            for res in MINTABLE_TOKEN_POSITIONS.range(deps.storage, None, None, Order::Ascending) {
-                let (pos, id) = res?;
-                if id == token_id {
-                    position = pos;
-                    break;
+    let mut minted_token_ids = Vec::with_capacity(quantity as usize);
+    if !is_deferred {
+        for _ in 0..quantity {
+            let mintable_token_mapping = match token_id {
+                Some(token_id) => {
+                    // set position to invalid value, iterate to find matching token_id
+                    // if token_id not found, token_id is already sold, position is unchanged and throw err
+                    // otherwise return position and token_id
+                    let mut position = 0;
+                    for res in
+                        MINTABLE_TOKEN_POSITIONS.range(deps.storage, None, None, Order::Ascending)
+                    {
+                        let (pos, id) = res?;
+                        if id == token_id {
+                            position = pos;
+                            break;
+                        }
+                    }
+                    if position == 0 {
+                        return Err(ContractError::TokenIdAlreadySold { token_id });
+                    }
+                    TokenPositionMapping { position, token_id }
                 }
-            }
-            if position == 0 {
-                return Err(ContractError::TokenIdAlreadySold { token_id });
-            }
-            TokenPositionMapping { position, token_id }
-        }
-        None => random_mintable_token_mapping(deps.as_ref(), env, info.sender.clone())?,
-    };
-
-    // Create mint msgs
-    let mint_msg = Sg721ExecuteMsg::<Extension, Empty>::Mint {
-        token_id: mintable_token_mapping.token_id.to_string(),
-        owner: recipient_addr.to_string(),
-        token_uri: Some(format!(
-            "{}/{}",
-            config.extension.base_token_uri, mintable_token_mapping.token_id
-        )),
-        extension: None,
-    };
-    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: sg721_address.to_string(),
-        msg: to_json_binary(&mint_msg)?,
-        funds: vec![],
-    });
-    res = res.add_message(msg);
+                None => {
+                    random_mintable_token_mapping(deps.as_ref(), env.clone(), info.sender.clone())?
+                }
+            };
 
-    // Remove mintable token position from map
-    MINTABLE_TOKEN_POSITIONS.remove(deps.storage, mintable_token_mapping.position);
-    let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
-    // Decrement mintable num tokens
-    MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+            let premint_hooks = token_premint_hooks(
+                deps.as_ref(),
+                &sg721_address,
+                Some(mintable_token_mapping.token_id),
+                &recipient_addr,
+                config.extension.whitelist.as_ref().filter(|_| is_admin),
+            )?;
+            res = mint_token(
+                deps.branch(),
+                res,
+                &config.extension.base_token_uri,
+                &sg721_address,
+                &recipient_addr,
+                &mintable_token_mapping,
+                premint_hooks,
+                royalty_info.clone(),
+            )?;
+
+            let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+            // Decrement mintable num tokens
+            MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+            minted_token_ids.push(mintable_token_mapping.token_id);
+        }
+    }
 
     // Update the airdrop count if is_admin
     if is_admin {
         let current_airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or(0);
-        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + 1))?;
-    }
+        AIRDROP_COUNT.save(deps.storage, &(current_airdrop_count + quantity))?;
+    }
+
+    // Hold back what could be rebated to dutch auction buyers until the clearing price is known
+    let rebate_reserve = match &config.extension.dutch_auction {
+        Some(auction) if auction.rebate && is_public && !is_admin => {
+            let floor = auction_floor(auction, &factory_params.min_mint_price);
+            let reserve = unit_price
+                .amount
+                .saturating_sub(floor)
+                .checked_mul(Uint128::from(quantity))
+                .map_err(StdError::overflow)?
+                .min(mint_price.amount - network_fee);
+
+            // Pending mints count towards the bid once their tokens are assigned
+            if !is_deferred {
+                record_auction_bid(
+                    deps.storage,
+                    &info.sender,
+                    mint_price.amount,
+                    quantity,
+                    reserve,
+                )?;
+            }
+            AUCTION_LAST_PRICE.save(deps.storage, &unit_price.amount)?;
+            Some(reserve)
+        }
+        _ => None,
+    };
 
     // Save the new mint count for the sender's address
     if is_public {
-        let new_mint_count = mint_count(deps.as_ref(), &info)? + 1;
+        let new_mint_count = mint_count(deps.as_ref(), &info)? + quantity;
         MINTER_ADDRS.save(deps.storage, &info.sender, &new_mint_count)?;
     } else {
-        let whitelist_addr = config.extension.whitelist.unwrap();
+        let whitelist_addr = config.extension.whitelist.clone().unwrap();
         // Fetch and increment the mint count for the current whitelist stage
         let wl_mint_count_response = whitelist_mint_count(deps.as_ref(), &info, whitelist_addr)?;
         save_whitelist_mint_count(
-            deps,
+            deps.branch(),
             &info,
             wl_mint_count_response.1,
             wl_mint_count_response.2,
-            wl_mint_count_response.0 + 1,
+            wl_mint_count_response.0 + quantity,
+            quantity,
         )?
     }
 
     let seller_amount = if !is_admin {
-        let amount = mint_price.amount - network_fee;
-        let payment_address = config.extension.payment_address;
-        let seller = config.extension.admin;
-        // Sending 0 coins fails, so only send if amount is non-zero
-        if !amount.is_zero() {
-            let msg = BankMsg::Send {
-                to_address: payment_address.unwrap_or(seller).to_string(),
-                amount: vec![coin(amount.u128(), mint_price.clone().denom)],
-            };
-            res = res.add_message(msg);
+        let amount = mint_price.amount - network_fee - rebate_reserve.unwrap_or_default();
+        if is_deferred {
+            res = commit_pending_mint(
+                deps.branch(),
+                &config.extension.randomness,
+                &sg721_address,
+                PendingMint {
+                    buyer: info.sender.clone(),
+                    quantity,
+                    height: env.block.height,
+                    payment: mint_price.clone(),
+                    network_fee,
+                    auction_reserve: rebate_reserve,
+                },
+                res,
+            )?;
+        } else {
+            res = pay_proceeds(
+                deps.branch(),
+                &config,
+                res,
+                holds_proceeds,
+                &minted_token_ids,
+                coin(amount.u128(), &mint_price.denom),
+            )?;
         }
         amount
     } else {
@@ -785,7 +1039,12 @@ fn _execute_mint(
         .add_attribute("action", action)
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient_addr)
-        .add_attribute("token_id", mintable_token_mapping.token_id.to_string())
+        .add_attributes(
+            minted_token_ids
+                .iter()
+                .map(|token_id| ("token_id", token_id.to_string())),
+        )
+        .add_attribute("quantity", quantity.to_string())
         .add_attribute(
             "network_fee",
             coin(network_fee.u128(), mint_price.clone().denom).to_string(),
@@ -797,6 +1056,560 @@ fn _execute_mint(
         ))
 }
 
+// Pre-mint hook calls for a token. A whitelist only gates buyers, so `skipped_hook` leaves it out
+// of admin mints when it is also a pre-mint hook.
+fn token_premint_hooks(
+    deps: Deps,
+    sg721_address: &Addr,
+    token_id: Option<u32>,
+    recipient_addr: &Addr,
+    skipped_hook: Option<&Addr>,
+) -> StdResult<Vec<SubMsg>> {
+    let mut premint_hooks = prepare_premint_hooks(
+        deps,
+        sg721_address.clone(),
+        token_id.map(|token_id| token_id.to_string()),
+        recipient_addr.to_string(),
+    )?;
+    if let Some(skipped_hook) = skipped_hook {
+        premint_hooks.retain(|hook| {
+            !matches!(&hook.msg, CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })
+                if contract_addr == skipped_hook.as_str())
+        });
+    }
+    Ok(premint_hooks)
+}
+
+// Adds the mint msg of a token wrapped in the given pre-mint hooks and its post-mint hooks, all
+// as one atomic action, and takes the token out of the mintable positions
+#[allow(clippy::too_many_arguments)]
+fn mint_token(
+    deps: DepsMut,
+    res: Response,
+    base_token_uri: &str,
+    sg721_address: &Addr,
+    recipient_addr: &Addr,
+    mapping: &TokenPositionMapping,
+    premint_hooks: Vec<SubMsg>,
+    royalty_info: Option<RoyaltyInfoResponse>,
+) -> Result<Response, ContractError> {
+    let postmint_hooks = prepare_postmint_hooks(
+        deps.as_ref(),
+        sg721_address.clone(),
+        Some(mapping.token_id.to_string()),
+        recipient_addr.to_string(),
+    )?;
+
+    let mint_msg = Sg721ExecuteMsg::<Extension, Empty>::Mint {
+        token_id: mapping.token_id.to_string(),
+        owner: recipient_addr.to_string(),
+        token_uri: Some(format!("{}/{}", base_token_uri, mapping.token_id)),
+        extension: None,
+        royalty_info,
+    };
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: sg721_address.to_string(),
+        msg: to_json_binary(&mint_msg)?,
+        funds: vec![],
+    });
+
+    MINTABLE_TOKEN_POSITIONS.remove(deps.storage, mapping.position);
+
+    Ok(res
+        .add_submessages(premint_hooks)
+        .add_message(msg)
+        .add_submessages(postmint_hooks))
+}
+
+// Reserves the tokens of a paid mint and holds its payment until randomness is available to
+// assign them. The pre-mint hooks run now, so a rejected buyer fails the mint before paying.
+// With a beacon, the randomness is requested right away.
+fn commit_pending_mint(
+    deps: DepsMut,
+    randomness: &RandomnessSource,
+    sg721_address: &Addr,
+    pending_mint: PendingMint,
+    res: Response,
+) -> Result<Response, ContractError> {
+    let mut premint_hooks = vec![];
+    for _ in 0..pending_mint.quantity {
+        premint_hooks.extend(token_premint_hooks(
+            deps.as_ref(),
+            sg721_address,
+            None,
+            &pending_mint.buyer,
+            None,
+        )?);
+    }
+
+    let id = PENDING_MINT_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PENDING_MINT_ID.save(deps.storage, &id)?;
+    let quantity = pending_mint.quantity;
+    PENDING_MINTS.save(deps.storage, id, &pending_mint)?;
+
+    let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+    MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - quantity))?;
+    let pending_num_tokens = PENDING_NUM_TOKENS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    PENDING_NUM_TOKENS.save(deps.storage, &(pending_num_tokens + quantity))?;
+
+    let mut res = res
+        .add_submessages(premint_hooks)
+        .add_attribute("pending_mint_id", id.to_string());
+    if let RandomnessSource::Beacon { address } = randomness {
+        res = res.add_message(WasmMsg::Execute {
+            contract_addr: address.clone(),
+            msg: to_json_binary(&BeaconExecuteMsg::GetNextRandomness {
+                job_id: id.to_string(),
+            })?,
+            funds: vec![],
+        });
+    }
+    Ok(res)
+}
+
+pub fn execute_reveal_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let pending_mint = PENDING_MINTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::PendingMintNotFound { id })?;
+    let reveal_height = match config.extension.randomness {
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks,
+        } => pending_mint.height + reveal_delay_blocks,
+        _ => {
+            return Err(ContractError::InvalidRandomnessSource(
+                "minter does not use commit-reveal".to_string(),
+            ))
+        }
+    };
+    if env.block.height < reveal_height {
+        return Err(ContractError::RevealTooEarly {
+            height: reveal_height,
+        });
+    }
+    // Past the window, every input of the seed would be known at commit
+    if env.block.height > pending_mint_deadline(&config, &pending_mint)? {
+        return Err(ContractError::RevealWindowPassed { id });
+    }
+
+    // The revealing block's data is unknown when the buyer commits
+    let seed = format!(
+        "{}{}{}{}{}",
+        id,
+        pending_mint.buyer,
+        pending_mint.height,
+        env.block.height,
+        env.block.time.nanos()
+    );
+    let sha256 = Sha256::digest(seed.into_bytes());
+    let randomness: [u8; 32] = sha256.to_vec().try_into().unwrap();
+
+    let res = assign_pending_mint(deps, &config, id, pending_mint, randomness)?;
+    Ok(res
+        .add_attribute("action", "reveal_mint")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_cancel_pending_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let pending_mint = PENDING_MINTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::PendingMintNotFound { id })?;
+    let deadline = pending_mint_deadline(&config, &pending_mint)?;
+    if env.block.height <= deadline {
+        return Err(ContractError::CancelTooEarly {
+            height: deadline + 1,
+        });
+    }
+
+    // The tokens go back to the mintable tokens
+    PENDING_MINTS.remove(deps.storage, id);
+    let pending_num_tokens = PENDING_NUM_TOKENS.load(deps.storage)?;
+    PENDING_NUM_TOKENS.save(deps.storage, &(pending_num_tokens - pending_mint.quantity))?;
+    let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+    MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens + pending_mint.quantity))?;
+
+    let mut res = Response::new();
+    if !pending_mint.payment.amount.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: pending_mint.buyer.to_string(),
+            amount: vec![pending_mint.payment.clone()],
+        });
+    }
+    Ok(res
+        .add_attribute("action", "cancel_pending_mint")
+        .add_attribute("pending_mint_id", id.to_string())
+        .add_attribute("buyer", pending_mint.buyer)
+        .add_attribute("refund", pending_mint.payment.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+// Last height the tokens of a pending mint can be assigned at by a reveal, or the beacon is
+// waited for. Past it, the pending mint can be canceled.
+fn pending_mint_deadline(
+    config: &Config,
+    pending_mint: &PendingMint,
+) -> Result<u64, ContractError> {
+    match config.extension.randomness {
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks,
+        } => Ok(pending_mint.height + reveal_delay_blocks + REVEAL_WINDOW_BLOCKS),
+        RandomnessSource::Beacon { .. } => Ok(pending_mint.height + BEACON_TIMEOUT_BLOCKS),
+        RandomnessSource::BlockData => Err(ContractError::InvalidRandomnessSource(
+            "minter does not defer mints".to_string(),
+        )),
+    }
+}
+
+pub fn execute_nois_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    callback: NoisCallback,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    match &config.extension.randomness {
+        RandomnessSource::Beacon { address } if info.sender == address.as_str() => {}
+        _ => {
+            return Err(ContractError::Unauthorized(
+                "Sender is not the randomness beacon".to_owned(),
+            ))
+        }
+    }
+
+    let id: u64 = callback
+        .job_id
+        .parse()
+        .map_err(|_| StdError::generic_err(format!("Invalid job id {}", callback.job_id)))?;
+    let pending_mint = PENDING_MINTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::PendingMintNotFound { id })?;
+    let randomness: [u8; 32] = callback.randomness.to_array()?;
+
+    let res = assign_pending_mint(deps, &config, id, pending_mint, randomness)?;
+    Ok(res
+        .add_attribute("action", "nois_receive")
+        .add_attribute("sender", info.sender))
+}
+
+// Mints the tokens of a pending mint to its buyer, picked with the given randomness, and pays out
+// the held payment. The pre-mint hooks already ran at commit.
+fn assign_pending_mint(
+    mut deps: DepsMut,
+    config: &Config,
+    id: u64,
+    pending_mint: PendingMint,
+    randomness: [u8; 32],
+) -> Result<Response, ContractError> {
+    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+    let mut res = Response::new();
+    let mut provider = sub_randomness(randomness);
+    let mut minted_token_ids = Vec::with_capacity(pending_mint.quantity as usize);
+    for _ in 0..pending_mint.quantity {
+        let mintable_token_mapping =
+            seeded_mintable_token_mapping(deps.as_ref(), provider.provide())?;
+        res = mint_token(
+            deps.branch(),
+            res,
+            &config.extension.base_token_uri,
+            &sg721_address,
+            &pending_mint.buyer,
+            &mintable_token_mapping,
+            vec![],
+            None,
+        )?;
+        minted_token_ids.push(mintable_token_mapping.token_id);
+    }
+
+    let pending_num_tokens = PENDING_NUM_TOKENS.load(deps.storage)?;
+    PENDING_NUM_TOKENS.save(deps.storage, &(pending_num_tokens - pending_mint.quantity))?;
+    PENDING_MINTS.remove(deps.storage, id);
+
+    let denom = &pending_mint.payment.denom;
+    if !pending_mint.network_fee.is_zero() {
+        let factory: ParamsResponse = deps
+            .querier
+            .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+        distribute_mint_fees(
+            coin(pending_mint.network_fee.u128(), denom),
+            &mut res,
+            false,
+            None,
+            &factory.params.fee_routing,
+        )?;
+    }
+    if let Some(reserve) = pending_mint.auction_reserve {
+        record_auction_bid(
+            deps.storage,
+            &pending_mint.buyer,
+            pending_mint.payment.amount,
+            pending_mint.quantity,
+            reserve,
+        )?;
+    }
+    let seller_amount = pending_mint.payment.amount
+        - pending_mint.network_fee
+        - pending_mint.auction_reserve.unwrap_or_default();
+    let holds_proceeds = match config.extension.escrow {
+        Some(_) => !ESCROW.load(deps.storage)?.released,
+        None => false,
+    };
+    res = pay_proceeds(
+        deps.branch(),
+        config,
+        res,
+        holds_proceeds,
+        &minted_token_ids,
+        coin(seller_amount.u128(), denom),
+    )?;
+
+    Ok(res
+        .add_attribute("pending_mint_id", id.to_string())
+        .add_attribute("recipient", pending_mint.buyer)
+        .add_attributes(
+            minted_token_ids
+                .iter()
+                .map(|token_id| ("token_id", token_id.to_string())),
+        ))
+}
+
+// Pays the proceeds of a mint to the seller, or holds them back in escrow
+fn pay_proceeds(
+    deps: DepsMut,
+    config: &Config,
+    res: Response,
+    holds_proceeds: bool,
+    token_ids: &[u32],
+    proceeds: Coin,
+) -> Result<Response, ContractError> {
+    if holds_proceeds {
+        escrow_proceeds(deps.storage, token_ids, proceeds)?;
+        return Ok(res);
+    }
+    // Sending 0 coins fails, so only send if amount is non-zero
+    if proceeds.amount.is_zero() {
+        return Ok(res);
+    }
+    let payment_address = config
+        .extension
+        .payment_address
+        .as_ref()
+        .unwrap_or(&config.extension.admin);
+    let msg = payment_msg(&deps.querier, payment_address, vec![proceeds])?;
+    Ok(res.add_message(msg))
+}
+
+// Adds a public dutch auction mint to the buyer's bid
+fn record_auction_bid(
+    storage: &mut dyn Storage,
+    buyer: &Addr,
+    paid: Uint128,
+    quantity: u32,
+    reserve: Uint128,
+) -> StdResult<()> {
+    let mut bid = AUCTION_BIDS.may_load(storage, buyer)?.unwrap_or_default();
+    bid.paid += paid;
+    bid.count += quantity;
+    bid.reserved += reserve;
+    AUCTION_BIDS.save(storage, buyer, &bid)
+}
+
+// Holds back the proceeds of a mint, split evenly between its tokens
+fn escrow_proceeds(
+    storage: &mut dyn Storage,
+    token_ids: &[u32],
+    proceeds: Coin,
+) -> Result<(), ContractError> {
+    if proceeds.amount.is_zero() {
+        return Ok(());
+    }
+
+    let quantity = token_ids.len() as u128;
+    let mut remaining = proceeds.amount;
+    for (i, token_id) in token_ids.iter().enumerate() {
+        // The last token gets what's left after rounding
+        let share = if i == token_ids.len() - 1 {
+            remaining
+        } else {
+            proceeds.amount.multiply_ratio(1u128, quantity)
+        };
+        remaining -= share;
+        TOKEN_ESCROWS.save(storage, *token_id, &coin(share.u128(), &proceeds.denom))?;
+    }
+
+    let mut escrow = ESCROW.load(storage)?;
+    match escrow
+        .balance
+        .iter_mut()
+        .find(|balance| balance.denom == proceeds.denom)
+    {
+        Some(balance) => balance.amount += proceeds.amount,
+        None => escrow.balance.push(proceeds),
+    }
+    ESCROW.save(storage, &escrow)?;
+    Ok(())
+}
+
+fn escrow_milestone_reached(
+    deps: Deps,
+    config: &Config,
+    params: &EscrowParams,
+    block_time: Timestamp,
+) -> StdResult<bool> {
+    Ok(match params.milestone {
+        EscrowMilestone::Time(time) => block_time >= time,
+        EscrowMilestone::PercentSold(percent) => {
+            let num_tokens = config.extension.num_tokens;
+            let sold = num_tokens - MINTABLE_NUM_TOKENS.load(deps.storage)?;
+            u64::from(sold) * 100 >= u64::from(percent) * u64::from(num_tokens)
+        }
+    })
+}
+
+// Refunds open when the proceeds weren't released by the deadline, or the remaining tokens
+// were burned before they were released
+fn escrow_refunds_open(params: &EscrowParams, escrow: &Escrow, block_time: Timestamp) -> bool {
+    !escrow.released && (escrow.burned_remaining || block_time >= params.refund_deadline)
+}
+
+pub fn execute_release_escrow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    let params = config
+        .extension
+        .escrow
+        .as_ref()
+        .ok_or(ContractError::EscrowNotEnabled {})?;
+
+    let mut escrow = ESCROW.load(deps.storage)?;
+    if escrow.released {
+        return Err(ContractError::EscrowReleased {});
+    }
+    if escrow_refunds_open(params, &escrow, env.block.time) {
+        return Err(ContractError::RefundsOpen {});
+    }
+    if !escrow_milestone_reached(deps.as_ref(), &config, params, env.block.time)? {
+        return Err(ContractError::EscrowMilestoneNotReached {});
+    }
+
+    let amount: Vec<Coin> = std::mem::take(&mut escrow.balance)
+        .into_iter()
+        .filter(|balance| !balance.amount.is_zero())
+        .collect();
+    escrow.released = true;
+    ESCROW.save(deps.storage, &escrow)?;
+
+    let mut res = Response::new();
+    if !amount.is_empty() {
+        let payment_address = config
+            .extension
+            .payment_address
+            .unwrap_or(config.extension.admin);
+        res = res.add_message(payment_msg(
+            &deps.querier,
+            &payment_address,
+            amount.clone(),
+        )?);
+    }
+
+    Ok(res
+        .add_attribute("action", "release_escrow")
+        .add_attribute("sender", info.sender)
+        .add_attributes(amount.iter().map(|balance| ("amount", balance.to_string()))))
+}
+
+pub fn execute_claim_refund(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: u32,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let params = config
+        .extension
+        .escrow
+        .as_ref()
+        .ok_or(ContractError::EscrowNotEnabled {})?;
+
+    let mut escrow = ESCROW.load(deps.storage)?;
+    if !escrow_refunds_open(params, &escrow, env.block.time) {
+        return Err(ContractError::RefundsNotOpen {});
+    }
+    let refund = TOKEN_ESCROWS
+        .may_load(deps.storage, token_id)?
+        .ok_or(ContractError::NoRefund { token_id })?;
+
+    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+    let owner: OwnerOfResponse = deps.querier.query_wasm_smart(
+        sg721_address.clone(),
+        &Cw721QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        },
+    )?;
+    if owner.owner != info.sender {
+        return Err(ContractError::Unauthorized(
+            "Sender is not the token owner".to_owned(),
+        ));
+    }
+
+    TOKEN_ESCROWS.remove(deps.storage, token_id);
+    if let Some(balance) = escrow
+        .balance
+        .iter_mut()
+        .find(|balance| balance.denom == refund.denom)
+    {
+        balance.amount -= refund.amount;
+    }
+    ESCROW.save(deps.storage, &escrow)?;
+
+    // The minter burns the token as an approved spender
+    let burn_msg = WasmMsg::Execute {
+        contract_addr: sg721_address.to_string(),
+        msg: to_json_binary(&Sg721ExecuteMsg::<Extension, Empty>::Burn {
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    };
+    let mut res = Response::new().add_message(burn_msg);
+    if !refund.amount.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![refund.clone()],
+        });
+    }
+
+    Ok(res
+        .add_attribute("action", "claim_refund")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id.to_string())
+        .add_attribute("refund", refund.to_string()))
+}
+
 fn random_token_list(
     env: &Env,
     sender: Addr,
@@ -851,6 +1664,39 @@ fn random_mintable_token_mapping(
     Ok(TokenPositionMapping { position, token_id })
 }
 
+// Picks the first mintable position at or after a random one, so any remaining token can be picked
+fn seeded_mintable_token_mapping(
+    deps: Deps,
+    randomness: [u8; 32],
+) -> Result<TokenPositionMapping, ContractError> {
+    let first = MINTABLE_TOKEN_POSITIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+    let last = MINTABLE_TOKEN_POSITIONS
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    let (Some(first), Some(last)) = (first, last) else {
+        return Err(ContractError::SoldOut {});
+    };
+
+    let start = int_in_range(randomness, first, last);
+    let position = MINTABLE_TOKEN_POSITIONS
+        .keys(
+            deps.storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()?
+        .ok_or(ContractError::SoldOut {})?;
+
+    let token_id = MINTABLE_TOKEN_POSITIONS.load(deps.storage, position)?;
+    Ok(TokenPositionMapping { position, token_id })
+}
+
 pub fn execute_update_mint_price(
     deps: DepsMut,
     env: Env,
@@ -864,6 +1710,9 @@ pub fn execute_update_mint_price(
             "Sender is not an admin".to_owned(),
         ));
     }
+    if config.extension.dutch_auction.is_some() {
+        return Err(ContractError::DutchAuctionPriceLocked {});
+    }
     // If current time is after the stored start time, only allow lowering price
     if env.block.time >= config.extension.start_time && price >= config.mint_price.amount.u128() {
         return Err(ContractError::UpdatedMintPriceTooHigh {
@@ -1057,6 +1906,15 @@ pub fn execute_burn_remaining(
         return Err(ContractError::SoldOut {});
     }
 
+    // Pending mints still need their tokens
+    if PENDING_NUM_TOKENS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        > 0
+    {
+        return Err(ContractError::PendingMints {});
+    }
+
     let keys = MINTABLE_TOKEN_POSITIONS
         .keys(deps.storage, None, None, Order::Ascending)
         .collect::<Vec<_>>();
@@ -1068,6 +1926,15 @@ pub fn execute_burn_remaining(
     // Decrement mintable num tokens
     MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - total))?;
 
+    // Burning the remaining tokens before the escrowed proceeds are released opens refunds
+    if config.extension.escrow.is_some() {
+        let mut escrow = ESCROW.load(deps.storage)?;
+        if !escrow.released {
+            escrow.burned_remaining = true;
+            ESCROW.save(deps.storage, &escrow)?;
+        }
+    }
+
     let event = Event::new("burn-remaining")
         .add_attribute("sender", info.sender)
         .add_attribute("tokens_burned", total.to_string())
@@ -1077,13 +1944,13 @@ pub fn execute_burn_remaining(
 
 // if admin_no_fee => no fee,
 // else if in whitelist => whitelist price
-// else => config unit price
-pub fn mint_price(deps: Deps, is_admin: bool) -> Result<Coin, StdError> {
+// else => dutch auction price, discount price or config unit price
+pub fn mint_price(deps: Deps, env: &Env, is_admin: bool) -> Result<Coin, StdError> {
     let config = CONFIG.load(deps.storage)?;
 
     let factory: ParamsResponse = deps
         .querier
-        .query_wasm_smart(config.factory, &Sg2QueryMsg::Params {})?;
+        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
     let factory_params = factory.params;
 
     if is_admin {
@@ -1094,11 +1961,11 @@ pub fn mint_price(deps: Deps, is_admin: bool) -> Result<Coin, StdError> {
     }
 
     if config.extension.whitelist.is_none() {
-        let price = config.extension.discount_price.unwrap_or(config.mint_price);
+        let price = public_price(&config, &factory_params.min_mint_price, env.block.time);
         return Ok(price);
     }
 
-    let whitelist = config.extension.whitelist.unwrap();
+    let whitelist = config.extension.whitelist.clone().unwrap();
 
     let wl_config: WhitelistConfigResponse = deps
         .querier
@@ -1107,11 +1974,170 @@ pub fn mint_price(deps: Deps, is_admin: bool) -> Result<Coin, StdError> {
     if wl_config.is_active {
         Ok(wl_config.mint_price)
     } else {
-        let price = config.extension.discount_price.unwrap_or(config.mint_price);
+        let price = public_price(&config, &factory_params.min_mint_price, env.block.time);
         Ok(price)
     }
 }
 
+// Additional mint price in the denom the buyer sent, if they paid in one of those denoms
+fn additional_mint_price(config: &Config, info: &MessageInfo) -> Option<Coin> {
+    config
+        .extension
+        .additional_mint_prices
+        .iter()
+        .find(|price| info.funds.iter().any(|fund| fund.denom == price.denom))
+        .cloned()
+}
+
+// Dutch auction price if there is an auction, otherwise the discount or fixed mint price
+fn public_price(config: &Config, min_mint_price: &Coin, block_time: Timestamp) -> Coin {
+    match &config.extension.dutch_auction {
+        Some(auction) => dutch_auction_price(
+            auction,
+            config.extension.start_time,
+            min_mint_price,
+            block_time,
+        ),
+        None => config
+            .extension
+            .discount_price
+            .clone()
+            .unwrap_or_else(|| config.mint_price.clone()),
+    }
+}
+
+/// Price of a dutch auction at `block_time`, dropping by `decay_step` every `decay_interval`
+/// seconds after `start_time` down to its floor
+pub fn dutch_auction_price(
+    auction: &DutchAuctionParams,
+    start_time: Timestamp,
+    min_mint_price: &Coin,
+    block_time: Timestamp,
+) -> Coin {
+    let intervals =
+        block_time.seconds().saturating_sub(start_time.seconds()) / auction.decay_interval;
+    let decay = auction.decay_step.saturating_mul(Uint128::from(intervals));
+    let price = auction
+        .start_price
+        .amount
+        .saturating_sub(decay)
+        .max(auction_floor(auction, min_mint_price));
+    coin(price.u128(), &auction.start_price.denom)
+}
+
+// The network minimum mint price is an absolute floor, even if it is raised after creation
+fn auction_floor(auction: &DutchAuctionParams, min_mint_price: &Coin) -> Uint128 {
+    auction.floor_price.amount.max(min_mint_price.amount)
+}
+
+// The clearing price is final once the auction sold out, at the last sale price,
+// or once the price reached its floor
+fn auction_clearing_price(
+    deps: Deps,
+    config: &Config,
+    min_mint_price: &Coin,
+    block_time: Timestamp,
+) -> StdResult<Option<Uint128>> {
+    let auction = match &config.extension.dutch_auction {
+        Some(auction) => auction,
+        None => return Ok(None),
+    };
+    let floor = auction_floor(auction, min_mint_price);
+
+    if MINTABLE_NUM_TOKENS.load(deps.storage)? == 0 {
+        return Ok(Some(
+            AUCTION_LAST_PRICE.may_load(deps.storage)?.unwrap_or(floor),
+        ));
+    }
+
+    let price = dutch_auction_price(
+        auction,
+        config.extension.start_time,
+        min_mint_price,
+        block_time,
+    );
+    Ok((price.amount == floor).then_some(floor))
+}
+
+// Difference between what was paid and the clearing price, capped by what was held back
+fn rebate_amount(bid: &AuctionBid, clearing_price: Uint128) -> Uint128 {
+    bid.paid
+        .saturating_sub(clearing_price.saturating_mul(Uint128::from(bid.count)))
+        .min(bid.reserved)
+}
+
+pub fn execute_claim_rebate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let buyer = match address {
+        Some(address) => deps.api.addr_validate(&address)?,
+        None => info.sender.clone(),
+    };
+
+    let factory: ParamsResponse = deps
+        .querier
+        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+    let clearing_price = auction_clearing_price(
+        deps.as_ref(),
+        &config,
+        &factory.params.min_mint_price,
+        env.block.time,
+    )?
+    .ok_or(ContractError::AuctionNotSettled {})?;
+
+    let mut bid = AUCTION_BIDS
+        .may_load(deps.storage, &buyer)?
+        .unwrap_or_default();
+    if bid.reserved.is_zero() {
+        return Err(ContractError::NoRebate {});
+    }
+    let rebate = rebate_amount(&bid, clearing_price);
+    // What is left of the reserve is part of the sale
+    let seller_amount = bid.reserved - rebate;
+    bid.paid -= rebate;
+    bid.reserved = Uint128::zero();
+    AUCTION_BIDS.save(deps.storage, &buyer, &bid)?;
+
+    let denom = config.mint_price.denom;
+    let mut res = Response::new();
+    if !rebate.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: buyer.to_string(),
+            amount: vec![coin(rebate.u128(), &denom)],
+        });
+    }
+    if !seller_amount.is_zero() {
+        let seller = config
+            .extension
+            .payment_address
+            .unwrap_or(config.extension.admin);
+        res = res.add_message(payment_msg(
+            &deps.querier,
+            &seller,
+            vec![coin(seller_amount.u128(), &denom)],
+        )?);
+    }
+
+    Ok(res
+        .add_attribute("action", "claim_rebate")
+        .add_attribute("sender", info.sender)
+        .add_attribute("buyer", buyer)
+        .add_attribute(
+            "clearing_price",
+            coin(clearing_price.u128(), &denom).to_string(),
+        )
+        .add_attribute("rebate", coin(rebate.u128(), &denom).to_string())
+        .add_attribute(
+            "seller_amount",
+            coin(seller_amount.u128(), denom).to_string(),
+        ))
+}
+
 fn mint_count(deps: Deps, info: &MessageInfo) -> Result<u32, StdError> {
     let mint_count = (MINTER_ADDRS.key(&info.sender).may_load(deps.storage)?).unwrap_or(0);
     Ok(mint_count)
@@ -1123,41 +2149,25 @@ fn whitelist_mint_count(
     info: &MessageInfo,
     whitelist_addr: Addr,
 ) -> Result<(u32, bool, Option<u32>), StdError> {
-    let is_tiered_whitelist = cw2::query_contract_info(&deps.querier, whitelist_addr.clone())
-        .map(|info| info.contract.contains("tiered-whitelist"))
-        .unwrap_or(false);
+    let is_tiered_whitelist = WHITELIST_IS_TIERED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
 
     if is_tiered_whitelist {
-        let active_stage_id = deps
+        let active_stage_id: u32 = deps
             .querier
-            .query_wasm_smart(whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
-        match active_stage_id {
-            1 => Ok((
-                WHITELIST_FS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(1),
-            )),
-            2 => Ok((
-                WHITELIST_SS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(2),
-            )),
-            3 => Ok((
-                WHITELIST_TS_MINTER_ADDRS
-                    .key(&info.sender)
-                    .may_load(deps.storage)?
-                    .unwrap_or(0),
-                true,
-                Some(3),
-            )),
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+            .query_wasm_smart(&whitelist_addr, &TieredWhitelistQueryMsg::ActiveStageId {})?;
+        if active_stage_id == 0 {
+            return Err(StdError::generic_err("Invalid stage ID"));
         }
+
+        Ok((
+            WHITELIST_STAGE_MINTER_ADDRS
+                .may_load(deps.storage, (&info.sender, active_stage_id))?
+                .unwrap_or(0),
+            true,
+            Some(active_stage_id),
+        ))
     } else {
         Ok((
             WHITELIST_MINTER_ADDRS
@@ -1176,34 +2186,17 @@ fn save_whitelist_mint_count(
     is_tiered_whitelist: bool,
     stage_id: Option<u32>,
     count: u32,
+    quantity: u32,
 ) -> StdResult<()> {
-    if is_tiered_whitelist & stage_id.is_some() {
-        match stage_id {
-            Some(1) => {
-                let _ = WHITELIST_FS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_fs_mint_count =
-                    WHITELIST_FS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_fs_mint_count += 1;
-                WHITELIST_FS_MINT_COUNT.save(deps.storage, &wl_fs_mint_count)
-            }
-            Some(2) => {
-                let _ = WHITELIST_SS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ss_mint_count =
-                    WHITELIST_SS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ss_mint_count += 1;
-                WHITELIST_SS_MINT_COUNT.save(deps.storage, &wl_ss_mint_count)
-            }
-            Some(3) => {
-                let _ = WHITELIST_TS_MINTER_ADDRS.save(deps.storage, &info.sender, &count);
-                let mut wl_ts_mint_count =
-                    WHITELIST_TS_MINT_COUNT.may_load(deps.storage)?.unwrap_or(0);
-                wl_ts_mint_count += 1;
-                WHITELIST_TS_MINT_COUNT.save(deps.storage, &wl_ts_mint_count)
-            }
-            _ => Err(StdError::generic_err("Invalid stage ID")),
+    match stage_id {
+        Some(stage_id) if is_tiered_whitelist => {
+            WHITELIST_STAGE_MINTER_ADDRS.save(deps.storage, (&info.sender, stage_id), &count)?;
+            let stage_mint_count = WHITELIST_STAGE_MINT_COUNT
+                .may_load(deps.storage, stage_id)?
+                .unwrap_or(0);
+            WHITELIST_STAGE_MINT_COUNT.save(deps.storage, stage_id, &(stage_mint_count + quantity))
         }
-    } else {
-        WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count)
+        _ => WHITELIST_MINTER_ADDRS.save(deps.storage, &info.sender, &count),
     }
 }
 
@@ -1251,14 +2244,20 @@ pub fn update_status(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::Status {} => to_json_binary(&query_status(deps)?),
         QueryMsg::StartTime {} => to_json_binary(&query_start_time(deps)?),
         QueryMsg::MintableNumTokens {} => to_json_binary(&query_mintable_num_tokens(deps)?),
-        QueryMsg::MintPrice {} => to_json_binary(&query_mint_price(deps)?),
+        QueryMsg::MintPrice {} => to_json_binary(&query_mint_price(deps, env)?),
         QueryMsg::MintCount { address } => to_json_binary(&query_mint_count(deps, address)?),
+        QueryMsg::PreMintHooks {} => query_premint_hooks(deps),
+        QueryMsg::PostMintHooks {} => query_postmint_hooks(deps),
+        QueryMsg::Rebate { address } => to_json_binary(&query_rebate(deps, env, address)?),
+        QueryMsg::PendingMint { id } => to_json_binary(&query_pending_mint(deps, id)?),
+        QueryMsg::Escrow {} => to_json_binary(&query_escrow(deps, env)?),
+        QueryMsg::TokenEscrow { token_id } => to_json_binary(&query_token_escrow(deps, token_id)?),
     }
 }
 
@@ -1278,6 +2277,56 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         whitelist: config.extension.whitelist.map(|w| w.to_string()),
         factory: config.factory.to_string(),
         discount_price: config.extension.discount_price,
+        dutch_auction: config.extension.dutch_auction,
+        additional_mint_prices: config.extension.additional_mint_prices,
+        randomness: config.extension.randomness,
+        escrow: config.extension.escrow,
+    })
+}
+
+fn query_escrow(deps: Deps, env: Env) -> StdResult<EscrowResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let Some(params) = config.extension.escrow.clone() else {
+        return Ok(EscrowResponse {
+            params: None,
+            balance: vec![],
+            milestone_reached: false,
+            released: false,
+            refunds_open: false,
+        });
+    };
+    let escrow = ESCROW.load(deps.storage)?;
+
+    Ok(EscrowResponse {
+        milestone_reached: escrow_milestone_reached(deps, &config, &params, env.block.time)?,
+        refunds_open: escrow_refunds_open(&params, &escrow, env.block.time),
+        released: escrow.released,
+        balance: escrow.balance,
+        params: Some(params),
+    })
+}
+
+fn query_token_escrow(deps: Deps, token_id: u32) -> StdResult<TokenEscrowResponse> {
+    // Nothing is held back for any token once the proceeds were released
+    let released = match ESCROW.may_load(deps.storage)? {
+        Some(escrow) => escrow.released,
+        None => true,
+    };
+    let amount = if released {
+        None
+    } else {
+        TOKEN_ESCROWS.may_load(deps.storage, token_id)?
+    };
+    Ok(TokenEscrowResponse { token_id, amount })
+}
+
+fn query_pending_mint(deps: Deps, id: u64) -> StdResult<PendingMintResponse> {
+    let pending_mint = PENDING_MINTS.load(deps.storage, id)?;
+    Ok(PendingMintResponse {
+        id,
+        buyer: pending_mint.buyer,
+        quantity: pending_mint.quantity,
+        height: pending_mint.height,
     })
 }
 
@@ -1292,18 +2341,11 @@ fn query_mint_count(deps: Deps, address: String) -> StdResult<MintCountResponse>
     let mint_count = (MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
     let standard_wl_count =
         (WHITELIST_MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
-    let tiered_wl_count = (WHITELIST_FS_MINTER_ADDRS
-        .key(&addr)
-        .may_load(deps.storage)?)
-    .unwrap_or(0)
-        + (WHITELIST_SS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0)
-        + (WHITELIST_TS_MINTER_ADDRS
-            .key(&addr)
-            .may_load(deps.storage)?)
-        .unwrap_or(0);
+    let tiered_wl_count = WHITELIST_STAGE_MINTER_ADDRS
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, count)| count))
+        .sum::<StdResult<u32>>()?;
     Ok(MintCountResponse {
         address: addr.to_string(),
         count: mint_count + standard_wl_count + tiered_wl_count,
@@ -1322,7 +2364,7 @@ fn query_mintable_num_tokens(deps: Deps) -> StdResult<MintableNumTokensResponse>
     Ok(MintableNumTokensResponse { count })
 }
 
-fn query_mint_price(deps: Deps) -> StdResult<MintPriceResponse> {
+fn query_mint_price(deps: Deps, env: Env) -> StdResult<MintPriceResponse> {
     let config = CONFIG.load(deps.storage)?;
 
     let factory: ParamsResponse = deps
@@ -1331,7 +2373,7 @@ fn query_mint_price(deps: Deps) -> StdResult<MintPriceResponse> {
 
     let factory_params = factory.params;
 
-    let current_price = mint_price(deps, false)?;
+    let current_price = mint_price(deps, &env, false)?;
     let public_price = config.mint_price.clone();
     let whitelist_price: Option<Coin> = if let Some(whitelist) = config.extension.whitelist {
         let wl_config: WhitelistConfigResponse = deps
@@ -1352,12 +2394,47 @@ fn query_mint_price(deps: Deps) -> StdResult<MintPriceResponse> {
         whitelist_price,
         current_price,
         discount_price,
+        additional_prices: config.extension.additional_mint_prices,
     })
 }
 
-// Reply callback triggered from cw721 contract instantiation
+fn query_rebate(deps: Deps, env: Env, address: String) -> StdResult<RebateResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let buyer = deps.api.addr_validate(&address)?;
+
+    let factory: ParamsResponse = deps
+        .querier
+        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+    let clearing_price = auction_clearing_price(
+        deps,
+        &config,
+        &factory.params.min_mint_price,
+        env.block.time,
+    )?;
+    let bid = AUCTION_BIDS
+        .may_load(deps.storage, &buyer)?
+        .unwrap_or_default();
+    let rebate = clearing_price
+        .map(|clearing_price| rebate_amount(&bid, clearing_price))
+        .unwrap_or_default();
+
+    Ok(RebateResponse {
+        address: buyer.to_string(),
+        paid: bid.paid,
+        count: bid.count,
+        settled: clearing_price.is_some(),
+        clearing_price: clearing_price
+            .map(|clearing_price| coin(clearing_price.u128(), &config.mint_price.denom)),
+        rebate,
+    })
+}
+
+// Reply callback triggered from cw721 contract instantiation or a failed mint hook
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // Mint hook failures roll back the whole mint
+    handle_reply(msg.id)?;
+
     if msg.id != INSTANTIATE_SG721_REPLY_ID {
         return Err(ContractError::InvalidReplyID {});
     }
@@ -1402,6 +2479,14 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: Empty) -> Result<Response, Contrac
         LAST_DISCOUNT_TIME.save(deps.storage, &last_discount_time)?;
     }
 
+    // tiered whitelist stage mint counts are keyed by stage id
+    migrate_whitelist_stage_mint_counts(deps.storage)?;
+    // whether the whitelist is tiered is decided when it is set
+    if let Some(whitelist) = CONFIG.load(deps.storage)?.extension.whitelist {
+        let is_tiered_whitelist = is_tiered_whitelist(&deps.querier, &whitelist);
+        WHITELIST_IS_TIERED.save(deps.storage, &is_tiered_whitelist)?;
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let event = Event::new("migrate")
@@ -1,6 +1,7 @@
 use cosmwasm_std::{Coin, StdError, Timestamp};
 use cw_utils::PaymentError;
 use sg1::FeeError;
+use sg_mint_hooks::MintHookError;
 use thiserror::Error;
 use url::ParseError;
 #[derive(Error, Debug, PartialEq)]
@@ -17,6 +18,9 @@ pub enum ContractError {
     #[error("{0}")]
     Fee(#[from] FeeError),
 
+    #[error("{0}")]
+    MintHook(#[from] MintHookError),
+
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
@@ -44,6 +48,48 @@ pub enum ContractError {
     #[error("Sold out")]
     SoldOut {},
 
+    #[error("Invalid mint quantity {got}, must be between 1 and {max}")]
+    InvalidMintQuantity { max: u32, got: u32 },
+
+    #[error("Invalid randomness source: {0}")]
+    InvalidRandomnessSource(String),
+
+    #[error("Pending mint {id} not found")]
+    PendingMintNotFound { id: u64 },
+
+    #[error("Pending mint can be revealed at height {height}")]
+    RevealTooEarly { height: u64 },
+
+    #[error("Reveal window of pending mint {id} passed, it can only be canceled")]
+    RevealWindowPassed { id: u64 },
+
+    #[error("Pending mint can be canceled at height {height}")]
+    CancelTooEarly { height: u64 },
+
+    #[error("Pending mints must be assigned first")]
+    PendingMints {},
+
+    #[error("Invalid escrow: {0}")]
+    InvalidEscrow(String),
+
+    #[error("Escrow is not enabled")]
+    EscrowNotEnabled {},
+
+    #[error("Escrow milestone not reached")]
+    EscrowMilestoneNotReached {},
+
+    #[error("Escrowed proceeds already released")]
+    EscrowReleased {},
+
+    #[error("Refunds are open")]
+    RefundsOpen {},
+
+    #[error("Refunds are not open")]
+    RefundsNotOpen {},
+
+    #[error("No refund for token {token_id}")]
+    NoRefund { token_id: u32 },
+
     #[error("Not sold out")]
     NotSoldOut {},
 
@@ -65,6 +111,18 @@ pub enum ContractError {
     #[error("Discount price can only be removed 1 hour after the last update")]
     DiscountRemovalTooSoon {},
 
+    #[error("Invalid dutch auction: {0}")]
+    InvalidDutchAuction(String),
+
+    #[error("Mint price is set by the dutch auction")]
+    DutchAuctionPriceLocked {},
+
+    #[error("Dutch auction has not settled yet")]
+    AuctionNotSettled {},
+
+    #[error("No rebate to claim")]
+    NoRebate {},
+
     #[error("Invalid address {addr}")]
     InvalidAddress { addr: String },
 
@@ -1,6 +1,13 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Timestamp};
-use vending_factory::{msg::VendingMinterCreateMsg, state::VendingMinterParams};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, HexBinary, Timestamp, Uint128};
+use sg4::StatusResponse;
+use sg721::RoyaltyInfoResponse;
+use sg_controllers::HooksResponse;
+use sg_mint_hooks::{sg_mint_hooks_execute, sg_mint_hooks_query};
+use vending_factory::msg::{
+    DutchAuctionParams, EscrowParams, RandomnessSource, VendingMinterCreateMsg,
+};
+use vending_factory::state::VendingMinterParams;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -8,9 +15,12 @@ pub struct InstantiateMsg {
     pub params: VendingMinterParams,
 }
 
+#[sg_mint_hooks_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint {},
+    Mint {
+        quantity: Option<u32>,
+    },
     SetWhitelist {
         whitelist: String,
     },
@@ -26,10 +36,17 @@ pub enum ExecuteMsg {
     },
     MintTo {
         recipient: String,
+        quantity: Option<u32>,
+        /// Royalty of the minted tokens, overriding the collection royalty
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
     MintFor {
         token_id: u32,
         recipient: String,
+        /// Royalty of the minted token, overriding the collection royalty
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
     Shuffle {},
     BurnRemaining {},
@@ -37,16 +54,76 @@ pub enum ExecuteMsg {
         price: u128,
     },
     RemoveDiscountPrice {},
+    /// Refunds a dutch auction buyer the difference to the clearing price once the auction settled.
+    /// Anyone can trigger it for any buyer, the rebate always goes to the buyer.
+    ClaimRebate {
+        address: Option<String>,
+    },
+    /// Assigns the tokens of a commit-reveal pending mint once the reveal delay passed, during
+    /// the reveal window. Anyone can reveal, the tokens always go to the buyer.
+    RevealMint {
+        id: u64,
+    },
+    /// Refunds the payment of a pending mint that wasn't revealed within its reveal window, or
+    /// the beacon didn't deliver randomness for in time, and releases its tokens.
+    /// Anyone can cancel, the refund always goes to the buyer. Mint counts and whatever the
+    /// pre-mint hooks recorded stay used.
+    CancelPendingMint {
+        id: u64,
+    },
+    /// Callback of the randomness beacon, assigns the tokens of the pending mint in `job_id`
+    NoisReceive {
+        callback: NoisCallback,
+    },
+    /// Sends the escrowed proceeds to the payment address once the escrow milestone is reached
+    ReleaseEscrow {},
+    /// Burns the sender's token and refunds what was escrowed for it, once refunds are open.
+    /// The minter must be approved to burn the token.
+    ClaimRefund {
+        token_id: u32,
+    },
 }
 
+/// Randomness delivered by the beacon, in the format of nois proxy callbacks
 #[cw_serde]
+pub struct NoisCallback {
+    /// Pending mint id the randomness was requested for
+    pub job_id: String,
+    pub published: Timestamp,
+    /// 32 bytes of randomness
+    pub randomness: HexBinary,
+}
+
+/// Request sent to the randomness beacon, in the format of nois proxy requests
+#[cw_serde]
+pub enum BeaconExecuteMsg {
+    GetNextRandomness { job_id: String },
+}
+
+#[sg_mint_hooks_query]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(ConfigResponse)]
     Config {},
+    #[returns(MintableNumTokensResponse)]
     MintableNumTokens {},
+    #[returns(StartTimeResponse)]
     StartTime {},
+    #[returns(MintPriceResponse)]
     MintPrice {},
+    #[returns(MintCountResponse)]
     MintCount { address: String },
+    #[returns(StatusResponse)]
     Status {},
+    #[returns(RebateResponse)]
+    Rebate { address: String },
+    #[returns(PendingMintResponse)]
+    PendingMint { id: u64 },
+    #[returns(EscrowResponse)]
+    Escrow {},
+    #[returns(TokenEscrowResponse)]
+    TokenEscrow { token_id: u32 },
 }
 
 #[cw_serde]
@@ -62,6 +139,13 @@ pub struct ConfigResponse {
     pub whitelist: Option<String>,
     pub factory: String,
     pub discount_price: Option<Coin>,
+    pub dutch_auction: Option<DutchAuctionParams>,
+    #[serde(default)]
+    pub additional_mint_prices: Vec<Coin>,
+    #[serde(default)]
+    pub randomness: RandomnessSource,
+    #[serde(default)]
+    pub escrow: Option<EscrowParams>,
 }
 
 #[cw_serde]
@@ -81,6 +165,9 @@ pub struct MintPriceResponse {
     pub whitelist_price: Option<Coin>,
     pub current_price: Coin,
     pub discount_price: Option<Coin>,
+    /// Public prices in other denoms that are accepted instead of `public_price`
+    #[serde(default)]
+    pub additional_prices: Vec<Coin>,
 }
 
 #[cw_serde]
@@ -88,3 +175,37 @@ pub struct MintCountResponse {
     pub address: String,
     pub count: u32,
 }
+
+#[cw_serde]
+pub struct RebateResponse {
+    pub address: String,
+    pub paid: Uint128,
+    pub count: u32,
+    /// Whether the clearing price is final
+    pub settled: bool,
+    pub clearing_price: Option<Coin>,
+    pub rebate: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingMintResponse {
+    pub id: u64,
+    pub buyer: Addr,
+    pub quantity: u32,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct EscrowResponse {
+    pub params: Option<EscrowParams>,
+    pub balance: Vec<Coin>,
+    pub milestone_reached: bool,
+    pub released: bool,
+    pub refunds_open: bool,
+}
+
+#[cw_serde]
+pub struct TokenEscrowResponse {
+    pub token_id: u32,
+    pub amount: Option<Coin>,
+}
@@ -1,7 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 use sg4::{MinterConfig, Status};
+use vending_factory::msg::{DutchAuctionParams, EscrowParams, RandomnessSource};
 
 #[cw_serde]
 pub struct ConfigExtension {
@@ -13,6 +14,13 @@ pub struct ConfigExtension {
     pub start_time: Timestamp,
     pub per_address_limit: u32,
     pub discount_price: Option<Coin>,
+    pub dutch_auction: Option<DutchAuctionParams>,
+    #[serde(default)]
+    pub additional_mint_prices: Vec<Coin>,
+    #[serde(default)]
+    pub randomness: RandomnessSource,
+    #[serde(default)]
+    pub escrow: Option<EscrowParams>,
 }
 pub type Config = MinterConfig<ConfigExtension>;
 
@@ -25,16 +33,96 @@ pub const MINTER_ADDRS: Map<&Addr, u32> = Map::new("ma");
 
 // Keep track of the number of tokens minted by each address for regular whitelists
 pub const WHITELIST_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlma");
-// Keep track of the number of tokens minted by each address for first, second & third tiered whitelist stages
-pub const WHITELIST_FS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlfsma");
-pub const WHITELIST_SS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wlssma");
-pub const WHITELIST_TS_MINTER_ADDRS: Map<&Addr, u32> = Map::new("wltsma");
-pub const WHITELIST_FS_MINT_COUNT: Item<u32> = Item::new("wlfsmc");
-pub const WHITELIST_SS_MINT_COUNT: Item<u32> = Item::new("wlssmc");
-pub const WHITELIST_TS_MINT_COUNT: Item<u32> = Item::new("wltsmc");
+// Keep track of the number of tokens minted by each address in each tiered whitelist stage
+pub const WHITELIST_STAGE_MINTER_ADDRS: Map<(&Addr, u32), u32> = Map::new("wlsma");
+// Keep track of the number of tokens minted in each tiered whitelist stage
+pub const WHITELIST_STAGE_MINT_COUNT: Map<u32, u32> = Map::new("wlsmc");
+// Whether the whitelist is a tiered whitelist, decided when the whitelist is set
+pub const WHITELIST_IS_TIERED: Item<bool> = Item::new("wlit");
 pub const AIRDROP_COUNT: Item<u32> = Item::new("airdrop_count");
 
 pub const LAST_DISCOUNT_TIME: Item<Timestamp> = Item::new("last_discount_time");
 
+/// What a buyer paid in a dutch auction with rebates, and how much of it is held back for the rebate
+#[cw_serde]
+#[derive(Default)]
+pub struct AuctionBid {
+    pub paid: Uint128,
+    pub count: u32,
+    pub reserved: Uint128,
+}
+
+pub const AUCTION_BIDS: Map<&Addr, AuctionBid> = Map::new("ab");
+// Unit price of the last public dutch auction mint
+pub const AUCTION_LAST_PRICE: Item<Uint128> = Item::new("alp");
+
+/// A paid mint waiting for randomness before its tokens are assigned to the buyer
+#[cw_serde]
+pub struct PendingMint {
+    pub buyer: Addr,
+    pub quantity: u32,
+    /// Block height of the commit
+    pub height: u64,
+    /// Held until the tokens are assigned, refunded if the pending mint is canceled
+    pub payment: Coin,
+    pub network_fee: Uint128,
+    /// Part of the payment held back for the dutch auction rebate, set when the mint counts
+    /// towards the buyer's bid
+    pub auction_reserve: Option<Uint128>,
+}
+
+pub const PENDING_MINTS: Map<u64, PendingMint> = Map::new("pm");
+pub const PENDING_MINT_ID: Item<u64> = Item::new("pmid");
+// Tokens of pending mints, already taken out of MINTABLE_NUM_TOKENS but still in MINTABLE_TOKEN_POSITIONS
+pub const PENDING_NUM_TOKENS: Item<u32> = Item::new("pnt");
+
+/// Sale proceeds held by the minter while escrow is enabled
+#[cw_serde]
+#[derive(Default)]
+pub struct Escrow {
+    pub balance: Vec<Coin>,
+    /// Set once the admin released the proceeds, later mints pay out right away
+    pub released: bool,
+    /// Set when the remaining tokens were burned before the proceeds were released
+    pub burned_remaining: bool,
+}
+
+pub const ESCROW: Item<Escrow> = Item::new("escrow");
+// Proceeds held back for each token minted while escrow is enabled, refunded when the token is burned
+pub const TOKEN_ESCROWS: Map<u32, Coin> = Map::new("te");
+
 /// Holds the status of the minter. Can be changed with on-chain governance proposals.
 pub const STATUS: Item<Status> = Item::new("status");
+
+// Mint counts of the first, second & third tiered whitelist stages, from before stages were
+// keyed by id
+const LEGACY_WHITELIST_STAGE_MINTER_ADDRS: [Map<&Addr, u32>; 3] =
+    [Map::new("wlfsma"), Map::new("wlssma"), Map::new("wltsma")];
+const LEGACY_WHITELIST_STAGE_MINT_COUNTS: [Item<u32>; 3] = [
+    Item::new("wlfsmc"),
+    Item::new("wlssmc"),
+    Item::new("wltsmc"),
+];
+
+/// Moves the mint counts of the first three tiered whitelist stages to the maps keyed by stage id
+pub fn migrate_whitelist_stage_mint_counts(storage: &mut dyn Storage) -> StdResult<()> {
+    for (i, (minter_addrs, mint_count)) in LEGACY_WHITELIST_STAGE_MINTER_ADDRS
+        .iter()
+        .zip(LEGACY_WHITELIST_STAGE_MINT_COUNTS.iter())
+        .enumerate()
+    {
+        let stage_id = i as u32 + 1;
+        let counts = minter_addrs
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (addr, count) in counts {
+            WHITELIST_STAGE_MINTER_ADDRS.save(storage, (&addr, stage_id), &count)?;
+            minter_addrs.remove(storage, &addr);
+        }
+        if let Some(count) = mint_count.may_load(storage)? {
+            WHITELIST_STAGE_MINT_COUNT.save(storage, stage_id, &count)?;
+            mint_count.remove(storage);
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::ContractError;
 use crate::ContractError::CheckedMultiplyFractionError;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Api, Coin, Timestamp, Uint128};
+use vending_factory::msg::{DutchAuctionParams, EscrowMilestone, EscrowParams, RandomnessSource};
 
 pub fn get_three_percent_of_tokens(num_tokens: u32) -> Result<Uint128, ContractError> {
     let three_percent = (Uint128::new(3), Uint128::new(100));
@@ -26,3 +27,86 @@ pub fn check_dynamic_per_address_limit(
     let result = Uint128::from(per_address_limit) <= three_percent_tokens;
     Ok(result)
 }
+
+// Check dutch auction prices are in the mint denom, only go down and stay above the network minimum
+pub fn validate_dutch_auction(
+    auction: &DutchAuctionParams,
+    min_mint_price: &Coin,
+) -> Result<(), ContractError> {
+    if auction.start_price.denom != min_mint_price.denom
+        || auction.floor_price.denom != min_mint_price.denom
+    {
+        return Err(ContractError::InvalidDenom {
+            expected: min_mint_price.denom.clone(),
+            got: auction.start_price.denom.clone(),
+        });
+    }
+    if auction.floor_price.amount < min_mint_price.amount {
+        return Err(ContractError::InsufficientMintPrice {
+            expected: min_mint_price.amount.u128(),
+            got: auction.floor_price.amount.u128(),
+        });
+    }
+    if auction.floor_price.amount > auction.start_price.amount {
+        return Err(ContractError::InvalidDutchAuction(
+            "floor price is higher than start price".to_string(),
+        ));
+    }
+    if auction.decay_step.is_zero() || auction.decay_interval == 0 {
+        return Err(ContractError::InvalidDutchAuction(
+            "decay step and interval must be greater than zero".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Check a reveal happens in a later block than the commit and the beacon address is valid
+pub fn validate_randomness_source(
+    api: &dyn Api,
+    randomness: &RandomnessSource,
+) -> Result<(), ContractError> {
+    match randomness {
+        RandomnessSource::BlockData => {}
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks,
+        } => {
+            if *reveal_delay_blocks == 0 {
+                return Err(ContractError::InvalidRandomnessSource(
+                    "reveal delay must be at least one block".to_string(),
+                ));
+            }
+        }
+        RandomnessSource::Beacon { address } => {
+            api.addr_validate(address).map_err(|_| {
+                ContractError::InvalidRandomnessSource(format!("invalid beacon address {address}"))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+// Check the escrow milestone can be reached before refunds open
+pub fn validate_escrow(escrow: &EscrowParams, start_time: Timestamp) -> Result<(), ContractError> {
+    if escrow.refund_deadline <= start_time {
+        return Err(ContractError::InvalidEscrow(
+            "refund deadline must be after the start time".to_string(),
+        ));
+    }
+    match escrow.milestone {
+        EscrowMilestone::Time(time) => {
+            if time >= escrow.refund_deadline {
+                return Err(ContractError::InvalidEscrow(
+                    "milestone must be before the refund deadline".to_string(),
+                ));
+            }
+        }
+        EscrowMilestone::PercentSold(percent) => {
+            if percent == 0 || percent > 100 {
+                return Err(ContractError::InvalidEscrow(
+                    "percent sold must be between 1 and 100".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
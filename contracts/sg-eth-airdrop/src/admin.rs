@@ -0,0 +1,87 @@
+use crate::contract::validation::{validate_claim_window, validate_merkle_root};
+use crate::state::{Config, CONFIG, ELIGIBLE_ADDRESSES};
+use crate::ContractError;
+use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response, Storage, Timestamp};
+use cw_utils::nonpayable;
+
+pub fn execute_update_claim_window(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Option<Timestamp>,
+    end_time: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = load_config_for_admin(deps.storage, &info)?;
+    if config
+        .end_time
+        .is_some_and(|end_time| env.block.time >= end_time)
+    {
+        return Err(ContractError::ClaimEnded {});
+    }
+    validate_claim_window(&env, start_time, end_time)?;
+
+    config.start_time = start_time;
+    config.end_time = end_time;
+    CONFIG.save(deps.storage, &config)?;
+
+    let mut res = Response::new().add_attribute("action", "update_claim_window");
+    if let Some(start_time) = start_time {
+        res = res.add_attribute("start_time", start_time.to_string());
+    }
+    if let Some(end_time) = end_time {
+        res = res.add_attribute("end_time", end_time.to_string());
+    }
+    Ok(res)
+}
+
+pub fn execute_add_eligible_addresses(
+    deps: DepsMut,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = load_config_for_admin(deps.storage, &info)?;
+    if config.merkle_root.is_some() {
+        return Err(ContractError::AddressesWithMerkleRoot {});
+    }
+
+    for address in addresses.iter() {
+        ELIGIBLE_ADDRESSES.save(deps.storage, address, &Empty {})?;
+    }
+    Ok(Response::new()
+        .add_attribute("action", "add_eligible_addresses")
+        .add_attribute("count", addresses.len().to_string()))
+}
+
+pub fn execute_update_merkle_root(
+    deps: DepsMut,
+    info: MessageInfo,
+    merkle_root: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = load_config_for_admin(deps.storage, &info)?;
+    if config.merkle_root.is_none() {
+        return Err(ContractError::MerkleRootNotSet {});
+    }
+    validate_merkle_root(&merkle_root, &[])?;
+
+    config.merkle_root = Some(merkle_root.clone());
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_merkle_root")
+        .add_attribute("merkle_root", merkle_root))
+}
+
+fn load_config_for_admin(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+) -> Result<Config, ContractError> {
+    let config = CONFIG.load(storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.clone(),
+        });
+    }
+    Ok(config)
+}
@@ -1,14 +1,25 @@
-use crate::query::query_collection_whitelist;
-use crate::state::ADDRS_TO_MINT_COUNT;
+use crate::msg::{ClaimAction, MerkleProof};
+use crate::query::{query_collection_whitelist, query_minter_airdrop_price};
+use crate::state::{Config, ADDRS_TO_MINT_COUNT, CLAIMERS, TOTAL_CLAIMED};
 use crate::{state::CONFIG, ContractError};
-use build_messages::claim_and_whitelist_add;
+use build_messages::claim_action_messages;
 use cosmwasm_std::{
-    coins, Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg,
+    coin, coins, Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdError,
+    StdResult, SubMsg, Uint128,
 };
 
+use sg_tiered_whitelist::helpers::interface::CollectionWhitelistContract as TieredWhitelistContract;
+use sg_tiered_whitelist::msg::{
+    AddMembersMsg as TieredWhitelistAddMembersMsg, ExecuteMsg as TieredWhitelistExecuteMsg,
+};
 use sg_whitelist::msg::ExecuteMsg as CollectionWhitelistExecuteMsg;
 use sg_whitelist::{helpers::interface::CollectionWhitelistContract, msg::AddMembersMsg};
-use validation::validate_claim;
+use sg_whitelist_flex::helpers::interface::CollectionWhitelistContract as WhitelistFlexContract;
+use sg_whitelist_flex::msg::{
+    AddMembersMsg as WhitelistFlexAddMembersMsg, ExecuteMsg as WhitelistFlexExecuteMsg,
+    HasMemberResponse, Member, QueryMsg as WhitelistFlexQueryMsg,
+};
+use validation::{validate_claim, validate_claim_window};
 
 pub fn claim_airdrop(
     deps: DepsMut,
@@ -16,19 +27,30 @@ pub fn claim_airdrop(
     _env: Env,
     eth_address: String,
     eth_sig: String,
+    pub_key: Option<String>,
+    proof: Option<MerkleProof>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    validate_claim(
+    validate_claim_window(&_env, &config)?;
+    // Merkle leaves hold lowercase Ethereum addresses, so claims are counted on the same key
+    let eth_address = config.normalize_address(eth_address);
+    let allocation = validate_claim(
         &deps,
+        &_env,
         info.clone(),
         eth_address.clone(),
         eth_sig,
+        pub_key,
         config.clone(),
+        proof,
     )?;
-    let res = claim_and_whitelist_add(&deps, info, config.airdrop_amount)?;
+    let (res, claimed_amount) =
+        claim_action_messages(&deps, info, &config, allocation.airdrop_amount)?;
+    let total_claimed = TOTAL_CLAIMED.may_load(deps.storage)?.unwrap_or_default();
+    TOTAL_CLAIMED.save(deps.storage, &(total_claimed + claimed_amount))?;
     increment_local_mint_count_for_address(deps, eth_address)?;
 
-    Ok(res.add_attribute("claimed_amount", config.airdrop_amount.to_string()))
+    Ok(res.add_attribute("claimed_amount", claimed_amount.to_string()))
 }
 
 pub fn increment_local_mint_count_for_address(
@@ -39,6 +61,10 @@ pub fn increment_local_mint_count_for_address(
         .load(deps.storage, &eth_address)
         .unwrap_or(0);
     ADDRS_TO_MINT_COUNT.save(deps.storage, &eth_address, &(mint_count_for_address + 1))?;
+    if mint_count_for_address == 0 {
+        let claimers = CLAIMERS.may_load(deps.storage)?.unwrap_or_default();
+        CLAIMERS.save(deps.storage, &(claimers + 1))?;
+    }
 
     Ok(Response::new())
 }
@@ -46,25 +72,83 @@ pub fn increment_local_mint_count_for_address(
 mod build_messages {
     use super::*;
     use sg_utils::NATIVE_DENOM;
+    use vending_minter::helpers::MinterContract;
+    use vending_minter::msg::ExecuteMsg as MinterExecuteMsg;
 
-    pub fn claim_and_whitelist_add(
+    /// Messages of the claim action of the airdrop, with the amount of the airdrop funds they spend
+    pub fn claim_action_messages(
         deps: &DepsMut,
         info: MessageInfo,
+        config: &Config,
         airdrop_amount: u128,
-    ) -> Result<Response, ContractError> {
-        let mut res = Response::new();
-        let bank_msg = SubMsg::new(BankMsg::Send {
-            to_address: info.sender.to_string(),
+    ) -> Result<(Response, Uint128), ContractError> {
+        let res = Response::new();
+        match config.claim_action {
+            ClaimAction::WhitelistAdd => {
+                let collection_whitelist = query_collection_whitelist(deps)?;
+                let res = send_airdrop(res, &info.sender, airdrop_amount).add_message(
+                    add_member_to_collection_whitelist(deps, info.sender, collection_whitelist)?,
+                );
+                Ok((res, Uint128::from(airdrop_amount)))
+            }
+            ClaimAction::WhitelistFlexAdd { mint_count } => {
+                let collection_whitelist = query_collection_whitelist(deps)?;
+                let mut res = send_airdrop(res, &info.sender, airdrop_amount);
+                // Whitelist-flex rejects duplicate members, so later claims only send funds
+                if !is_whitelist_flex_member(deps, &info.sender, &collection_whitelist)? {
+                    res = res.add_message(add_member_to_whitelist_flex(
+                        deps,
+                        info.sender,
+                        collection_whitelist,
+                        mint_count,
+                    )?);
+                }
+                Ok((res, Uint128::from(airdrop_amount)))
+            }
+            ClaimAction::TieredWhitelistAdd { stage_id } => {
+                let collection_whitelist = query_collection_whitelist(deps)?;
+                // The tiered whitelist skips members already in the stage, so later claims only send funds
+                let res = send_airdrop(res, &info.sender, airdrop_amount).add_message(
+                    add_member_to_tiered_whitelist(
+                        deps,
+                        info.sender,
+                        collection_whitelist,
+                        stage_id,
+                    )?,
+                );
+                Ok((res, Uint128::from(airdrop_amount)))
+            }
+            ClaimAction::MintTo { quantity } => {
+                let airdrop_price = query_minter_airdrop_price(deps)?;
+                let amount = airdrop_price
+                    .amount
+                    .checked_mul(quantity.into())
+                    .map_err(StdError::from)?;
+                let mint_to_msg = MinterExecuteMsg::MintTo {
+                    recipient: info.sender.to_string(),
+                    quantity: Some(quantity),
+                    royalty_info: None,
+                };
+                let minter = MinterContract(config.minter_address.clone());
+                let msg = match amount.is_zero() {
+                    true => minter.call(mint_to_msg)?,
+                    false => minter
+                        .call_with_funds(mint_to_msg, coin(amount.u128(), airdrop_price.denom))?,
+                };
+                Ok((res.add_message(msg), amount))
+            }
+            ClaimAction::SendTokens => Ok((
+                send_airdrop(res, &info.sender, airdrop_amount),
+                Uint128::from(airdrop_amount),
+            )),
+        }
+    }
+
+    fn send_airdrop(res: Response, recipient: &Addr, airdrop_amount: u128) -> Response {
+        res.add_submessage(SubMsg::new(BankMsg::Send {
+            to_address: recipient.to_string(),
             amount: coins(airdrop_amount, NATIVE_DENOM),
-        });
-        res = res.add_submessage(bank_msg);
-        let collection_whitelist = query_collection_whitelist(deps)?;
-        let res = res.add_message(add_member_to_collection_whitelist(
-            deps,
-            info.sender,
-            collection_whitelist,
-        )?);
-        Ok(res)
+        }))
     }
 
     fn add_member_to_collection_whitelist(
@@ -79,18 +163,64 @@ mod build_messages {
         CollectionWhitelistContract(deps.api.addr_validate(&collection_whitelist)?)
             .call(execute_msg)
     }
+
+    fn is_whitelist_flex_member(
+        deps: &DepsMut,
+        wallet_address: &Addr,
+        collection_whitelist: &str,
+    ) -> StdResult<bool> {
+        let res: HasMemberResponse = deps.querier.query_wasm_smart(
+            collection_whitelist,
+            &WhitelistFlexQueryMsg::HasMember {
+                member: wallet_address.to_string(),
+            },
+        )?;
+        Ok(res.has_member)
+    }
+
+    fn add_member_to_whitelist_flex(
+        deps: &DepsMut,
+        wallet_address: Addr,
+        collection_whitelist: String,
+        mint_count: u32,
+    ) -> StdResult<CosmosMsg> {
+        let inner_msg = WhitelistFlexAddMembersMsg {
+            to_add: vec![Member {
+                address: wallet_address.to_string(),
+                mint_count,
+            }],
+        };
+        let execute_msg = WhitelistFlexExecuteMsg::AddMembers(inner_msg);
+        WhitelistFlexContract(deps.api.addr_validate(&collection_whitelist)?).call(execute_msg)
+    }
+
+    fn add_member_to_tiered_whitelist(
+        deps: &DepsMut,
+        wallet_address: Addr,
+        collection_whitelist: String,
+        stage_id: u32,
+    ) -> StdResult<CosmosMsg> {
+        let inner_msg = TieredWhitelistAddMembersMsg {
+            to_add: vec![wallet_address.to_string()],
+            stage_id,
+        };
+        let execute_msg = TieredWhitelistExecuteMsg::AddMembers(inner_msg);
+        TieredWhitelistContract(deps.api.addr_validate(&collection_whitelist)?).call(execute_msg)
+    }
 }
 
 mod validation {
     use super::*;
-    use cosmwasm_std::StdError;
-    use ethereum_verify::verify_ethereum_text;
-
-    use crate::{
-        query::{query_airdrop_is_eligible, query_per_address_limit},
-        state::Config,
+    use claim_verify::{verify_cosmos_arbitrary, verify_solana_text};
+    use cosmwasm_std::Binary;
+    use ethereum_verify::{
+        verify_ethereum_text, verify_ethereum_typed_data, TypedClaim, TypedDataDomain,
     };
 
+    use crate::contract::{TYPED_DATA_DOMAIN_NAME, TYPED_DATA_DOMAIN_VERSION};
+    use crate::msg::{Allocation, SignatureType};
+    use crate::{query::query_allocation, state::Config};
+
     pub fn compute_plaintext_msg(config: &Config, info: MessageInfo) -> String {
         str::replace(
             &config.claim_msg_plaintext,
@@ -99,24 +229,56 @@ mod validation {
         )
     }
 
+    pub fn validate_claim_window(env: &Env, config: &Config) -> Result<(), ContractError> {
+        if config
+            .start_time
+            .is_some_and(|start_time| env.block.time < start_time)
+        {
+            return Err(ContractError::ClaimNotStarted {});
+        }
+        if config
+            .end_time
+            .is_some_and(|end_time| env.block.time >= end_time)
+        {
+            return Err(ContractError::ClaimEnded {});
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_claim(
         deps: &DepsMut,
+        env: &Env,
         info: MessageInfo,
         eth_address: String,
         eth_sig: String,
+        pub_key: Option<String>,
         config: Config,
-    ) -> Result<(), ContractError> {
-        validate_is_eligible(deps, eth_address.clone())?;
-        validate_eth_sig(deps, info, eth_address.clone(), eth_sig, config)?;
-        validate_mints_remaining(deps, &eth_address)?;
-        Ok(())
+        proof: Option<MerkleProof>,
+    ) -> Result<Allocation, ContractError> {
+        let allocation = validate_is_eligible(deps, eth_address.clone(), proof)?;
+        validate_eth_sig(
+            deps,
+            env,
+            info,
+            eth_address.clone(),
+            eth_sig,
+            pub_key,
+            config,
+        )?;
+        validate_mints_remaining(deps, &eth_address, allocation.per_address_limit)?;
+        Ok(allocation)
     }
 
-    fn validate_is_eligible(deps: &DepsMut, eth_address: String) -> Result<(), ContractError> {
-        let eligible = query_airdrop_is_eligible(deps.as_ref(), eth_address.clone())?;
-        match eligible {
-            true => Ok(()),
-            false => Err(ContractError::AddressNotEligible {
+    fn validate_is_eligible(
+        deps: &DepsMut,
+        eth_address: String,
+        proof: Option<MerkleProof>,
+    ) -> Result<Allocation, ContractError> {
+        let allocation = query_allocation(deps.as_ref(), eth_address.clone(), proof)?;
+        match allocation {
+            Some(allocation) => Ok(allocation),
+            None => Err(ContractError::AddressNotEligible {
                 address: eth_address,
             }),
         }
@@ -124,13 +286,37 @@ mod validation {
 
     fn validate_eth_sig(
         deps: &DepsMut,
+        env: &Env,
         info: MessageInfo,
         eth_address: String,
         eth_sig: String,
+        pub_key: Option<String>,
         config: Config,
     ) -> Result<(), ContractError> {
-        let valid_eth_sig =
-            validate_ethereum_text(deps, info, &config, eth_sig, eth_address.clone())?;
+        let valid_eth_sig = match config.signature_type {
+            SignatureType::PersonalSign => {
+                validate_ethereum_text(deps, info, &config, eth_sig, eth_address.clone())?
+            }
+            SignatureType::TypedData => {
+                validate_ethereum_typed_data(deps, env, info, eth_sig, eth_address.clone())?
+            }
+            SignatureType::Adr036 => {
+                let pub_key = pub_key.ok_or(ContractError::PubKeyRequired {})?;
+                let plaintext_msg = compute_plaintext_msg(&config, info);
+                verify_cosmos_arbitrary(
+                    deps.as_ref(),
+                    &plaintext_msg,
+                    &Binary::from_base64(&eth_sig)?,
+                    &Binary::from_base64(&pub_key)?,
+                    &eth_address,
+                )?
+            }
+            SignatureType::Solana => {
+                let plaintext_msg = compute_plaintext_msg(&config, info);
+                let eth_sig_hex = decode_eth_sig(eth_sig)?;
+                verify_solana_text(deps.as_ref(), &plaintext_msg, &eth_sig_hex, &eth_address)?
+            }
+        };
         match valid_eth_sig {
             true => Ok(()),
             false => Err(ContractError::AddressNotEligible {
@@ -142,10 +328,10 @@ mod validation {
     pub fn validate_mints_remaining(
         deps: &DepsMut,
         eth_address: &str,
+        per_address_limit: u32,
     ) -> Result<(), ContractError> {
         let mint_count = ADDRS_TO_MINT_COUNT.load(deps.storage, eth_address);
         let mint_count = mint_count.unwrap_or(0);
-        let per_address_limit = query_per_address_limit(&deps.as_ref())?;
         if mint_count < per_address_limit {
             Ok(())
         } else {
@@ -163,13 +349,39 @@ mod validation {
         eth_address: String,
     ) -> StdResult<bool> {
         let plaintext_msg = compute_plaintext_msg(config, info);
-        match hex::decode(eth_sig.clone()) {
-            Ok(eth_sig_hex) => {
-                verify_ethereum_text(deps.as_ref(), &plaintext_msg, &eth_sig_hex, &eth_address)
-            }
-            Err(_) => Err(StdError::InvalidHex {
-                msg: format!("Could not decode {eth_sig}"),
-            }),
-        }
+        let eth_sig_hex = decode_eth_sig(eth_sig)?;
+        verify_ethereum_text(deps.as_ref(), &plaintext_msg, &eth_sig_hex, &eth_address)
+    }
+
+    /// Verifies an EIP-712 claim for the sender, with the claims already made by the Ethereum
+    /// address as nonce so that each signature can only be used once
+    pub fn validate_ethereum_typed_data(
+        deps: &DepsMut,
+        env: &Env,
+        info: MessageInfo,
+        eth_sig: String,
+        eth_address: String,
+    ) -> StdResult<bool> {
+        let domain = TypedDataDomain {
+            name: TYPED_DATA_DOMAIN_NAME.to_string(),
+            version: TYPED_DATA_DOMAIN_VERSION.to_string(),
+        };
+        let claim = TypedClaim {
+            chain_id: env.block.chain_id.clone(),
+            verifying_contract: env.contract.address.to_string(),
+            recipient: info.sender.to_string(),
+            nonce: ADDRS_TO_MINT_COUNT
+                .may_load(deps.storage, &eth_address)?
+                .unwrap_or(0)
+                .into(),
+        };
+        let eth_sig_hex = decode_eth_sig(eth_sig)?;
+        verify_ethereum_typed_data(deps.as_ref(), &domain, &claim, &eth_sig_hex, &eth_address)
+    }
+
+    fn decode_eth_sig(eth_sig: String) -> StdResult<Vec<u8>> {
+        hex::decode(eth_sig.clone()).map_err(|_| StdError::InvalidHex {
+            msg: format!("Could not decode {eth_sig}"),
+        })
     }
 }
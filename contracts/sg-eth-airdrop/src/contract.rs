@@ -1,13 +1,17 @@
+use crate::admin::{
+    execute_add_eligible_addresses, execute_update_claim_window, execute_update_merkle_root,
+};
 use crate::claim_airdrop::claim_airdrop;
 #[cfg(not(feature = "library"))]
 use crate::error::ContractError;
+use crate::funds::{execute_top_up, execute_withdraw_unclaimed};
 use crate::msg::{ExecuteMsg, InstantiateMsg};
 use crate::state::CONFIG;
 
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use cw2::set_contract_version;
-use sg1::fair_burn;
+use sg1::{fair_burn, query_minter_fee_routing};
 
 use build_message::{state_config, whitelist_instantiate};
 use validation::validate_instantiation_params;
@@ -15,6 +19,8 @@ use validation::validate_instantiation_params;
 const CONTRACT_NAME: &str = "crates.io:sg-eth-airdrop";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const INSTANTIATION_FEE: u128 = 100_000_000; // 100 STARS
+pub const TYPED_DATA_DOMAIN_NAME: &str = "Stargaze Airdrop";
+pub const TYPED_DATA_DOMAIN_VERSION: &str = "1";
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -24,17 +30,27 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    validate_instantiation_params(info.clone(), msg.clone())?;
+    validate_instantiation_params(&env, info.clone(), msg.clone())?;
+    let fee_routing = query_minter_fee_routing(&deps.querier, &msg.minter_address)?;
     let mut res = Response::new();
-    fair_burn(info.sender.to_string(), INSTANTIATION_FEE, None, &mut res);
+    fair_burn(
+        info.sender.to_string(),
+        INSTANTIATION_FEE,
+        None,
+        &mut res,
+        &fee_routing,
+    );
     let cfg = state_config(deps.as_ref(), info.clone(), msg.clone())?;
     CONFIG.save(deps.storage, &cfg)?;
-    Ok(res
+    let res = res
         .add_attribute("action", "instantiate")
         .add_attribute("contract_name", CONTRACT_NAME)
         .add_attribute("contract_version", CONTRACT_VERSION)
-        .add_attribute("sender", info.sender)
-        .add_submessage(whitelist_instantiate(env, msg)?))
+        .add_attribute("sender", info.sender);
+    match msg.merkle_root {
+        Some(merkle_root) => Ok(res.add_attribute("merkle_root", merkle_root)),
+        None => Ok(res.add_submessage(whitelist_instantiate(env, msg)?)),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -48,7 +64,23 @@ pub fn execute(
         ExecuteMsg::ClaimAirdrop {
             eth_address,
             eth_sig,
-        } => claim_airdrop(deps, info, _env, eth_address, eth_sig),
+            pub_key,
+            proof,
+        } => claim_airdrop(deps, info, _env, eth_address, eth_sig, pub_key, proof),
+        ExecuteMsg::TopUp {} => execute_top_up(info),
+        ExecuteMsg::WithdrawUnclaimed { recipient } => {
+            execute_withdraw_unclaimed(deps, _env, info, recipient)
+        }
+        ExecuteMsg::UpdateClaimWindow {
+            start_time,
+            end_time,
+        } => execute_update_claim_window(deps, _env, info, start_time, end_time),
+        ExecuteMsg::AddEligibleAddresses { addresses } => {
+            execute_add_eligible_addresses(deps, info, addresses)
+        }
+        ExecuteMsg::UpdateMerkleRoot { merkle_root } => {
+            execute_update_merkle_root(deps, info, merkle_root)
+        }
     }
 }
 
@@ -93,26 +125,73 @@ mod build_message {
             airdrop_amount: validate_airdrop_amount(msg.airdrop_amount)?,
             whitelist_address: None,
             minter_address: deps.api.addr_validate(msg.minter_address.as_ref())?,
+            merkle_root: msg.merkle_root,
+            signature_type: msg.signature_type.unwrap_or_default(),
+            start_time: msg.start_time,
+            end_time: msg.end_time,
+            claim_action: msg.claim_action.unwrap_or_default(),
         })
     }
 }
 
-mod validation {
+pub(crate) mod validation {
     use super::*;
-    use cosmwasm_std::Uint128;
+    use crate::msg::ClaimAction;
+    use cosmwasm_std::{Timestamp, Uint128};
     use cw_utils::must_pay;
     use sg_utils::NATIVE_DENOM;
+    use whitelist_mtree::helpers::crypto::verify_merkle_root;
 
     const MIN_AIRDROP: u128 = 10_000_000; // 10 STARS
     const MAX_AIRDROP: u128 = 100_000_000_000_000; // 100 million STARS
 
     pub fn validate_instantiation_params(
+        env: &Env,
         info: MessageInfo,
         msg: InstantiateMsg,
     ) -> Result<(), ContractError> {
         validate_airdrop_amount(msg.airdrop_amount)?;
+        validate_claim_window(env, msg.start_time, msg.end_time)?;
+        if let Some(claim_action) = msg.claim_action {
+            validate_claim_action(&claim_action)?;
+        }
         validate_plaintext_msg(msg.claim_msg_plaintext)?;
         validate_instantiate_funds(info)?;
+        if let Some(merkle_root) = msg.merkle_root {
+            validate_merkle_root(&merkle_root, &msg.addresses)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_claim_window(
+        env: &Env,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+    ) -> Result<(), ContractError> {
+        if let Some(end_time) = end_time {
+            if end_time <= env.block.time || start_time.is_some_and(|start| start >= end_time) {
+                return Err(ContractError::InvalidClaimWindow {});
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate_claim_action(claim_action: &ClaimAction) -> Result<(), ContractError> {
+        match claim_action {
+            ClaimAction::WhitelistFlexAdd { mint_count: 0 }
+            | ClaimAction::MintTo { quantity: 0 } => Err(ContractError::InvalidClaimAction {}),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_merkle_root(
+        merkle_root: &String,
+        addresses: &[String],
+    ) -> Result<(), ContractError> {
+        verify_merkle_root(merkle_root)?;
+        if !addresses.is_empty() {
+            return Err(ContractError::AddressesWithMerkleRoot {});
+        }
         Ok(())
     }
 
@@ -49,6 +49,30 @@ pub enum ContractError {
     #[error("Plaintext message must contain `{{wallet}}` string")]
     PlaintextMsgNoWallet {},
 
+    #[error("Addresses can't be set along with a merkle root")]
+    AddressesWithMerkleRoot {},
+
+    #[error("Airdrop does not use a merkle root")]
+    MerkleRootNotSet {},
+
+    #[error("A public key is required to verify ADR-036 signatures")]
+    PubKeyRequired {},
+
+    #[error("Claim start time must be before its end time, and the end time in the future")]
+    InvalidClaimWindow {},
+
+    #[error("Airdrop claims have not started")]
+    ClaimNotStarted {},
+
+    #[error("Airdrop claims have ended")]
+    ClaimEnded {},
+
+    #[error("Unclaimed funds can only be withdrawn after the claim end time")]
+    ClaimNotEnded {},
+
+    #[error("Claim actions must mint or allow at least one token")]
+    InvalidClaimAction {},
+
     #[error("Plaintext message is too long")]
     PlaintextTooLong {},
 }
@@ -0,0 +1,51 @@
+use crate::{state::CONFIG, ContractError};
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response};
+use cw_utils::{must_pay, nonpayable};
+use sg_utils::NATIVE_DENOM;
+
+pub fn execute_top_up(info: MessageInfo) -> Result<Response, ContractError> {
+    let amount = must_pay(&info, NATIVE_DENOM)?;
+    Ok(Response::new()
+        .add_attribute("action", "top_up")
+        .add_attribute("sender", info.sender)
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_withdraw_unclaimed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender,
+        });
+    }
+    match config.end_time {
+        Some(end_time) if env.block.time >= end_time => (),
+        _ => return Err(ContractError::ClaimNotEnded {}),
+    }
+
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender,
+    };
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, NATIVE_DENOM)?;
+    if balance.amount.is_zero() {
+        return Err(ContractError::NoFunds {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_unclaimed")
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("amount", balance.amount)
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(balance.amount.u128(), NATIVE_DENOM),
+        }))
+}
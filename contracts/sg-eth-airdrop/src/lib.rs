@@ -5,4 +5,6 @@ pub mod query;
 pub mod reply;
 pub mod state;
 pub use crate::error::ContractError;
+mod admin;
 mod claim_airdrop;
+mod funds;
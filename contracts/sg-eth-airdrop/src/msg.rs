@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -10,6 +10,99 @@ pub struct InstantiateMsg {
     pub whitelist_code_id: u64,
     pub minter_address: Addr,
     pub per_address_limit: u32,
+    /// Root of a merkle tree of allocations, replacing the whitelist of `addresses`.
+    /// Each leaf is the sha256 hash of `{eth_address}:{per_address_limit}:{airdrop_amount}`,
+    /// with Ethereum addresses in lowercase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merkle_root: Option<String>,
+    /// How claims are signed, `personal_sign` of `claim_msg_plaintext` by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_type: Option<SignatureType>,
+    /// Claims are rejected before this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<Timestamp>,
+    /// Claims are rejected from this time, after which the admin can withdraw unclaimed funds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<Timestamp>,
+    /// What a claim does for the claimer, `whitelist_add` by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claim_action: Option<ClaimAction>,
+}
+
+#[cw_serde]
+#[derive(Eq, Default)]
+pub enum SignatureType {
+    /// `personal_sign` of `claim_msg_plaintext`, with `{wallet}` replaced by the recipient
+    #[default]
+    PersonalSign,
+    /// EIP-712 typed data `Claim` of the chain id, airdrop contract, recipient and nonce,
+    /// the nonce being the number of claims already made by the Ethereum address
+    TypedData,
+    /// Cosmos ADR-036 `signArbitrary` of `claim_msg_plaintext`, by a bech32 address of any chain
+    Adr036,
+    /// Solana `signMessage` of `claim_msg_plaintext`, by a base58 address
+    Solana,
+}
+
+impl SignatureType {
+    /// Ethereum addresses are hex, so their case doesn't matter
+    pub fn is_ethereum(&self) -> bool {
+        matches!(self, SignatureType::PersonalSign | SignatureType::TypedData)
+    }
+}
+
+#[cw_serde]
+#[derive(Eq, Default)]
+pub enum ClaimAction {
+    /// Sends the airdrop amount and adds the claimer to the `sg_whitelist` of the minter,
+    /// the airdrop contract being one of its admins
+    #[default]
+    WhitelistAdd,
+    /// Sends the airdrop amount and adds the claimer to the whitelist-flex of the minter with
+    /// `mint_count` mints, the airdrop contract being one of its admins
+    WhitelistFlexAdd { mint_count: u32 },
+    /// Sends the airdrop amount and adds the claimer to stage `stage_id` of the tiered whitelist
+    /// of the minter, the airdrop contract being one of its admins
+    TieredWhitelistAdd { stage_id: u32 },
+    /// Mints `quantity` tokens to the claimer with `MintTo`, the airdrop contract being the
+    /// minter admin. The airdrop price of the minter is paid from the airdrop funds instead of
+    /// sending the airdrop amount.
+    MintTo { quantity: u32 },
+    /// Only sends the airdrop amount
+    SendTokens,
+}
+
+/// Airdrop granted to an eligible Ethereum address
+#[cw_serde]
+pub struct Allocation {
+    /// Number of times the address can claim
+    pub per_address_limit: u32,
+    /// Amount sent to the claimer on each claim
+    pub airdrop_amount: u128,
+}
+
+/// Proof that an allocation is a leaf of the airdrop merkle tree
+#[cw_serde]
+pub struct MerkleProof {
+    pub allocation: Allocation,
+    pub proof_hashes: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ClaimStatsResponse {
+    pub total_claimed: Uint128,
+    /// Funds of the airdrop left to claim
+    pub remaining_budget: Uint128,
+    /// Number of addresses that claimed at least once
+    pub claimers: u32,
+    pub start_time: Option<Timestamp>,
+    pub end_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct AirdropEligibleResponse {
+    pub eligible: bool,
+    pub allocation: Option<Allocation>,
 }
 
 #[cw_serde]
@@ -21,17 +114,47 @@ pub struct AirdropClaimResponse {
 
 #[cw_serde]
 pub enum ExecuteMsg {
+    /// `eth_address` is the address on the chain of the signature type, and `eth_sig` its
+    /// signature, hex encoded or base64 encoded for ADR-036
     ClaimAirdrop {
         eth_address: String,
         eth_sig: String,
+        /// Base64 encoded public key of the signer, required for ADR-036
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub_key: Option<String>,
+        /// Required when the airdrop uses a merkle root
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        proof: Option<MerkleProof>,
     },
+    /// Adds funds to the airdrop
+    TopUp {},
+    /// Admin sends the unclaimed funds to `recipient`, or to itself, once the claim window ended
+    WithdrawUnclaimed { recipient: Option<String> },
+    /// Admin replaces the claim window, until its end time
+    UpdateClaimWindow {
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+    },
+    /// Admin makes more addresses eligible, when the airdrop does not use a merkle root
+    AddEligibleAddresses { addresses: Vec<String> },
+    /// Admin replaces the merkle root of allocations, when the airdrop uses one
+    UpdateMerkleRoot { merkle_root: String },
 }
 
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    #[returns(bool)]
-    AirdropEligible { eth_address: String },
+    #[returns(AirdropEligibleResponse)]
+    AirdropEligible {
+        eth_address: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        proof: Option<MerkleProof>,
+    },
     #[returns(Addr)]
     GetMinter {},
+    /// Claims made by an Ethereum address, the nonce of its next typed data claim
+    #[returns(u32)]
+    ClaimCount { eth_address: String },
+    #[returns(ClaimStatsResponse)]
+    ClaimStats {},
 }
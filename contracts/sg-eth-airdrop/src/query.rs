@@ -1,26 +1,105 @@
+use crate::msg::{AirdropEligibleResponse, Allocation, ClaimStatsResponse, MerkleProof};
+use crate::state::{ADDRS_TO_MINT_COUNT, CLAIMERS, ELIGIBLE_ADDRESSES, TOTAL_CLAIMED};
 use crate::{msg::QueryMsg, state::CONFIG, ContractError};
 use cosmwasm_std::{entry_point, to_json_binary, Binary};
-use cosmwasm_std::{Addr, Env};
+use cosmwasm_std::{Addr, Coin, Env};
 use cosmwasm_std::{Deps, DepsMut, StdResult};
-use vending_minter::helpers::MinterContract;
+use serde::Deserialize;
+use sg_utils::NATIVE_DENOM;
+use vending_minter::msg::QueryMsg as MinterQueryMsg;
 use whitelist_immutable::helpers::WhitelistImmutableContract;
+use whitelist_mtree::helpers::crypto::compute_merkle_root;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::AirdropEligible { eth_address } => {
-            to_json_binary(&query_airdrop_is_eligible(deps, eth_address)?)
+        QueryMsg::AirdropEligible { eth_address, proof } => {
+            to_json_binary(&query_airdrop_eligible(deps, eth_address, proof)?)
         }
         QueryMsg::GetMinter {} => to_json_binary(&query_minter(deps)?),
+        QueryMsg::ClaimCount { eth_address } => {
+            to_json_binary(&query_claim_count(deps, eth_address)?)
+        }
+        QueryMsg::ClaimStats {} => to_json_binary(&query_claim_stats(deps, env)?),
     }
 }
 
+fn query_claim_stats(deps: Deps, env: Env) -> StdResult<ClaimStatsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let remaining_budget = deps
+        .querier
+        .query_balance(env.contract.address, NATIVE_DENOM)?
+        .amount;
+    Ok(ClaimStatsResponse {
+        total_claimed: TOTAL_CLAIMED.may_load(deps.storage)?.unwrap_or_default(),
+        remaining_budget,
+        claimers: CLAIMERS.may_load(deps.storage)?.unwrap_or_default(),
+        start_time: config.start_time,
+        end_time: config.end_time,
+    })
+}
+
+fn query_claim_count(deps: Deps, eth_address: String) -> StdResult<u32> {
+    let config = CONFIG.load(deps.storage)?;
+    let eth_address = config.normalize_address(eth_address);
+    Ok(ADDRS_TO_MINT_COUNT
+        .may_load(deps.storage, &eth_address)?
+        .unwrap_or(0))
+}
+
 fn query_minter(deps: Deps) -> StdResult<Addr> {
     let config = CONFIG.load(deps.storage)?;
     Ok(config.minter_address)
 }
 
+fn query_airdrop_eligible(
+    deps: Deps,
+    eth_address: String,
+    proof: Option<MerkleProof>,
+) -> StdResult<AirdropEligibleResponse> {
+    let allocation = query_allocation(deps, eth_address, proof)?;
+    Ok(AirdropEligibleResponse {
+        eligible: allocation.is_some(),
+        allocation,
+    })
+}
+
+/// Allocation of an Ethereum address, from the merkle proof when the airdrop uses a merkle root,
+/// otherwise from the whitelist and the airdrop config
+pub fn query_allocation(
+    deps: Deps,
+    eth_address: String,
+    proof: Option<MerkleProof>,
+) -> StdResult<Option<Allocation>> {
+    let config = CONFIG.load(deps.storage)?;
+    let eth_address = config.normalize_address(eth_address);
+    match (config.merkle_root, proof) {
+        (Some(merkle_root), Some(proof)) => {
+            let leaf = format!(
+                "{}:{}:{}",
+                eth_address, proof.allocation.per_address_limit, proof.allocation.airdrop_amount
+            );
+            let root = compute_merkle_root(leaf.as_bytes(), proof.proof_hashes)?;
+            match root == merkle_root {
+                true => Ok(Some(proof.allocation)),
+                false => Ok(None),
+            }
+        }
+        (Some(_), None) => Ok(None),
+        (None, _) => match query_airdrop_is_eligible(deps, eth_address)? {
+            true => Ok(Some(Allocation {
+                per_address_limit: query_per_address_limit(&deps)?,
+                airdrop_amount: config.airdrop_amount,
+            })),
+            false => Ok(None),
+        },
+    }
+}
+
 pub fn query_airdrop_is_eligible(deps: Deps, eth_address: String) -> StdResult<bool> {
+    if ELIGIBLE_ADDRESSES.has(deps.storage, &eth_address) {
+        return Ok(true);
+    }
     let config = CONFIG.load(deps.storage)?;
     match config.whitelist_address {
         Some(address) => WhitelistImmutableContract(deps.api.addr_validate(&address)?)
@@ -31,16 +110,38 @@ pub fn query_airdrop_is_eligible(deps: Deps, eth_address: String) -> StdResult<b
     }
 }
 
+/// Fields shared by the config of every minter
+#[derive(Deserialize)]
+struct MinterConfigResponse {
+    whitelist: Option<String>,
+}
+
+/// Fields shared by the mint price of every minter
+#[derive(Deserialize)]
+struct MinterMintPriceResponse {
+    airdrop_price: Coin,
+}
+
 pub fn query_collection_whitelist(deps: &DepsMut) -> Result<String, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let minter_addr = config.minter_address;
-    let config = MinterContract(minter_addr).config(&deps.querier)?;
+    let config: MinterConfigResponse = deps
+        .querier
+        .query_wasm_smart(config.minter_address, &MinterQueryMsg::Config {})?;
     match config.whitelist {
         Some(whitelist) => Ok(whitelist),
         None => Err(ContractError::CollectionWhitelistMinterNotSet {}),
     }
 }
 
+/// Price the minter admin pays for each `MintTo`
+pub fn query_minter_airdrop_price(deps: &DepsMut) -> StdResult<Coin> {
+    let config = CONFIG.load(deps.storage)?;
+    let mint_price: MinterMintPriceResponse = deps
+        .querier
+        .query_wasm_smart(config.minter_address, &MinterQueryMsg::MintPrice {})?;
+    Ok(mint_price.airdrop_price)
+}
+
 pub fn query_per_address_limit(deps: &Deps) -> StdResult<u32> {
     let config = CONFIG.load(deps.storage)?;
     match config.whitelist_address {
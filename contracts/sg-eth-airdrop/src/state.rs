@@ -1,4 +1,6 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Empty, Timestamp, Uint128};
+
+use crate::msg::{ClaimAction, SignatureType};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,7 +12,31 @@ pub struct Config {
     pub airdrop_amount: u128,
     pub whitelist_address: Option<String>,
     pub minter_address: Addr,
+    #[serde(default)]
+    pub merkle_root: Option<String>,
+    #[serde(default)]
+    pub signature_type: SignatureType,
+    #[serde(default)]
+    pub start_time: Option<Timestamp>,
+    #[serde(default)]
+    pub end_time: Option<Timestamp>,
+    #[serde(default)]
+    pub claim_action: ClaimAction,
 }
 
 pub const CONFIG: Item<Config> = Item::new("cfg");
 pub const ADDRS_TO_MINT_COUNT: Map<&str, u32> = Map::new("amc");
+pub const TOTAL_CLAIMED: Item<Uint128> = Item::new("tc");
+pub const CLAIMERS: Item<u32> = Item::new("cc");
+/// Addresses made eligible by the admin after instantiation, along with the whitelist
+pub const ELIGIBLE_ADDRESSES: Map<&str, Empty> = Map::new("ea");
+
+impl Config {
+    /// Key of an address in the merkle tree and claim counts
+    pub fn normalize_address(&self, address: String) -> String {
+        match self.merkle_root.is_some() && self.signature_type.is_ethereum() {
+            true => address.to_lowercase(),
+            false => address,
+        }
+    }
+}
@@ -2,27 +2,39 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     coins, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty,
-    Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, Uint128,
+    Env, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128,
+    WasmMsg,
 };
 use cw2::set_contract_version;
-use cw4::{Cw4Contract, Member, MemberListResponse, MemberResponse};
+use cw20::{Cw20Contract, Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw4::{Cw4Contract, Member, MemberChangedHookMsg, MemberListResponse, MemberResponse};
+use cw721::{Cw721ExecuteMsg, Cw721ReceiveMsg};
+use cw_storage_plus::Bound;
 use cw_utils::{maybe_addr, parse_reply_instantiate_data};
 use semver::Version;
+use sg1::SplitsInfoResponse;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, Group, InstantiateMsg, QueryMsg};
-use crate::state::{ADMIN, GROUP};
+use crate::msg::{
+    ClaimableResponse, Cw20TokensResponse, DistributionResponse, ExecuteMsg, Group, InstantiateMsg,
+    Nft, NftsResponse, QueryMsg, ReceivedResponse,
+};
+use crate::state::{
+    Distribution, MemberAccount, ADMIN, CW20_TOKENS, DISTRIBUTION, DISTRIBUTION_THRESHOLD, GROUP,
+    INDICES, MEMBER_ACCOUNTS, NFTS, OUTSTANDING, TRACKED_MEMBERS,
+};
 
 // Version info for migration info
 pub const CONTRACT_NAME: &str = "crates.io:sg-splits";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const INIT_GROUP_REPLY_ID: u64 = 1;
+const DISTRIBUTE_REPLY_ID: u64 = 2;
 
-// This is the same hardcoded value as in cw4-group
+const CW20_DENOM_PREFIX: &str = "cw20:";
+
+// This is the same hardcoded value as in cw4-group, members are paid in pages of this size
 pub const PAGINATION_LIMIT: u32 = 30;
-// We hardcode a smaller number to effectively check group size
-pub const MAX_GROUP_SIZE: u32 = 25;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -38,6 +50,14 @@ pub fn instantiate(
     let admin_addr = maybe_addr(deps.api, msg.admin)?;
     ADMIN.set(deps.branch(), admin_addr)?;
 
+    let threshold = msg.distribution_threshold.unwrap_or_default();
+    checked_distribution_threshold(&threshold)?;
+    DISTRIBUTION_THRESHOLD.save(deps.storage, &threshold)?;
+
+    for token in msg.cw20_tokens.unwrap_or_default() {
+        CW20_TOKENS.save(deps.storage, &deps.api.addr_validate(&token)?, &Empty {})?;
+    }
+
     match msg.group {
         Group::Cw4Instantiate(init) => Ok(Response::default().add_submessage(
             SubMsg::reply_on_success(init.into_wasm_msg(self_addr), INIT_GROUP_REPLY_ID),
@@ -51,7 +71,9 @@ pub fn instantiate(
 
             checked_total_weight(&group, deps.as_ref())?;
             checked_total_members(&group, deps.as_ref())?;
+            checked_member_changed_hook(&group, deps.as_ref(), &self_addr)?;
 
+            track_members(deps.branch(), &group)?;
             GROUP.save(deps.storage, &group)?;
             Ok(Response::default())
         }
@@ -71,35 +93,454 @@ pub fn execute(
         ExecuteMsg::UpdateAdmin { admin } => {
             Ok(ADMIN.execute_update_admin(deps, info, maybe_addr(api, admin)?)?)
         }
-        ExecuteMsg::Distribute { denom_list } => {
-            execute_distribute(deps.as_ref(), env, info, denom_list)
+        ExecuteMsg::Distribute {
+            denom_list,
+            cw20_list,
+        } => execute_distribute(deps, env, info, denom_list, cw20_list),
+        ExecuteMsg::AbortDistribution {} => execute_abort_distribution(deps, info),
+        ExecuteMsg::Claim { denoms, cw20_list } => {
+            execute_claim(deps, env, info, denoms, cw20_list)
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, info, msg),
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_nft(deps, info, msg),
+        ExecuteMsg::TransferNft {
+            collection,
+            token_id,
+            recipient,
+        } => execute_transfer_nft(deps, info, collection, token_id, recipient),
+        ExecuteMsg::UpdateCw20Tokens { add, remove } => {
+            execute_update_cw20_tokens(deps, info, add, remove)
         }
+        ExecuteMsg::Deposit {} => execute_deposit(deps, env, info),
+        ExecuteMsg::UpdateDistributionThreshold { threshold } => {
+            execute_update_distribution_threshold(deps, info, threshold)
+        }
+        ExecuteMsg::MemberChangedHook(msg) => execute_member_changed_hook(deps, env, info, msg),
     }
 }
 
 pub fn execute_distribute(
-    deps: Deps,
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
     denom_list: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
 ) -> Result<Response, ContractError> {
-    if !can_distribute(deps, info)? {
+    if !can_distribute(deps.as_ref(), &env, info)? {
         return Err(ContractError::Unauthorized {});
     }
+    checked_cw20_list(deps.as_ref(), cw20_list.as_deref())?;
 
+    distribute_page(deps, &env, denom_list, cw20_list)
+}
+
+/// Pays the next page of members of the distribution in progress, or of a new one
+fn distribute_page(
+    mut deps: DepsMut,
+    env: &Env,
+    denom_list: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
     let group = GROUP.load(deps.storage)?;
 
-    let total_weight = checked_total_weight(&group, deps)?;
-    let members = group.list_members(&deps.querier, None, Some(PAGINATION_LIMIT))?;
-    let members_count = members.len();
-    if members_count == 0 || members_count > MAX_GROUP_SIZE as usize {
-        return Err(ContractError::InvalidMemberCount {
-            count: members_count,
-        });
+    // Resume the distribution in progress, or book deposits and snapshot what is owed to start a new one.
+    // The denom and cw20 lists only apply when a new distribution starts.
+    let mut distribution = match DISTRIBUTION.may_load(deps.storage)? {
+        Some(distribution) => distribution,
+        None => start_distribution(deps.branch(), env, &group, denom_list, cw20_list)?,
+    };
+
+    let members = group.list_members(
+        &deps.querier,
+        distribution.cursor.clone(),
+        Some(PAGINATION_LIMIT),
+    )?;
+
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    for member in members.iter() {
+        let addr = Addr::unchecked(&member.addr);
+        for (coin, distributed) in distribution
+            .funds
+            .iter()
+            .zip(distribution.distributed.iter_mut())
+        {
+            let amount = pay_out(deps.storage, &addr, &coin.denom, member.weight)?;
+            if amount.is_zero() {
+                continue;
+            }
+
+            distributed.amount += amount;
+            msgs.push(transfer_msg(&member.addr, &coin.denom, amount)?);
+        }
+    }
+
+    let complete = members.len() < PAGINATION_LIMIT as usize;
+    if complete {
+        DISTRIBUTION.remove(deps.storage);
+    } else {
+        distribution.cursor = members.last().map(|m| m.addr.clone());
+        DISTRIBUTION.save(deps.storage, &distribution)?;
     }
+
+    Ok(Response::new()
+        .add_attribute("action", "distribute")
+        .add_attribute("members", members.len().to_string())
+        .add_attribute("complete", complete.to_string())
+        .add_messages(msgs))
+}
+
+fn start_distribution(
+    deps: DepsMut,
+    env: &Env,
+    group: &Cw4Contract,
+    denom_list: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
+) -> Result<Distribution, ContractError> {
+    let total_weight = checked_total_weight(group, deps.as_ref())?;
+    checked_total_members(group, deps.as_ref())?;
+
+    let balances = query_balances(deps.as_ref(), env, denom_list, cw20_list)?;
+    ensure!(!balances.is_empty(), ContractError::NoFunds {});
+
+    book_deposits(deps.storage, &balances, total_weight)?;
+
     let mut funds: Vec<Coin> = Vec::new();
-    if let Some(denom_list) = denom_list {
-        for denom in denom_list.iter() {
+    for coin in balances {
+        let outstanding = OUTSTANDING
+            .may_load(deps.storage, &coin.denom)?
+            .unwrap_or_default();
+        if !outstanding.is_zero() {
+            funds.push(Coin::new(outstanding.u128(), coin.denom));
+        }
+    }
+
+    ensure!(
+        !funds.is_empty(),
+        ContractError::NotEnoughFunds { min: total_weight }
+    );
+
+    Ok(Distribution {
+        distributed: funds
+            .iter()
+            .map(|coin| Coin::new(0, coin.denom.clone()))
+            .collect(),
+        funds,
+        total_weight,
+        cursor: None,
+    })
+}
+
+/// Drops the distribution in progress. Members it didn't pay keep their settled and
+/// unsettled amounts, the booked funds stay outstanding until they claim.
+pub fn execute_abort_distribution(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+    ensure!(
+        DISTRIBUTION.exists(deps.storage),
+        ContractError::NoDistribution {}
+    );
+    DISTRIBUTION.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "abort_distribution"))
+}
+
+pub fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denoms: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let group = GROUP.load(deps.storage)?;
+    let total_weight = checked_total_weight(&group, deps.as_ref())?;
+    let weight = group
+        .is_member(&deps.querier, &info.sender, None)?
+        .unwrap_or_default();
+    checked_cw20_list(deps.as_ref(), cw20_list.as_deref())?;
+
+    let balances = query_balances(deps.as_ref(), &env, denoms, cw20_list)?;
+    book_deposits(deps.storage, &balances, total_weight)?;
+
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    for coin in balances {
+        let amount = pay_out(deps.storage, &info.sender, &coin.denom, weight)?;
+        if !amount.is_zero() {
+            msgs.push(transfer_msg(info.sender.as_str(), &coin.denom, amount)?);
+        }
+    }
+
+    ensure!(!msgs.is_empty(), ContractError::NothingToClaim {});
+
+    Ok(Response::new()
+        .add_attribute("action", "claim")
+        .add_attribute("member", info.sender.to_string())
+        .add_messages(msgs))
+}
+
+/// Books the attached funds, e.g. mint proceeds forwarded by a minter.
+/// Pays a page of members once a denom reaches the distribution threshold.
+/// The payment runs in a submessage whose failure is ignored, so it can't fail the deposit.
+pub fn execute_deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let funds = checked_funds(&info)?;
+
+    let res = Response::new()
+        .add_attribute("action", "deposit")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attributes(funds.iter().map(|coin| ("amount", coin.to_string())));
+
+    // Without weight the funds stay unbooked until the group has members again
+    let total_weight = GROUP.load(deps.storage)?.total_weight(&deps.querier)?;
+    if total_weight == 0 {
+        return Ok(res);
+    }
+    let denoms = funds.iter().map(|coin| coin.denom.clone()).collect();
+    let balances = query_balances(deps.as_ref(), &env, Some(denoms), None)?;
+    book_deposits(deps.storage, &balances, total_weight)?;
+
+    // Keep a distribution in progress going, or start one for the denoms over the threshold
+    let denom_list = match DISTRIBUTION.may_load(deps.storage)? {
+        Some(_) => None,
+        None => {
+            let mut denoms: Vec<String> = Vec::new();
+            for threshold in DISTRIBUTION_THRESHOLD
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+            {
+                let outstanding = OUTSTANDING
+                    .may_load(deps.storage, &threshold.denom)?
+                    .unwrap_or_default();
+                if outstanding >= threshold.amount {
+                    denoms.push(threshold.denom);
+                }
+            }
+            if denoms.is_empty() {
+                return Ok(res);
+            }
+            Some(denoms)
+        }
+    };
+
+    let distribute_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Distribute {
+            denom_list,
+            cw20_list: None,
+        })?,
+        funds: vec![],
+    };
+    Ok(res.add_submessage(SubMsg::reply_on_error(distribute_msg, DISTRIBUTE_REPLY_ID)))
+}
+
+pub fn execute_update_distribution_threshold(
+    deps: DepsMut,
+    info: MessageInfo,
+    threshold: Vec<Coin>,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+    checked_distribution_threshold(&threshold)?;
+    DISTRIBUTION_THRESHOLD.save(deps.storage, &threshold)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_distribution_threshold")
+        .add_attributes(threshold.iter().map(|coin| ("threshold", coin.to_string()))))
+}
+
+/// Accepts the allowed CW20 tokens sent to the contract, they are booked like native deposits
+pub fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    ensure!(!msg.amount.is_zero(), ContractError::NoFunds {});
+    ensure!(
+        CW20_TOKENS.has(deps.storage, &info.sender),
+        ContractError::Cw20NotAllowed {
+            token: info.sender.to_string()
+        }
+    );
+
+    Ok(Response::new()
+        .add_attribute("action", "receive")
+        .add_attribute("token", info.sender.to_string())
+        .add_attribute("sender", msg.sender)
+        .add_attribute("amount", msg.amount))
+}
+
+/// Holds NFTs sent to the contract until the admin routes them to a member
+pub fn execute_receive_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    ensure!(
+        ADMIN.get(deps.as_ref())?.is_some(),
+        ContractError::NftsWithoutAdmin {}
+    );
+    NFTS.save(deps.storage, (&info.sender, &msg.token_id), &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "receive_nft")
+        .add_attribute("collection", info.sender.to_string())
+        .add_attribute("sender", msg.sender)
+        .add_attribute("token_id", msg.token_id))
+}
+
+pub fn execute_transfer_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    collection: String,
+    token_id: String,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    let collection_addr = deps.api.addr_validate(&collection)?;
+    ensure!(
+        NFTS.has(deps.storage, (&collection_addr, &token_id)),
+        ContractError::NftNotHeld {
+            collection,
+            token_id
+        }
+    );
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    ensure!(
+        GROUP
+            .load(deps.storage)?
+            .is_member(&deps.querier, &recipient_addr, None)?
+            .is_some(),
+        ContractError::NotMember { addr: recipient }
+    );
+    NFTS.remove(deps.storage, (&collection_addr, &token_id));
+
+    let transfer_msg = WasmMsg::Execute {
+        contract_addr: collection_addr.to_string(),
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.clone(),
+            token_id: token_id.clone(),
+        })?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_attribute("action", "transfer_nft")
+        .add_attribute("collection", collection)
+        .add_attribute("token_id", token_id)
+        .add_attribute("recipient", recipient)
+        .add_message(transfer_msg))
+}
+
+pub fn execute_update_cw20_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    for token in add.iter() {
+        CW20_TOKENS.save(deps.storage, &deps.api.addr_validate(token)?, &Empty {})?;
+    }
+    for token in remove.iter() {
+        CW20_TOKENS.remove(deps.storage, &deps.api.addr_validate(token)?);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_cw20_tokens")
+        .add_attributes(add.into_iter().map(|token| ("add", token)))
+        .add_attributes(remove.into_iter().map(|token| ("remove", token))))
+}
+
+/// Settles the accounts of changed members at their previous weight,
+/// so deposits booked before the change are split with the old weights.
+pub fn execute_member_changed_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MemberChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let group = GROUP.load(deps.storage)?;
+    ensure!(group.addr() == info.sender, ContractError::Unauthorized {});
+
+    // The group already applied the change, recover the total weight before it
+    let (old_weights, new_weights) = msg.diffs.iter().fold((0u64, 0u64), |(old, new), diff| {
+        (
+            old + diff.old.unwrap_or_default(),
+            new + diff.new.unwrap_or_default(),
+        )
+    });
+    let old_total_weight = group.total_weight(&deps.querier)? + old_weights - new_weights;
+    if old_total_weight > 0 {
+        let balances = query_balances(deps.as_ref(), &env, None, None)?;
+        book_deposits(deps.storage, &balances, old_total_weight)?;
+    }
+
+    let indices = INDICES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for diff in msg.diffs.iter() {
+        let addr = deps.api.addr_validate(&diff.key)?;
+        for (denom, index) in indices.iter() {
+            let mut account = load_member_account(deps.storage, &addr, denom, *index)?;
+            account.settle(*index, diff.old.unwrap_or_default());
+            MEMBER_ACCOUNTS.save(deps.storage, (&addr, denom), &account)?;
+        }
+        TRACKED_MEMBERS.save(deps.storage, &addr, &Empty {})?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "member_changed_hook")
+        .add_attribute("members", msg.diffs.len().to_string()))
+}
+
+/// Key of a CW20 token in the per denom accounting
+pub fn cw20_denom(token: &Addr) -> String {
+    format!("{CW20_DENOM_PREFIX}{token}")
+}
+
+fn transfer_msg(to_address: &str, denom: &str, amount: Uint128) -> StdResult<CosmosMsg> {
+    match denom.strip_prefix(CW20_DENOM_PREFIX) {
+        Some(token) => Ok(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to_address.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+        None => Ok(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount: coins(amount.u128(), denom),
+        }
+        .into()),
+    }
+}
+
+/// Returns the non zero balances of the listed native denoms and CW20 tokens,
+/// or of all native denoms and accepted CW20 tokens when neither list is set.
+/// CW20 balances use the `cw20:<address>` denom, tokens that fail to answer are skipped.
+fn query_balances(
+    deps: Deps,
+    env: &Env,
+    denom_list: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
+) -> StdResult<Vec<Coin>> {
+    let (mut funds, tokens) = if denom_list.is_none() && cw20_list.is_none() {
+        (
+            deps.querier
+                .query_all_balances(env.contract.address.clone())?,
+            CW20_TOKENS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?,
+        )
+    } else {
+        let mut funds: Vec<Coin> = Vec::new();
+        for denom in denom_list.unwrap_or_default().iter() {
             let balance = deps
                 .querier
                 .query_balance(env.contract.address.clone(), denom)?;
@@ -108,38 +549,159 @@ pub fn execute_distribute(
             }
             funds.push(balance);
         }
-    } else {
-        funds = deps.querier.query_all_balances(env.contract.address)?;
+        let tokens = cw20_list
+            .unwrap_or_default()
+            .iter()
+            .map(|token| deps.api.addr_validate(token))
+            .collect::<StdResult<Vec<_>>>()?;
+        (funds, tokens)
+    };
+
+    for token in tokens {
+        match Cw20Contract(token.clone()).balance(&deps.querier, &env.contract.address) {
+            Ok(amount) if !amount.is_zero() => {
+                funds.push(Coin::new(amount.u128(), cw20_denom(&token)))
+            }
+            _ => continue,
+        }
     }
+    Ok(funds)
+}
 
-    ensure!(!funds.is_empty(), ContractError::NoFunds {});
+/// Returns the index of the denom once the unbooked balance is booked.
+/// To avoid rounding errors, deposits are booked modulo the total weight,
+/// the rest stays unbooked until more funds come in.
+fn booked_index(
+    storage: &dyn Storage,
+    balance: &Coin,
+    total_weight: u64,
+) -> StdResult<(Uint128, Uint128)> {
+    let index = INDICES
+        .may_load(storage, &balance.denom)?
+        .unwrap_or_default();
+    let outstanding = OUTSTANDING
+        .may_load(storage, &balance.denom)?
+        .unwrap_or_default();
+    let per_weight = balance.amount.saturating_sub(outstanding) / Uint128::from(total_weight);
+
+    Ok((
+        index + per_weight,
+        outstanding + per_weight * Uint128::from(total_weight),
+    ))
+}
 
-    let mut msgs: Vec<CosmosMsg> = Vec::new();
-    for member in members.iter().filter(|m| m.weight > 0) {
-        for coin in funds.iter() {
-            // To avoid rounding errors, distribute funds modulo the total weight.
-            // Keep remaining balance in the contract.
-            let multiplier = coin.amount / Uint128::from(total_weight);
-            if multiplier.is_zero() {
-                continue;
-            }
+fn book_deposits(storage: &mut dyn Storage, balances: &[Coin], total_weight: u64) -> StdResult<()> {
+    for balance in balances {
+        let (index, outstanding) = booked_index(storage, balance, total_weight)?;
+        INDICES.save(storage, &balance.denom, &index)?;
+        OUTSTANDING.save(storage, &balance.denom, &outstanding)?;
+    }
+    Ok(())
+}
+
+/// Settles the member account for the denom and returns the amount to pay out
+fn pay_out(storage: &mut dyn Storage, addr: &Addr, denom: &str, weight: u64) -> StdResult<Uint128> {
+    let index = INDICES.may_load(storage, denom)?.unwrap_or_default();
+    let mut account = load_member_account(storage, addr, denom, index)?;
+    account.settle(index, weight);
+
+    let amount = account.pending;
+    account.pending = Uint128::zero();
+    account.received += amount;
+    MEMBER_ACCOUNTS.save(storage, (addr, denom), &account)?;
+    if !amount.is_zero() {
+        OUTSTANDING.update(storage, denom, |outstanding| {
+            outstanding
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(StdError::overflow)
+        })?;
+    }
+
+    Ok(amount)
+}
 
-            let amount = Uint128::from(member.weight) * multiplier;
-            msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: member.addr.clone(),
-                amount: coins(amount.u128(), coin.denom.clone()),
-            }));
+/// Loads the account of the member for the denom.
+/// A member that joined without the hook is only owed what is booked from the given index on.
+fn load_member_account(
+    storage: &dyn Storage,
+    addr: &Addr,
+    denom: &str,
+    index: Uint128,
+) -> StdResult<MemberAccount> {
+    match MEMBER_ACCOUNTS.may_load(storage, (addr, denom))? {
+        Some(account) => Ok(account),
+        None if TRACKED_MEMBERS.has(storage, addr) => Ok(MemberAccount::default()),
+        None => Ok(MemberAccount::new(index)),
+    }
+}
+
+/// Tracks the members of the group from index 0, nothing is booked yet
+fn track_members(deps: DepsMut, group: &Cw4Contract) -> StdResult<()> {
+    let mut start_after = None;
+    loop {
+        let members = group.list_members(&deps.querier, start_after, Some(PAGINATION_LIMIT))?;
+        for member in members.iter() {
+            TRACKED_MEMBERS.save(deps.storage, &Addr::unchecked(&member.addr), &Empty {})?;
+        }
+        if members.len() < PAGINATION_LIMIT as usize {
+            return Ok(());
         }
+        start_after = members.last().map(|m| m.addr.clone());
     }
+}
 
-    ensure!(
-        !msgs.is_empty(),
-        ContractError::NotEnoughFunds { min: total_weight }
-    );
+/// Member changes are only accounted for through the hook, so a group with an admin must call it
+fn checked_member_changed_hook(
+    group: &Cw4Contract,
+    deps: Deps,
+    self_addr: &Addr,
+) -> Result<(), ContractError> {
+    if group.admin(&deps.querier)?.is_some() {
+        ensure!(
+            group.hooks(&deps.querier)?.contains(&self_addr.to_string()),
+            ContractError::MissingMemberChangedHook {
+                addr: group.addr().to_string()
+            }
+        );
+    }
+    Ok(())
+}
 
-    Ok(Response::new()
-        .add_attribute("action", "distribute")
-        .add_messages(msgs))
+/// Listed CW20 tokens must be accepted, like tokens sent through `Receive`
+fn checked_cw20_list(deps: Deps, cw20_list: Option<&[String]>) -> Result<(), ContractError> {
+    for token in cw20_list.unwrap_or_default() {
+        ensure!(
+            CW20_TOKENS.has(deps.storage, &deps.api.addr_validate(token)?),
+            ContractError::Cw20NotAllowed {
+                token: token.clone()
+            }
+        );
+    }
+    Ok(())
+}
+
+fn checked_funds(info: &MessageInfo) -> Result<Vec<Coin>, ContractError> {
+    let funds: Vec<Coin> = info
+        .funds
+        .iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .cloned()
+        .collect();
+    ensure!(!funds.is_empty(), ContractError::NoFunds {});
+    Ok(funds)
+}
+
+fn checked_distribution_threshold(threshold: &[Coin]) -> Result<(), ContractError> {
+    for (i, coin) in threshold.iter().enumerate() {
+        ensure!(
+            !coin.amount.is_zero() && !threshold[..i].iter().any(|c| c.denom == coin.denom),
+            ContractError::InvalidDistributionThreshold {
+                denom: coin.denom.clone()
+            }
+        );
+    }
+    Ok(())
 }
 
 fn checked_total_weight(group: &Cw4Contract, deps: Deps) -> Result<u64, ContractError> {
@@ -151,19 +713,21 @@ fn checked_total_weight(group: &Cw4Contract, deps: Deps) -> Result<u64, Contract
     Ok(weight)
 }
 
-fn checked_total_members(group: &Cw4Contract, deps: Deps) -> Result<u64, ContractError> {
-    let members = group
-        .list_members(&deps.querier, None, Some(PAGINATION_LIMIT))?
-        .len();
-    if members == 0 || members > MAX_GROUP_SIZE as usize {
+fn checked_total_members(group: &Cw4Contract, deps: Deps) -> Result<(), ContractError> {
+    let members = group.list_members(&deps.querier, None, Some(1))?.len();
+    if members == 0 {
         return Err(ContractError::InvalidMemberCount { count: members });
     }
 
-    Ok(members as u64)
+    Ok(())
 }
 
-/// Checks if the sender is an admin or a member of a group.
-fn can_distribute(deps: Deps, info: MessageInfo) -> StdResult<bool> {
+/// Checks if the sender is the contract itself, an admin or a member of a group.
+fn can_distribute(deps: Deps, env: &Env, info: MessageInfo) -> StdResult<bool> {
+    if info.sender == env.contract.address {
+        return Ok(true);
+    }
+
     match ADMIN.get(deps)? {
         Some(admin) => Ok(admin == info.sender),
         None => Ok(GROUP
@@ -174,7 +738,7 @@ fn can_distribute(deps: Deps, info: MessageInfo) -> StdResult<bool> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Admin {} => to_json_binary(&ADMIN.query_admin(deps)?),
         QueryMsg::Group {} => to_json_binary(&query_group(deps)?),
@@ -182,6 +746,18 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_json_binary(&list_members(deps, start_after, limit)?)
         }
         QueryMsg::Member { address } => to_json_binary(&query_member(deps, address)?),
+        QueryMsg::Distribution {} => to_json_binary(&query_distribution(deps)?),
+        QueryMsg::Claimable {
+            address,
+            denoms,
+            cw20_list,
+        } => to_json_binary(&query_claimable(deps, env, address, denoms, cw20_list)?),
+        QueryMsg::Received { address } => to_json_binary(&query_received(deps, address)?),
+        QueryMsg::SplitsInfo {} => to_json_binary(&query_splits_info(deps)?),
+        QueryMsg::Cw20Tokens {} => to_json_binary(&query_cw20_tokens(deps)?),
+        QueryMsg::Nfts { start_after, limit } => {
+            to_json_binary(&query_nfts(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -189,6 +765,108 @@ fn query_group(deps: Deps) -> StdResult<Addr> {
     Ok(GROUP.load(deps.storage)?.addr())
 }
 
+fn query_splits_info(deps: Deps) -> StdResult<SplitsInfoResponse> {
+    Ok(SplitsInfoResponse {
+        group: GROUP.load(deps.storage)?.addr(),
+        distribution_threshold: DISTRIBUTION_THRESHOLD
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+fn query_cw20_tokens(deps: Deps) -> StdResult<Cw20TokensResponse> {
+    Ok(Cw20TokensResponse {
+        tokens: CW20_TOKENS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?,
+    })
+}
+
+fn query_nfts(
+    deps: Deps,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<NftsResponse> {
+    let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+    let start_after = match start_after {
+        Some((collection, token_id)) => Some((deps.api.addr_validate(&collection)?, token_id)),
+        None => None,
+    };
+    let start = start_after
+        .as_ref()
+        .map(|(collection, token_id)| Bound::exclusive((collection, token_id.as_str())));
+    let nfts = NFTS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(collection, token_id)| Nft {
+                collection,
+                token_id,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(NftsResponse { nfts })
+}
+
+fn query_distribution(deps: Deps) -> StdResult<DistributionResponse> {
+    Ok(DistributionResponse {
+        distribution: DISTRIBUTION.may_load(deps.storage)?,
+    })
+}
+
+fn query_claimable(
+    deps: Deps,
+    env: Env,
+    address: String,
+    denoms: Option<Vec<String>>,
+    cw20_list: Option<Vec<String>>,
+) -> StdResult<ClaimableResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    let group = GROUP.load(deps.storage)?;
+    let total_weight = group.total_weight(&deps.querier)?;
+    let weight = group
+        .is_member(&deps.querier, &addr, None)?
+        .unwrap_or_default();
+
+    checked_cw20_list(deps, cw20_list.as_deref())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let mut claimable: Vec<Coin> = Vec::new();
+    for balance in query_balances(deps, &env, denoms, cw20_list)? {
+        // Include the deposits the next claim would book
+        let index = match total_weight {
+            0 => INDICES
+                .may_load(deps.storage, &balance.denom)?
+                .unwrap_or_default(),
+            _ => booked_index(deps.storage, &balance, total_weight)?.0,
+        };
+        let mut account = load_member_account(deps.storage, &addr, &balance.denom, index)?;
+        account.settle(index, weight);
+        if !account.pending.is_zero() {
+            claimable.push(Coin::new(account.pending.u128(), balance.denom));
+        }
+    }
+
+    Ok(ClaimableResponse { claimable })
+}
+
+fn query_received(deps: Deps, address: String) -> StdResult<ReceivedResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    let received = MEMBER_ACCOUNTS
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((denom, account)) if !account.received.is_zero() => {
+                Some(Ok(Coin::new(account.received.u128(), denom)))
+            }
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReceivedResponse { received })
+}
+
 fn query_member(deps: Deps, member: String) -> StdResult<MemberResponse> {
     let group = GROUP.load(deps.storage)?;
     let voter_addr = deps.api.addr_validate(&member)?;
@@ -215,7 +893,11 @@ fn list_members(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // A failed distribution on deposit is retried by the next deposit or `Distribute`
+    if msg.id == DISTRIBUTE_REPLY_ID {
+        return Ok(Response::default().add_attribute("action", "distribute_on_deposit_failed"));
+    }
     if msg.id != INIT_GROUP_REPLY_ID {
         return Err(ContractError::InvalidReplyID {});
     }
@@ -229,7 +911,10 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                         addr: res.contract_address.clone(),
                     }
                 })?);
+            // The new group can't call the splits contract yet, so it can't have an admin
+            checked_member_changed_hook(&group, deps.as_ref(), &env.contract.address)?;
 
+            track_members(deps.branch(), &group)?;
             GROUP.save(deps.storage, &group)?;
 
             Ok(Response::default().add_attribute("action", "reply_on_success"))
@@ -239,7 +924,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
     let current_version = cw2::get_contract_version(deps.storage)?;
     if current_version.contract != CONTRACT_NAME {
         return Err(StdError::generic_err("Cannot upgrade to a different contract").into());
@@ -260,6 +945,13 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Ok(Response::new());
     }
 
+    // Versions before per member accounting booked nothing, so the current members
+    // are owed their share of the whole balance like members tracked since instantiation
+    if INDICES.is_empty(deps.storage) {
+        let group = GROUP.load(deps.storage)?;
+        track_members(deps.branch(), &group)?;
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::new())
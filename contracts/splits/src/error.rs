@@ -19,9 +19,36 @@ pub enum ContractError {
     #[error("Contract has not enough funds. Minimum required: `{min}`")]
     NotEnoughFunds { min: u64 },
 
+    #[error("Invalid distribution threshold for `{denom}`")]
+    InvalidDistributionThreshold { denom: String },
+
+    #[error("CW20 token `{token}` is not accepted")]
+    Cw20NotAllowed { token: String },
+
+    #[error("No distribution in progress")]
+    NoDistribution {},
+
+    #[error("NFTs are only accepted by splits with an admin to route them")]
+    NftsWithoutAdmin {},
+
+    #[error("NFT `{token_id}` of `{collection}` is not held")]
+    NftNotHeld {
+        collection: String,
+        token_id: String,
+    },
+
+    #[error("`{addr}` is not a member of the group")]
+    NotMember { addr: String },
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+
     #[error("Group contract invalid address `{addr}`")]
     InvalidGroup { addr: String },
 
+    #[error("Group contract `{addr}` has an admin but does not call the splits contract on member changes")]
+    MissingMemberChangedHook { addr: String },
+
     #[error("Group contract invalid total weight `{weight}`")]
     InvalidWeight { weight: u64 },
 
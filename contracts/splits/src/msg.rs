@@ -1,7 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin};
+use cw20::Cw20ReceiveMsg;
+use cw4::MemberChangedHookMsg;
+use cw721::Cw721ReceiveMsg;
 use sg_controllers::ContractInstantiateMsg;
 
+use crate::state::Distribution;
+
 #[cw_serde]
 pub enum Group {
     Cw4Instantiate(ContractInstantiateMsg),
@@ -12,12 +17,66 @@ pub enum Group {
 pub struct InstantiateMsg {
     pub admin: Option<String>,
     pub group: Group,
+    /// Booked amount per denom at which a `Deposit` also pays a page of members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution_threshold: Option<Vec<Coin>>,
+    /// CW20 tokens the contract accepts through `Receive`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cw20_tokens: Option<Vec<String>>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    UpdateAdmin { admin: Option<String> },
-    Distribute { denom_list: Option<Vec<String>> },
+    UpdateAdmin {
+        admin: Option<String>,
+    },
+    /// Pays the next page of members what they are owed, booking new deposits
+    /// when a new distribution starts. Call repeatedly until the response
+    /// `complete` attribute is `true`.
+    /// Distributes all native denoms and accepted CW20 tokens when neither list is set.
+    Distribute {
+        denom_list: Option<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cw20_list: Option<Vec<String>>,
+    },
+    /// Admin drops the distribution in progress, so the next `Distribute` starts a new one.
+    /// Members it didn't pay keep what they are owed and can still `Claim` it.
+    AbortDistribution {},
+    /// Books new deposits and pays the sender what it is owed,
+    /// in all native denoms and accepted CW20 tokens when neither list is set
+    Claim {
+        denoms: Option<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cw20_list: Option<Vec<String>>,
+    },
+    /// CW20 tokens sent to the contract are split like native coins.
+    /// Only tokens allowed by the admin are accepted.
+    Receive(Cw20ReceiveMsg),
+    /// NFTs sent to the contract are held until the admin routes them to a member,
+    /// contracts without an admin reject them
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Admin sends a held NFT to a member of the group
+    TransferNft {
+        collection: String,
+        token_id: String,
+        recipient: String,
+    },
+    /// Admin updates the CW20 tokens accepted through `Receive`
+    UpdateCw20Tokens {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Books the attached funds, e.g. mint proceeds forwarded by a minter.
+    /// Pays a page of members when a denom reaches the distribution threshold,
+    /// a failed payment does not fail the deposit.
+    Deposit {},
+    /// Admin sets the booked amounts per denom that trigger a distribution on deposit
+    UpdateDistributionThreshold {
+        threshold: Vec<Coin>,
+    },
+    /// Called by the group when members change. Register the splits contract as a hook
+    /// of the group so weight changes only apply to deposits booked after them.
+    MemberChangedHook(MemberChangedHookMsg),
 }
 
 #[cw_serde]
@@ -37,4 +96,68 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+
+    /// The distribution in progress, if any
+    #[returns(DistributionResponse)]
+    Distribution {},
+
+    /// Amount the address can claim, including deposits not booked yet.
+    /// CW20 amounts use the `cw20:<address>` denom.
+    #[returns(ClaimableResponse)]
+    Claimable {
+        address: String,
+        denoms: Option<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cw20_list: Option<Vec<String>>,
+    },
+
+    /// Answered so payers like minters can tell the splits contract apart and `Deposit` into it
+    #[returns(sg1::SplitsInfoResponse)]
+    SplitsInfo {},
+
+    /// Amount paid out to the address since instantiation
+    #[returns(ReceivedResponse)]
+    Received { address: String },
+
+    /// CW20 tokens accepted through `Receive`
+    #[returns(Cw20TokensResponse)]
+    Cw20Tokens {},
+
+    /// NFTs held until the admin routes them to a member
+    #[returns(NftsResponse)]
+    Nfts {
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct DistributionResponse {
+    pub distribution: Option<Distribution>,
+}
+
+#[cw_serde]
+pub struct ClaimableResponse {
+    pub claimable: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct ReceivedResponse {
+    pub received: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct Cw20TokensResponse {
+    pub tokens: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct Nft {
+    pub collection: Addr,
+    pub token_id: String,
+}
+
+#[cw_serde]
+pub struct NftsResponse {
+    pub nfts: Vec<Nft>,
 }
@@ -1,8 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Empty, Uint128};
 use cw4::Cw4Contract;
 
 use cw_controllers::Admin;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 pub const GROUP: Item<Cw4Contract> = Item::new("group");
 
 pub const ADMIN: Admin = Admin::new("admin");
+
+/// A distribution processed in pages of members across `Distribute` calls
+#[cw_serde]
+pub struct Distribution {
+    /// Snapshot of the amount owed to members per denom when the distribution started
+    pub funds: Vec<Coin>,
+    /// Amount sent to members so far per denom
+    pub distributed: Vec<Coin>,
+    /// Group total weight when the distribution started
+    pub total_weight: u64,
+    /// Last member paid, the next page starts after it
+    pub cursor: Option<String>,
+}
+
+pub const DISTRIBUTION: Item<Distribution> = Item::new("distribution");
+
+/// Booked amount per denom at which a deposit pays a page of members
+pub const DISTRIBUTION_THRESHOLD: Item<Vec<Coin>> = Item::new("distribution_threshold");
+
+/// Amount booked per unit of weight since instantiation, per denom
+pub const INDICES: Map<&str, Uint128> = Map::new("indices");
+
+/// Amount booked but not paid out yet, per denom.
+/// The contract balance above it has not been booked.
+pub const OUTSTANDING: Map<&str, Uint128> = Map::new("outstanding");
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MemberAccount {
+    /// Denom index when the account was last settled
+    pub index: Uint128,
+    /// Settled amount that can be claimed
+    pub pending: Uint128,
+    /// Amount paid out to the member since instantiation
+    pub received: Uint128,
+}
+
+impl MemberAccount {
+    /// Account of a member settled at the given index
+    pub fn new(index: Uint128) -> Self {
+        MemberAccount {
+            index,
+            ..MemberAccount::default()
+        }
+    }
+
+    /// Credits the amount booked since the last settlement at the given weight
+    pub fn settle(&mut self, index: Uint128, weight: u64) {
+        self.pending += (index - self.index) * Uint128::from(weight);
+        self.index = index;
+    }
+}
+
+/// Members tracked since they joined the group, at instantiation or through the member changed hook.
+/// Their accounts for a denom booked after they joined start at index 0,
+/// the accounts of other members start at the index of their first settlement.
+pub const TRACKED_MEMBERS: Map<&Addr, Empty> = Map::new("tracked_members");
+
+pub const MEMBER_ACCOUNTS: Map<(&Addr, &str), MemberAccount> = Map::new("member_accounts");
+
+/// CW20 tokens accepted through `Receive`, distributed when no list is given
+pub const CW20_TOKENS: Map<&Addr, Empty> = Map::new("cw20_tokens");
+
+/// NFTs held until the admin routes them to a member, by collection and token id
+pub const NFTS: Map<(&Addr, &str), Empty> = Map::new("nfts");
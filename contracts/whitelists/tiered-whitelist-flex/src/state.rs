@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Timestamp};
 use cw_storage_plus::{Item, Map};
+use sg1::FeeRouting;
 
 #[cw_serde]
 pub struct Stage {
@@ -13,7 +14,6 @@ pub struct Stage {
 
 #[cw_serde]
 pub struct Config {
-    pub stages: Vec<Stage>,
     pub num_members: u32,
     pub member_limit: u32,
     pub whale_cap: Option<u32>,
@@ -39,6 +39,9 @@ impl AdminList {
 pub const ADMIN_LIST: Item<AdminList> = Item::new("admin_list");
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const FEE_ROUTING: Item<FeeRouting> = Item::new("fee_routing");
+
+pub const STAGES: Map<u32, Stage> = Map::new("stages");
 
 pub const WHITELIST_STAGES: Map<(u32, Addr), u32> = Map::new("wl_stages");
 
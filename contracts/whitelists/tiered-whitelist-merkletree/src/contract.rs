@@ -4,7 +4,8 @@ use crate::admin::{
 use crate::error::ContractError;
 use crate::helpers::crypto::{string_to_byte_slice, valid_hash_string, verify_merkle_root};
 use crate::helpers::utils::{
-    fetch_active_stage, fetch_active_stage_index, validate_stages, validate_update, verify_tree_uri,
+    fetch_active_stage, fetch_active_stage_index, first_stage, last_stage, load_stages,
+    validate_stages, validate_update, verify_tree_uri,
 };
 use crate::helpers::validators::map_validate;
 use crate::msg::{
@@ -12,19 +13,22 @@ use crate::msg::{
     InstantiateMsg, IsActiveResponse, MerkleRootResponse, MerkleTreeURIResponse, QueryMsg,
     StageResponse, StagesResponse, UpdateStageConfigMsg,
 };
-use crate::state::{AdminList, Config, Stage, ADMIN_LIST, CONFIG, MERKLE_ROOTS, MERKLE_TREE_URIS};
+use crate::state::{
+    AdminList, Stage, ADMIN_LIST, FEE_ROUTING, LEGACY_CONFIG, MERKLE_ROOTS, MERKLE_TREE_URIS,
+    STAGES,
+};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_binary, Binary, Coin, Deps, DepsMut, Empty, Env, Event, MessageInfo, Response,
-    StdError, StdResult, Timestamp, Uint128,
+    ensure, to_json_binary, Binary, Coin, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    Response, StdError, StdResult, Timestamp, Uint128,
 };
 use cw2::set_contract_version;
 use cw_utils::must_pay;
 use sg_utils::NATIVE_DENOM;
 
 use semver::Version;
-use sg1::checked_fair_burn;
+use sg1::{checked_fair_burn, query_factory_fee_routing, FeeRouting};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:tiered-whitelist-merkletree";
@@ -62,10 +66,14 @@ pub fn instantiate(
 
     validate_stages(&env, &msg.stages)?;
 
-    let mut res = Response::new();
-    checked_fair_burn(&info, &env, CREATION_FEE, None, &mut res)?;
+    let fee_routing = match &msg.factory {
+        Some(factory) => query_factory_fee_routing(&deps.querier, factory)?,
+        None => FeeRouting::default(),
+    };
+    FEE_ROUTING.save(deps.storage, &fee_routing)?;
 
-    let config = Config { stages: msg.stages };
+    let mut res = Response::new();
+    checked_fair_burn(&info, &env, CREATION_FEE, None, &mut res, &fee_routing)?;
 
     let admin_config = AdminList {
         admins: map_validate(deps.api, &msg.admins)?,
@@ -74,7 +82,9 @@ pub fn instantiate(
 
     MERKLE_ROOTS.save(deps.storage, &msg.merkle_roots)?;
     ADMIN_LIST.save(deps.storage, &admin_config)?;
-    CONFIG.save(deps.storage, &config)?;
+    for (stage_id, stage) in msg.stages.iter().enumerate() {
+        STAGES.save(deps.storage, stage_id as u32, stage)?;
+    }
 
     let tree_uris = msg.merkle_tree_uris.unwrap_or_default();
     if !tree_uris.is_empty() {
@@ -119,7 +129,7 @@ pub fn execute_update_merkle_tree(
     merkle_tree_uris: Option<Vec<String>>,
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
-    let config = CONFIG.load(deps.storage)?;
+    let stages = load_stages(deps.storage)?;
 
     for merkle_root in merkle_roots.iter() {
         verify_merkle_root(merkle_root)?;
@@ -132,10 +142,7 @@ pub fn execute_update_merkle_tree(
     }
 
     ensure!(
-        config
-            .stages
-            .iter()
-            .all(|stage| stage.end_time <= env.block.time),
+        stages.iter().all(|stage| stage.end_time <= env.block.time),
         ContractError::AlreadyEnded {}
     );
 
@@ -165,29 +172,24 @@ pub fn execute_update_stage_config(
     msg: UpdateStageConfigMsg,
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
-    let mut config = CONFIG.load(deps.storage)?;
-    let stage_id = msg.stage_id as usize;
+    let stage_id = msg.stage_id;
+    let stage = STAGES
+        .may_load(deps.storage, stage_id)?
+        .ok_or(ContractError::StageNotFound {})?;
     let updated_stage = Stage {
-        name: msg.name.unwrap_or(config.stages[stage_id].clone().name),
-        start_time: msg
-            .start_time
-            .unwrap_or(config.stages[stage_id].clone().start_time),
-        end_time: msg
-            .end_time
-            .unwrap_or(config.stages[stage_id].clone().end_time),
-        mint_price: msg
-            .mint_price
-            .unwrap_or(config.stages[stage_id].clone().mint_price),
+        name: msg.name.unwrap_or(stage.clone().name),
+        start_time: msg.start_time.unwrap_or(stage.clone().start_time),
+        end_time: msg.end_time.unwrap_or(stage.clone().end_time),
+        mint_price: msg.mint_price.unwrap_or(stage.clone().mint_price),
         per_address_limit: msg
             .per_address_limit
-            .unwrap_or(config.stages[stage_id].clone().per_address_limit),
+            .unwrap_or(stage.clone().per_address_limit),
         mint_count_limit: msg
             .mint_count_limit
-            .unwrap_or(config.stages[stage_id].clone().mint_count_limit),
+            .unwrap_or(stage.clone().mint_count_limit),
     };
-    config.stages[stage_id] = updated_stage.clone();
-    validate_update(&env, &config.stages)?;
-    CONFIG.save(deps.storage, &config)?;
+    STAGES.save(deps.storage, stage_id, &updated_stage)?;
+    validate_update(&env, &load_stages(deps.storage)?)?;
 
     Ok(Response::new()
         .add_attribute("action", "update_stage_config")
@@ -228,17 +230,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 fn query_has_started(deps: Deps, env: Env) -> StdResult<HasStartedResponse> {
-    let config = CONFIG.load(deps.storage)?;
+    let stages = load_stages(deps.storage)?;
     Ok(HasStartedResponse {
-        has_started: !config.stages.is_empty() && (env.block.time >= config.stages[0].start_time),
+        has_started: first_stage(&stages).is_some_and(|stage| env.block.time >= stage.start_time),
     })
 }
 
 fn query_has_ended(deps: Deps, env: Env) -> StdResult<HasEndedResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    let stage_count = config.stages.len();
+    let stages = load_stages(deps.storage)?;
     Ok(HasEndedResponse {
-        has_ended: (stage_count > 0) && (env.block.time >= config.stages[stage_count - 1].end_time),
+        has_ended: last_stage(&stages).is_some_and(|stage| env.block.time >= stage.end_time),
     })
 }
 
@@ -292,7 +293,7 @@ pub fn query_has_member(
 }
 
 pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
-    let config = CONFIG.load(deps.storage)?;
+    let stages = load_stages(deps.storage)?;
     let active_stage = fetch_active_stage(deps.storage, &env);
     if let Some(stage) = active_stage {
         Ok(ConfigResponse {
@@ -304,11 +305,11 @@ pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
             mint_price: stage.mint_price,
             is_active: true,
         })
-    } else if !config.stages.is_empty() {
-        let stage = if env.block.time < config.stages[0].start_time {
-            config.stages[0].clone()
+    } else if let (Some(first), Some(last)) = (first_stage(&stages), last_stage(&stages)) {
+        let stage = if env.block.time < first.start_time {
+            first.clone()
         } else {
-            config.stages[config.stages.len() - 1].clone()
+            last.clone()
         };
         Ok(ConfigResponse {
             num_members: 0,
@@ -348,36 +349,31 @@ pub fn query_merkle_tree_uris(deps: Deps) -> StdResult<MerkleTreeURIResponse> {
 }
 
 pub fn query_stage(deps: Deps, stage_id: u32) -> StdResult<StageResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    ensure!(
-        stage_id < config.stages.len() as u32,
-        StdError::generic_err("Stage not found")
-    );
+    let stage = STAGES
+        .may_load(deps.storage, stage_id)?
+        .ok_or_else(|| StdError::generic_err("Stage not found"))?;
     let merkle_root = MERKLE_ROOTS.load(deps.storage)?[stage_id as usize].clone();
     Ok(StageResponse {
         stage_id,
-        stage: config.stages[stage_id as usize].clone(),
+        stage,
         merkle_root,
     })
 }
 
 pub fn query_stage_list(deps: Deps) -> StdResult<StagesResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    ensure!(
-        !config.stages.is_empty(),
-        StdError::generic_err("No stages found")
-    );
     let merkle_roots = MERKLE_ROOTS.load(deps.storage)?;
-    let stages = config
-        .stages
-        .iter()
-        .enumerate()
-        .map(|(i, stage)| StageResponse {
-            stage_id: i as u32,
-            stage: stage.clone(),
-            merkle_root: merkle_roots[i].clone(),
+    let stages = STAGES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (stage_id, stage) = item?;
+            Ok(StageResponse {
+                stage_id,
+                stage,
+                merkle_root: merkle_roots[stage_id as usize].clone(),
+            })
         })
-        .collect();
+        .collect::<StdResult<Vec<_>>>()?;
+    ensure!(!stages.is_empty(), StdError::generic_err("No stages found"));
     Ok(StagesResponse { stages })
 }
 
@@ -403,6 +399,14 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Ok(Response::new());
     }
 
+    // stages are keyed by stage id
+    if let Some(legacy_config) = LEGACY_CONFIG.may_load(deps.storage)? {
+        for (stage_id, stage) in legacy_config.stages.iter().enumerate() {
+            STAGES.save(deps.storage, stage_id as u32, stage)?;
+        }
+        LEGACY_CONFIG.remove(deps.storage);
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let event = Event::new("migrate")
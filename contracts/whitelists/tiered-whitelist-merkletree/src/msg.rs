@@ -15,6 +15,9 @@ pub struct InstantiateMsg {
     pub merkle_tree_uris: Option<Vec<String>>,
     pub admins: Vec<String>,
     pub admins_mutable: bool,
+    /// Factory whose governance fee routing is used for the creation fee
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<String>,
 }
 
 #[cw_serde]
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Timestamp};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
+use sg1::FeeRouting;
 
 #[cw_serde]
 pub struct Stage {
@@ -11,8 +12,10 @@ pub struct Stage {
     pub per_address_limit: u32,
     pub mint_count_limit: Option<u32>,
 }
+
+/// Config from before stages were keyed by stage id
 #[cw_serde]
-pub struct Config {
+pub struct LegacyConfig {
     pub stages: Vec<Stage>,
 }
 
@@ -34,6 +37,8 @@ impl AdminList {
 }
 
 pub const ADMIN_LIST: Item<AdminList> = Item::new("admin_list");
-pub const CONFIG: Item<Config> = Item::new("config");
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+pub const FEE_ROUTING: Item<FeeRouting> = Item::new("fee_routing");
+pub const STAGES: Map<u32, Stage> = Map::new("stages");
 pub const MERKLE_ROOTS: Item<Vec<String>> = Item::new("merkle_roots");
 pub const MERKLE_TREE_URIS: Item<Vec<String>> = Item::new("merkle_tree_uris");
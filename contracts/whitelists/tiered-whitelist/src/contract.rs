@@ -4,7 +4,8 @@ use crate::admin::{
 use crate::error::ContractError;
 use crate::helpers::validators::map_validate;
 use crate::helpers::{
-    fetch_active_stage, fetch_active_stage_index, validate_stages, validate_update,
+    fetch_active_stage, fetch_active_stage_index, first_stage, last_stage, load_stages,
+    next_stage_id, validate_stages, validate_update,
 };
 use crate::msg::{
     AddMembersMsg, AllStageMemberInfoResponse, ConfigResponse, ExecuteMsg, HasEndedResponse,
@@ -12,7 +13,10 @@ use crate::msg::{
     QueryMsg, RemoveMembersMsg, StageMemberInfoResponse, StageResponse, StagesResponse,
     UpdateStageConfigMsg,
 };
-use crate::state::{AdminList, Config, Stage, ADMIN_LIST, CONFIG, MEMBER_COUNT, WHITELIST_STAGES};
+use crate::state::{
+    AdminList, Config, Stage, ADMIN_LIST, CONFIG, FEE_ROUTING, MEMBER_COUNT, STAGES,
+    WHITELIST_STAGES,
+};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -25,7 +29,7 @@ use cw_storage_plus::Bound;
 use cw_utils::{may_pay, maybe_addr, must_pay};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use sg1::checked_fair_burn;
+use sg1::{checked_fair_burn, query_factory_fee_routing, FeeRouting};
 use sg_utils::NATIVE_DENOM;
 
 // version info for migration info
@@ -81,7 +85,6 @@ pub fn instantiate(
     msg.members.iter_mut().for_each(|members| members.dedup());
 
     let config = Config {
-        stages: msg.stages.clone(),
         num_members: msg.members.iter().map(|m| m.len() as u32).sum(),
         member_limit: msg.member_limit,
     };
@@ -93,8 +96,14 @@ pub fn instantiate(
     };
     ADMIN_LIST.save(deps.storage, &admin_config)?;
 
+    let fee_routing = match &msg.factory {
+        Some(factory) => query_factory_fee_routing(&deps.querier, factory)?,
+        None => FeeRouting::default(),
+    };
+    FEE_ROUTING.save(deps.storage, &fee_routing)?;
+
     let mut res = Response::new();
-    checked_fair_burn(&info, &env, creation_fee, None, &mut res)?;
+    checked_fair_burn(&info, &env, creation_fee, None, &mut res, &fee_routing)?;
 
     if config.member_limit < config.num_members {
         return Err(ContractError::MembersExceeded {
@@ -103,7 +112,8 @@ pub fn instantiate(
         });
     }
 
-    for stage in 0..msg.stages.clone().len() {
+    for (stage, stage_config) in msg.stages.iter().enumerate() {
+        STAGES.save(deps.storage, stage as u32, stage_config)?;
         MEMBER_COUNT.save(
             deps.storage,
             stage as u32,
@@ -150,29 +160,24 @@ pub fn execute_update_stage_config(
     msg: UpdateStageConfigMsg,
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
-    let mut config = CONFIG.load(deps.storage)?;
-    let stage_id = msg.stage_id as usize;
+    let stage_id = msg.stage_id;
+    let stage = STAGES
+        .may_load(deps.storage, stage_id)?
+        .ok_or(ContractError::StageNotFound {})?;
     let updated_stage = Stage {
-        name: msg.name.unwrap_or(config.stages[stage_id].clone().name),
-        start_time: msg
-            .start_time
-            .unwrap_or(config.stages[stage_id].clone().start_time),
-        end_time: msg
-            .end_time
-            .unwrap_or(config.stages[stage_id].clone().end_time),
-        mint_price: msg
-            .mint_price
-            .unwrap_or(config.stages[stage_id].clone().mint_price),
+        name: msg.name.unwrap_or(stage.clone().name),
+        start_time: msg.start_time.unwrap_or(stage.clone().start_time),
+        end_time: msg.end_time.unwrap_or(stage.clone().end_time),
+        mint_price: msg.mint_price.unwrap_or(stage.clone().mint_price),
         per_address_limit: msg
             .per_address_limit
-            .unwrap_or(config.stages[stage_id].clone().per_address_limit),
+            .unwrap_or(stage.clone().per_address_limit),
         mint_count_limit: msg
             .mint_count_limit
-            .unwrap_or(config.stages[stage_id].clone().mint_count_limit),
+            .unwrap_or(stage.clone().mint_count_limit),
     };
-    config.stages[stage_id] = updated_stage.clone();
-    validate_update(&env, &config.stages)?;
-    CONFIG.save(deps.storage, &config)?;
+    STAGES.save(deps.storage, stage_id, &updated_stage)?;
+    validate_update(&env, &load_stages(deps.storage)?)?;
 
     Ok(Response::new()
         .add_attribute("action", "update_stage_config")
@@ -197,7 +202,7 @@ pub fn execute_add_members(
     can_execute(&deps, info.sender.clone())?;
     let mut config = CONFIG.load(deps.storage)?;
     ensure!(
-        msg.stage_id < config.stages.len() as u32,
+        STAGES.has(deps.storage, msg.stage_id),
         ContractError::StageNotFound {}
     );
     // remove duplicate members
@@ -241,13 +246,12 @@ pub fn execute_remove_members(
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
     let mut config = CONFIG.load(deps.storage)?;
-    ensure!(
-        msg.stage_id < config.stages.len() as u32,
-        ContractError::StageNotFound {}
-    );
+    let stage = STAGES
+        .may_load(deps.storage, msg.stage_id)?
+        .ok_or(ContractError::StageNotFound {})?;
 
     ensure!(
-        env.block.time < config.stages[msg.stage_id as usize].start_time,
+        env.block.time < stage.start_time,
         ContractError::AlreadyStarted {}
     );
 
@@ -280,13 +284,11 @@ pub fn execute_add_stage(
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
     let mut config = CONFIG.load(deps.storage)?;
-    ensure!(
-        config.stages.len().lt(&3),
-        ContractError::MaxStageCountExceeded {}
-    );
-    config.stages.push(msg.clone());
-    validate_stages(&env, &config.stages)?;
-    let stage_id = config.stages.len().saturating_sub(1) as u32;
+    let mut stages = load_stages(deps.storage)?;
+    stages.push(msg.clone());
+    validate_stages(&env, &stages)?;
+    let stage_id = next_stage_id(deps.storage)?;
+    STAGES.save(deps.storage, stage_id, &msg)?;
 
     // remove duplicate members
     members.sort_unstable();
@@ -310,7 +312,8 @@ pub fn execute_add_stage(
     CONFIG.save(deps.storage, &config)?;
     Ok(Response::new()
         .add_attribute("action", "add_stage")
-        .add_attribute("stage_count", config.stages.len().to_string())
+        .add_attribute("stage_id", stage_id.to_string())
+        .add_attribute("stage_count", stages.len().to_string())
         .add_attribute("sender", info.sender))
 }
 
@@ -322,37 +325,38 @@ pub fn execute_remove_stage(
 ) -> Result<Response, ContractError> {
     can_execute(&deps, info.sender.clone())?;
     let mut config = CONFIG.load(deps.storage)?;
-    ensure!(
-        config.stages.len().gt(&(stage_id as usize)),
-        ContractError::StageNotFound {}
-    );
+    let stage = STAGES
+        .may_load(deps.storage, stage_id)?
+        .ok_or(ContractError::StageNotFound {})?;
 
     ensure!(
-        env.block.time < config.stages[stage_id as usize].start_time,
+        env.block.time < stage.start_time,
         ContractError::AlreadyStarted {}
     );
 
-    // remove members from the WHITELIST_STAGES for stage_id and following stages. Reduce the num_members count
-    for stage in stage_id..config.stages.len() as u32 {
-        let members = WHITELIST_STAGES
-            .prefix(stage)
-            .range(deps.storage, None, None, Order::Ascending)
-            .map(|addr| addr.unwrap().0)
-            .collect::<Vec<Addr>>();
-        for member in members {
-            WHITELIST_STAGES.remove(deps.storage, (stage, member));
-            config.num_members -= 1;
-        }
-        MEMBER_COUNT.remove(deps.storage, stage);
+    // remove the members of the stage and reduce the num_members count. Other stages keep their ids.
+    let members = WHITELIST_STAGES
+        .prefix(stage_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for member in members {
+        WHITELIST_STAGES.remove(deps.storage, (stage_id, member));
+        config.num_members -= 1;
     }
-
-    // remove the stage and following stages permanently
-    config.stages = config.stages.into_iter().take(stage_id as usize).collect();
+    MEMBER_COUNT.remove(deps.storage, stage_id);
+    STAGES.remove(deps.storage, stage_id);
 
     CONFIG.save(deps.storage, &config)?;
     Ok(Response::new()
         .add_attribute("action", "remove_stage")
-        .add_attribute("stage_count", config.stages.len().to_string())
+        .add_attribute("stage_id", stage_id.to_string())
+        .add_attribute(
+            "stage_count",
+            STAGES
+                .keys(deps.storage, None, None, Order::Ascending)
+                .count()
+                .to_string(),
+        )
         .add_attribute("sender", info.sender))
 }
 
@@ -390,7 +394,14 @@ pub fn execute_increase_member_limit(
 
     let mut res = Response::new();
     if upgrade_fee > 0 {
-        checked_fair_burn(&info, &env, upgrade_fee, None, &mut res)?
+        checked_fair_burn(
+            &info,
+            &env,
+            upgrade_fee,
+            None,
+            &mut res,
+            &FEE_ROUTING.may_load(deps.storage)?.unwrap_or_default(),
+        )?
     }
 
     config.member_limit = member_limit;
@@ -432,17 +443,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 fn query_has_started(deps: Deps, env: Env) -> StdResult<HasStartedResponse> {
-    let config = CONFIG.load(deps.storage)?;
+    let stages = load_stages(deps.storage)?;
     Ok(HasStartedResponse {
-        has_started: !config.stages.is_empty() && (env.block.time >= config.stages[0].start_time),
+        has_started: first_stage(&stages).is_some_and(|stage| env.block.time >= stage.start_time),
     })
 }
 
 fn query_has_ended(deps: Deps, env: Env) -> StdResult<HasEndedResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    let stage_count = config.stages.len();
+    let stages = load_stages(deps.storage)?;
     Ok(HasEndedResponse {
-        has_ended: (stage_count > 0) && (env.block.time >= config.stages[stage_count - 1].end_time),
+        has_ended: last_stage(&stages).is_some_and(|stage| env.block.time >= stage.end_time),
     })
 }
 
@@ -489,8 +499,7 @@ pub fn query_stage_member_info(
     member: String,
 ) -> StdResult<StageMemberInfoResponse> {
     let addr = deps.api.addr_validate(&member)?;
-    let stage = CONFIG.load(deps.storage)?;
-    let stage = stage.stages[stage_id as usize].clone();
+    let stage = STAGES.load(deps.storage, stage_id)?;
     let is_member = WHITELIST_STAGES.has(deps.storage, (stage_id, addr.clone()));
     Ok(StageMemberInfoResponse {
         stage_id,
@@ -504,13 +513,12 @@ pub fn query_all_stage_member_info(
     member: String,
 ) -> StdResult<AllStageMemberInfoResponse> {
     let addr = deps.api.addr_validate(&member)?;
-    let config = CONFIG.load(deps.storage)?;
     let mut all_stage_member_info = vec![];
-    for stage_id in 0..config.stages.len() {
-        let stage = config.stages[stage_id].clone();
-        let is_member = WHITELIST_STAGES.may_load(deps.storage, (stage_id as u32, addr.clone()))?;
+    for item in STAGES.range(deps.storage, None, None, Order::Ascending) {
+        let (stage_id, stage) = item?;
+        let is_member = WHITELIST_STAGES.may_load(deps.storage, (stage_id, addr.clone()))?;
         all_stage_member_info.push(StageMemberInfoResponse {
-            stage_id: stage_id as u32,
+            stage_id,
             is_member: is_member.unwrap_or(false),
             per_address_limit: stage.per_address_limit,
         });
@@ -521,6 +529,7 @@ pub fn query_all_stage_member_info(
 }
 pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
+    let stages = load_stages(deps.storage)?;
     let active_stage = fetch_active_stage(deps.storage, &env);
     if let Some(stage) = active_stage {
         Ok(ConfigResponse {
@@ -532,11 +541,11 @@ pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
             mint_price: stage.mint_price,
             is_active: true,
         })
-    } else if !config.stages.is_empty() {
-        let stage = if env.block.time < config.stages[0].start_time {
-            config.stages[0].clone()
+    } else if let (Some(first), Some(last)) = (first_stage(&stages), last_stage(&stages)) {
+        let stage = if env.block.time < first.start_time {
+            first.clone()
         } else {
-            config.stages[config.stages.len() - 1].clone()
+            last.clone()
         };
         Ok(ConfigResponse {
             num_members: config.num_members,
@@ -564,36 +573,28 @@ pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
 }
 
 pub fn query_stage(deps: Deps, stage_id: u32) -> StdResult<StageResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    ensure!(
-        stage_id < config.stages.len() as u32,
-        StdError::generic_err("Stage not found")
-    );
+    let stage = STAGES
+        .may_load(deps.storage, stage_id)?
+        .ok_or_else(|| StdError::generic_err("Stage not found"))?;
     Ok(StageResponse {
         stage_id,
-        stage: config.stages[stage_id as usize].clone(),
+        stage,
         member_count: MEMBER_COUNT.may_load(deps.storage, stage_id)?.unwrap_or(0),
     })
 }
 
 pub fn query_stage_list(deps: Deps) -> StdResult<StagesResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    ensure!(
-        !config.stages.is_empty(),
-        StdError::generic_err("No stages found")
-    );
-    let stages = config
-        .stages
-        .iter()
-        .enumerate()
-        .map(|(i, stage)| StageResponse {
-            stage_id: i as u32,
-            stage: stage.clone(),
-            member_count: MEMBER_COUNT
-                .may_load(deps.storage, i as u32)
-                .unwrap_or(Some(0u32))
-                .unwrap_or(0u32),
+    let stages = STAGES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (stage_id, stage) = item?;
+            Ok(StageResponse {
+                stage_id,
+                stage,
+                member_count: MEMBER_COUNT.may_load(deps.storage, stage_id)?.unwrap_or(0),
+            })
         })
-        .collect();
+        .collect::<StdResult<Vec<_>>>()?;
+    ensure!(!stages.is_empty(), StdError::generic_err("No stages found"));
     Ok(StagesResponse { stages })
 }
@@ -38,9 +38,6 @@ pub enum ContractError {
     #[error("Max minting limit per address exceeded")]
     MaxPerAddressLimitExceeded {},
 
-    #[error("The number of stages cannot exceed 3")]
-    MaxStageCountExceeded {},
-
     #[error("Stage not found")]
     StageNotFound {},
 
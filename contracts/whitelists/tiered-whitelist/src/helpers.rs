@@ -2,28 +2,49 @@ pub mod interface;
 pub mod validators;
 
 use crate::contract::MAX_PER_ADDRESS_LIMIT;
-use crate::state::{Config, Stage, CONFIG};
+use crate::state::{Stage, STAGES};
 use crate::ContractError;
-use cosmwasm_std::{ensure, Env, StdError, Storage};
+use cosmwasm_std::{ensure, Env, Order, StdError, StdResult, Storage};
+
+/// Stages in stage id order
+pub fn load_stages(storage: &dyn Storage) -> StdResult<Vec<Stage>> {
+    STAGES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, stage)| stage))
+        .collect()
+}
+
+/// Id for a new stage, after the highest id in use
+pub fn next_stage_id(storage: &dyn Storage) -> StdResult<u32> {
+    let last_id = STAGES
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    Ok(last_id.map_or(0, |id| id + 1))
+}
 
 pub fn fetch_active_stage(deps: &dyn Storage, env: &Env) -> Option<Stage> {
-    let config: Config = CONFIG.load(deps).ok()?;
-    let current_time = env.block.time;
-    config
-        .stages
-        .iter()
-        .find(|stage| stage.start_time <= current_time && current_time <= stage.end_time)
-        .cloned()
+    let stage_id = fetch_active_stage_index(deps, env)?;
+    STAGES.load(deps, stage_id).ok()
 }
 
 pub fn fetch_active_stage_index(deps: &dyn Storage, env: &Env) -> Option<u32> {
-    let config: Config = CONFIG.load(deps).ok()?;
     let current_time = env.block.time;
-    config
-        .stages
-        .iter()
-        .position(|stage| stage.start_time <= current_time && current_time <= stage.end_time)
-        .map(|i| i as u32)
+    STAGES
+        .range(deps, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .find(|(_, stage)| stage.start_time <= current_time && current_time <= stage.end_time)
+        .map(|(stage_id, _)| stage_id)
+}
+
+/// The stage that starts first
+pub fn first_stage(stages: &[Stage]) -> Option<&Stage> {
+    stages.iter().min_by_key(|stage| stage.start_time)
+}
+
+/// The stage that ends last
+pub fn last_stage(stages: &[Stage]) -> Option<&Stage> {
+    stages.iter().max_by_key(|stage| stage.end_time)
 }
 
 pub fn validate_stages(env: &Env, stages: &[Stage]) -> Result<(), ContractError> {
@@ -31,10 +52,6 @@ pub fn validate_stages(env: &Env, stages: &[Stage]) -> Result<(), ContractError>
         !stages.is_empty(),
         StdError::generic_err("Must have at least one stage")
     );
-    ensure!(
-        stages.len() < 4,
-        StdError::generic_err("Cannot have more than 3 stages")
-    );
 
     // Check per address limit is valid
     if stages.iter().any(|stage| {
@@ -61,7 +78,7 @@ pub fn validate_stages(env: &Env, stages: &[Stage]) -> Result<(), ContractError>
     );
 
     ensure!(
-        stages[0].start_time > env.block.time,
+        stages.iter().all(|stage| stage.start_time > env.block.time),
         StdError::generic_err("Stages must have a start time in the future")
     );
     for i in 0..stages.len() {
@@ -71,9 +88,11 @@ pub fn validate_stages(env: &Env, stages: &[Stage]) -> Result<(), ContractError>
             StdError::generic_err("Stage start time must be before the end time")
         );
 
+        // Stages don't have to be ordered by time
         for other_stage in stages.iter().skip(i + 1) {
             ensure!(
-                other_stage.start_time >= stage.end_time,
+                other_stage.start_time >= stage.end_time
+                    || other_stage.end_time <= stage.start_time,
                 StdError::generic_err("Stages must have non-overlapping times")
             );
         }
@@ -86,10 +105,6 @@ pub fn validate_update(_env: &Env, stages: &[Stage]) -> Result<(), ContractError
         !stages.is_empty(),
         StdError::generic_err("Must have at least one stage")
     );
-    ensure!(
-        stages.len() < 4,
-        StdError::generic_err("Cannot have more than 3 stages")
-    );
 
     // Check per address limit is valid
     if stages.iter().any(|stage| {
@@ -122,9 +137,11 @@ pub fn validate_update(_env: &Env, stages: &[Stage]) -> Result<(), ContractError
             StdError::generic_err("Stage start time must be before the end time")
         );
 
+        // Stages don't have to be ordered by time
         for other_stage in stages.iter().skip(i + 1) {
             ensure!(
-                other_stage.start_time >= stage.end_time,
+                other_stage.start_time >= stage.end_time
+                    || other_stage.end_time <= stage.start_time,
                 StdError::generic_err("Stages must have non-overlapping times")
             );
         }
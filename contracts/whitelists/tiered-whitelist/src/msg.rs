@@ -9,6 +9,9 @@ pub struct InstantiateMsg {
     pub member_limit: u32,
     pub admins: Vec<String>,
     pub admins_mutable: bool,
+    /// Factory whose governance fee routing is used for the creation and member limit fees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<String>,
 }
 
 #[cw_serde]
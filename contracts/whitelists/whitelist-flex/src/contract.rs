@@ -8,7 +8,7 @@ use crate::msg::{
     HasStartedResponse, InstantiateMsg, IsActiveResponse, Member, MembersResponse, QueryMsg,
     RemoveMembersMsg,
 };
-use crate::state::{AdminList, Config, ADMIN_LIST, CONFIG, WHITELIST};
+use crate::state::{AdminList, Config, ADMIN_LIST, CONFIG, FEE_ROUTING, WHITELIST};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -20,7 +20,7 @@ use cw_storage_plus::Bound;
 use cw_utils::{may_pay, maybe_addr, must_pay};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use sg1::checked_fair_burn;
+use sg1::{checked_fair_burn, query_factory_fee_routing, FeeRouting};
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
 // version info for migration info
@@ -111,8 +111,14 @@ pub fn instantiate(
         ));
     }
 
+    let fee_routing = match &msg.factory {
+        Some(factory) => query_factory_fee_routing(&deps.querier, factory)?,
+        None => FeeRouting::default(),
+    };
+    FEE_ROUTING.save(deps.storage, &fee_routing)?;
+
     let mut res = Response::new();
-    checked_fair_burn(&info, &env, creation_fee, None, &mut res)?;
+    checked_fair_burn(&info, &env, creation_fee, None, &mut res, &fee_routing)?;
 
     if config.member_limit < config.num_members {
         return Err(ContractError::MembersExceeded {
@@ -312,7 +318,14 @@ pub fn execute_increase_member_limit(
 
     let mut res = Response::new();
     if upgrade_fee > 0 {
-        checked_fair_burn(&info, &env, upgrade_fee, None, &mut res)?
+        checked_fair_burn(
+            &info,
+            &env,
+            upgrade_fee,
+            None,
+            &mut res,
+            &FEE_ROUTING.may_load(deps.storage)?.unwrap_or_default(),
+        )?
     }
 
     config.member_limit = member_limit;
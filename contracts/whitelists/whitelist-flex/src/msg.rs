@@ -17,6 +17,9 @@ pub struct InstantiateMsg {
     pub admins: Vec<String>,
     pub admins_mutable: bool,
     pub whale_cap: Option<u32>,
+    /// Factory whose governance fee routing is used for the creation and member limit fees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<String>,
 }
 
 #[cw_serde]
@@ -2,14 +2,16 @@ use crate::admin::{
     can_execute, execute_freeze, execute_update_admins, query_admin_list, query_can_execute,
 };
 use crate::error::ContractError;
-use crate::helpers::crypto::{string_to_byte_slice, valid_hash_string, verify_merkle_root};
+use crate::helpers::crypto::{compute_merkle_root, verify_merkle_root};
 use crate::helpers::utils::verify_tree_uri;
 use crate::helpers::validators::map_validate;
 use crate::msg::{
     ConfigResponse, ExecuteMsg, HasEndedResponse, HasMemberResponse, HasStartedResponse,
     InstantiateMsg, IsActiveResponse, MerkleRootResponse, MerkleTreeURIResponse, QueryMsg,
 };
-use crate::state::{AdminList, Config, ADMIN_LIST, CONFIG, MERKLE_ROOT, MERKLE_TREE_URI};
+use crate::state::{
+    AdminList, Config, ADMIN_LIST, CONFIG, FEE_ROUTING, MERKLE_ROOT, MERKLE_TREE_URI,
+};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -20,9 +22,8 @@ use cw2::set_contract_version;
 use cw_utils::must_pay;
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
-use rs_merkle::{algorithms::Sha256, Hasher};
 use semver::Version;
-use sg1::checked_fair_burn;
+use sg1::{checked_fair_burn, query_factory_fee_routing, FeeRouting};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:whitelist-merkletree";
@@ -73,8 +74,14 @@ pub fn instantiate(
         ));
     }
 
+    let fee_routing = match &msg.factory {
+        Some(factory) => query_factory_fee_routing(&deps.querier, factory)?,
+        None => FeeRouting::default(),
+    };
+    FEE_ROUTING.save(deps.storage, &fee_routing)?;
+
     let mut res = Response::new();
-    checked_fair_burn(&info, &env, CREATION_FEE, None, &mut res)?;
+    checked_fair_burn(&info, &env, CREATION_FEE, None, &mut res, &fee_routing)?;
 
     let config = Config {
         start_time: msg.start_time,
@@ -257,30 +264,10 @@ pub fn query_has_member(
 ) -> StdResult<HasMemberResponse> {
     let merkle_root = MERKLE_ROOT.load(deps.storage)?;
 
-    let member_init_hash_slice = Sha256::hash(member.as_bytes());
-
-    let final_hash = proof_hashes.into_iter().try_fold(
-        member_init_hash_slice,
-        |accum_hash_slice, new_proof_hashstring| {
-            valid_hash_string(&new_proof_hashstring)?;
-
-            let mut hashe_slices = [
-                accum_hash_slice,
-                string_to_byte_slice(&new_proof_hashstring)?,
-            ];
-            hashe_slices.sort_unstable();
-            Result::<[u8; 32], StdError>::Ok(Sha256::hash(&hashe_slices.concat()))
-        },
-    );
-
-    if final_hash.is_err() {
-        return Err(cosmwasm_std::StdError::GenericErr {
-            msg: "Invalid Merkle Proof".to_string(),
-        });
-    }
+    let final_hash = compute_merkle_root(member.as_bytes(), proof_hashes)?;
 
     Ok(HasMemberResponse {
-        has_member: merkle_root == hex::encode(final_hash.unwrap()),
+        has_member: merkle_root == final_hash,
     })
 }
 
@@ -1,4 +1,5 @@
 use cosmwasm_std::{HexBinary, StdError, StdResult};
+use rs_merkle::{algorithms::Sha256, Hasher};
 
 pub fn valid_hash_string(hash_string: &String) -> StdResult<()> {
     let hex_res = HexBinary::from_hex(hash_string.as_str());
@@ -32,3 +33,29 @@ pub fn string_to_byte_slice(string: &String) -> StdResult<[u8; 32]> {
     })?;
     Ok(byte_slice)
 }
+
+/// Hashes the leaf up the tree with the proof hashes, returning the hex encoded root they lead to
+pub fn compute_merkle_root(leaf: &[u8], proof_hashes: Vec<String>) -> StdResult<String> {
+    let leaf_hash_slice = Sha256::hash(leaf);
+
+    let final_hash = proof_hashes.into_iter().try_fold(
+        leaf_hash_slice,
+        |accum_hash_slice, new_proof_hashstring| {
+            valid_hash_string(&new_proof_hashstring)?;
+
+            let mut hashe_slices = [
+                accum_hash_slice,
+                string_to_byte_slice(&new_proof_hashstring)?,
+            ];
+            hashe_slices.sort_unstable();
+            Result::<[u8; 32], StdError>::Ok(Sha256::hash(&hashe_slices.concat()))
+        },
+    );
+
+    match final_hash {
+        Ok(final_hash) => Ok(hex::encode(final_hash)),
+        Err(_) => Err(cosmwasm_std::StdError::GenericErr {
+            msg: "Invalid Merkle Proof".to_string(),
+        }),
+    }
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Timestamp};
 use cw_storage_plus::Item;
+use sg1::FeeRouting;
 
 #[cw_serde]
 pub struct Config {
@@ -29,5 +30,6 @@ impl AdminList {
 
 pub const ADMIN_LIST: Item<AdminList> = Item::new("admin_list");
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const FEE_ROUTING: Item<FeeRouting> = Item::new("fee_routing");
 pub const MERKLE_ROOT: Item<String> = Item::new("merkle_root");
 pub const MERKLE_TREE_URI: Item<String> = Item::new("merkle_tree_uri");
@@ -38,6 +38,7 @@ mod tests {
             mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
             admins: vec![ADMIN.to_string()],
             admins_mutable: true,
+            factory: None,
         };
         let info = mock_info(ADMIN, &[coin(CREATION_AMOUNT, NATIVE_DENOM)]);
         let res = instantiate(deps, mock_env(), info, msg).unwrap();
@@ -78,6 +79,7 @@ mod tests {
                 mint_price: coin(1, NATIVE_DENOM),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid merkle root (non 32 bytes)
             InstantiateMsg {
@@ -89,6 +91,7 @@ mod tests {
                 mint_price: coin(1, NATIVE_DENOM),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid mint price denom
             InstantiateMsg {
@@ -100,6 +103,7 @@ mod tests {
                 mint_price: coin(UNIT_AMOUNT, "not_ustars"),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid admin address (MockApi only) (too short)
             InstantiateMsg {
@@ -111,6 +115,7 @@ mod tests {
                 mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
                 admins: vec!["A".to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid start time (after end time)
             InstantiateMsg {
@@ -122,6 +127,7 @@ mod tests {
                 mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid start time (before genesis mint start time)
             InstantiateMsg {
@@ -133,6 +139,7 @@ mod tests {
                 mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
             // invalid start time (before current block time)
             InstantiateMsg {
@@ -144,6 +151,7 @@ mod tests {
                 mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
                 admins: vec![ADMIN.to_string()],
                 admins_mutable: false,
+                factory: None,
             },
         ];
 
@@ -0,0 +1,461 @@
+use crate::admin::{
+    can_execute, execute_freeze, execute_update_admins, query_admin_list, query_can_execute,
+};
+use crate::error::ContractError;
+use crate::helpers::validators::map_validate;
+use crate::msg::{
+    ClaimsResponse, ConfigResponse, ExecuteMsg, GateResponse, HasEndedResponse, HasMemberResponse,
+    HasStartedResponse, InstantiateMsg, IsActiveResponse, MinterQueryMsg, QueryMsg,
+};
+use crate::state::{AdminList, Config, ADMIN_LIST, CLAIMS, CLAIM_TOKENS, CONFIG};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Timestamp,
+};
+use cw2::set_contract_version;
+use cw721::{Cw721QueryMsg, OwnerOfResponse};
+use cw_utils::nonpayable;
+use sg_controllers::HooksResponse;
+use sg_mint_hooks::pre::PreMintHookMsg;
+use sg_utils::GENESIS_MINT_START_TIME;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:sg-whitelist-token-gated";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// contract governance params
+pub const MAX_PER_ADDRESS_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    nonpayable(&info)?;
+
+    if msg.collections.is_empty() {
+        return Err(ContractError::NoCollections {});
+    }
+    let collections = map_validate(deps.api, &msg.collections)?;
+    for (i, collection) in collections.iter().enumerate() {
+        if collections[..i].contains(collection) {
+            return Err(ContractError::DuplicateCollection(collection.to_string()));
+        }
+    }
+
+    if msg.mints_per_token == 0 {
+        return Err(ContractError::InvalidMintsPerToken(msg.mints_per_token));
+    }
+
+    if msg.per_address_limit > MAX_PER_ADDRESS_LIMIT {
+        return Err(ContractError::InvalidPerAddressLimit {
+            max: MAX_PER_ADDRESS_LIMIT.to_string(),
+            got: msg.per_address_limit.to_string(),
+        });
+    }
+    if msg.per_address_limit == 0 {
+        return Err(ContractError::InvalidPerAddressLimit {
+            max: "must be > 0".to_string(),
+            got: msg.per_address_limit.to_string(),
+        });
+    }
+
+    if msg.start_time > msg.end_time {
+        return Err(ContractError::InvalidStartTime(
+            msg.start_time,
+            msg.end_time,
+        ));
+    }
+
+    if env.block.time >= msg.start_time {
+        return Err(ContractError::InvalidStartTime(
+            env.block.time,
+            msg.start_time,
+        ));
+    }
+
+    let genesis_start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    if msg.start_time < genesis_start_time {
+        return Err(ContractError::InvalidStartTime(
+            msg.start_time,
+            genesis_start_time,
+        ));
+    }
+
+    let config = Config {
+        collections,
+        mints_per_token: msg.mints_per_token,
+        start_time: msg.start_time,
+        end_time: msg.end_time,
+        mint_price: msg.mint_price,
+        per_address_limit: msg.per_address_limit,
+        minter: msg
+            .minter
+            .map(|minter| validate_minter(deps.as_ref(), &minter))
+            .transpose()?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let admin_config = AdminList {
+        admins: map_validate(deps.api, &msg.admins)?,
+        mutable: msg.admins_mutable,
+    };
+    ADMIN_LIST.save(deps.storage, &admin_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("contract_name", CONTRACT_NAME)
+        .add_attribute("contract_version", CONTRACT_VERSION)
+        .add_attribute("sender", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateStartTime(time) => execute_update_start_time(deps, env, info, time),
+        ExecuteMsg::UpdateEndTime(time) => execute_update_end_time(deps, env, info, time),
+        ExecuteMsg::UpdatePerAddressLimit(per_address_limit) => {
+            execute_update_per_address_limit(deps, info, per_address_limit)
+        }
+        ExecuteMsg::UpdateMinter { minter } => execute_update_minter(deps, info, minter),
+        ExecuteMsg::UpdateAdmins { admins } => execute_update_admins(deps, env, info, admins),
+        ExecuteMsg::Freeze {} => execute_freeze(deps, env, info),
+        ExecuteMsg::SetClaimToken {
+            collection,
+            token_id,
+        } => execute_set_claim_token(deps, info, collection, token_id),
+        ExecuteMsg::PreMintHook(msg) => execute_pre_mint_hook(deps, env, info, msg),
+    }
+}
+
+pub fn execute_update_start_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Timestamp,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    can_execute(&deps, info.sender.clone())?;
+
+    // don't allow updating start time if whitelist is active
+    if env.block.time >= config.start_time {
+        return Err(ContractError::AlreadyStarted {});
+    }
+
+    if start_time > config.end_time {
+        return Err(ContractError::InvalidStartTime(start_time, config.end_time));
+    }
+
+    let genesis_start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let start_time = if start_time < genesis_start_time {
+        genesis_start_time
+    } else {
+        start_time
+    };
+
+    config.start_time = start_time;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_start_time")
+        .add_attribute("start_time", start_time.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_end_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    end_time: Timestamp,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    can_execute(&deps, info.sender.clone())?;
+
+    // if whitelist already started don't allow updating end_time unless
+    // it is to reduce it
+    if env.block.time >= config.start_time && end_time > config.end_time {
+        return Err(ContractError::AlreadyStarted {});
+    }
+
+    if end_time < config.start_time {
+        return Err(ContractError::InvalidEndTime(end_time, config.start_time));
+    }
+
+    config.end_time = end_time;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_end_time")
+        .add_attribute("end_time", end_time.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_per_address_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    per_address_limit: u32,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    can_execute(&deps, info.sender)?;
+
+    if per_address_limit > MAX_PER_ADDRESS_LIMIT {
+        return Err(ContractError::InvalidPerAddressLimit {
+            max: MAX_PER_ADDRESS_LIMIT.to_string(),
+            got: per_address_limit.to_string(),
+        });
+    }
+    if per_address_limit == 0 {
+        return Err(ContractError::InvalidPerAddressLimit {
+            max: "must be > 0".to_string(),
+            got: per_address_limit.to_string(),
+        });
+    }
+
+    config.per_address_limit = per_address_limit;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_per_address_limit")
+        .add_attribute("per_address_limit", per_address_limit.to_string()))
+}
+
+pub fn execute_update_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    minter: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    can_execute(&deps, info.sender.clone())?;
+
+    config.minter = Some(validate_minter(deps.as_ref(), &minter)?);
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_minter")
+        .add_attribute("minter", minter)
+        .add_attribute("sender", info.sender))
+}
+
+// Claims are only recorded through the pre-mint hook, so the minter must support them
+fn validate_minter(deps: Deps, minter: &str) -> Result<Addr, ContractError> {
+    let minter = deps.api.addr_validate(minter)?;
+    query_pre_mint_hooks(deps, &minter)
+        .map_err(|_| ContractError::MinterWithoutPreMintHooks(minter.to_string()))?;
+    Ok(minter)
+}
+
+fn query_pre_mint_hooks(deps: Deps, minter: &Addr) -> StdResult<Vec<String>> {
+    let res: HooksResponse = deps
+        .querier
+        .query_wasm_smart(minter, &MinterQueryMsg::PreMintHooks {})?;
+    Ok(res.hooks)
+}
+
+pub fn execute_set_claim_token(
+    deps: DepsMut,
+    info: MessageInfo,
+    collection: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let collection = deps.api.addr_validate(&collection)?;
+    if !config.collections.contains(&collection) {
+        return Err(ContractError::CollectionNotGated(collection.to_string()));
+    }
+    if !is_token_owner(deps.as_ref(), &collection, &token_id, &info.sender) {
+        return Err(ContractError::NotTokenOwner {
+            collection: collection.to_string(),
+            token_id,
+        });
+    }
+
+    CLAIM_TOKENS.save(
+        deps.storage,
+        &info.sender,
+        &(collection.clone(), token_id.clone()),
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "set_claim_token")
+        .add_attribute("collection", collection)
+        .add_attribute("token_id", token_id)
+        .add_attribute("sender", info.sender))
+}
+
+/// Consumes a claim on the buyer's claim token, if they still hold it and it has mints left.
+/// Mints outside of the whitelist period don't need a token.
+pub fn execute_pre_mint_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: PreMintHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.minter.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !is_active(&config, &env) {
+        return Ok(Response::new().add_attribute("action", "pre_mint_hook"));
+    }
+
+    let buyer = deps.api.addr_validate(&msg.buyer)?;
+    let (collection, token_id) = claimable_token(deps.as_ref(), &config, &buyer)?
+        .ok_or_else(|| ContractError::NoClaimableToken(buyer.to_string()))?;
+    CLAIMS.update(
+        deps.storage,
+        (&collection, &token_id),
+        |claimed| -> StdResult<_> { Ok(claimed.unwrap_or(0) + 1) },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "pre_mint_hook")
+        .add_attribute("buyer", buyer)
+        .add_attribute("collection", collection)
+        .add_attribute("token_id", token_id))
+}
+
+fn is_active(config: &Config, env: &Env) -> bool {
+    (env.block.time >= config.start_time) && (env.block.time < config.end_time)
+}
+
+// The buyer's claim token, while they still hold it and it has mints left
+fn claimable_token(deps: Deps, config: &Config, buyer: &Addr) -> StdResult<Option<(Addr, String)>> {
+    let (collection, token_id) = match CLAIM_TOKENS.may_load(deps.storage, buyer)? {
+        Some(claim_token) => claim_token,
+        None => return Ok(None),
+    };
+    if !is_token_owner(deps, &collection, &token_id, buyer) {
+        return Ok(None);
+    }
+    let claimed = CLAIMS
+        .may_load(deps.storage, (&collection, &token_id))?
+        .unwrap_or(0);
+    Ok((claimed < config.mints_per_token).then_some((collection, token_id)))
+}
+
+// Burned tokens have no owner
+fn is_token_owner(deps: Deps, collection: &Addr, token_id: &str, owner: &Addr) -> bool {
+    deps.querier
+        .query_wasm_smart::<OwnerOfResponse>(
+            collection,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            },
+        )
+        .map(|res| res.owner == owner.as_str())
+        .unwrap_or(false)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::HasStarted {} => to_json_binary(&query_has_started(deps, env)?),
+        QueryMsg::HasEnded {} => to_json_binary(&query_has_ended(deps, env)?),
+        QueryMsg::IsActive {} => to_json_binary(&query_is_active(deps, env)?),
+        QueryMsg::HasMember { member } => to_json_binary(&query_has_member(deps, env, member)?),
+        QueryMsg::Claims {
+            collection,
+            token_id,
+        } => to_json_binary(&query_claims(deps, collection, token_id)?),
+        QueryMsg::ClaimToken { member } => to_json_binary(&query_claim_token(deps, member)?),
+        QueryMsg::Config {} => to_json_binary(&query_config(deps, env)?),
+        QueryMsg::Gate {} => to_json_binary(&query_gate(deps)?),
+        QueryMsg::AdminList {} => to_json_binary(&query_admin_list(deps)?),
+        QueryMsg::CanExecute { sender, .. } => to_json_binary(&query_can_execute(deps, &sender)?),
+    }
+}
+
+fn query_has_started(deps: Deps, env: Env) -> StdResult<HasStartedResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(HasStartedResponse {
+        has_started: (env.block.time >= config.start_time),
+    })
+}
+
+fn query_has_ended(deps: Deps, env: Env) -> StdResult<HasEndedResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(HasEndedResponse {
+        has_ended: (env.block.time >= config.end_time),
+    })
+}
+
+fn query_is_active(deps: Deps, env: Env) -> StdResult<IsActiveResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(IsActiveResponse {
+        is_active: is_active(&config, &env),
+    })
+}
+
+/// An address is a member while it holds its claim token and the token has mints left.
+/// Nobody is a member until the whitelist is a pre-mint hook of its minter, otherwise mints
+/// wouldn't consume claims.
+pub fn query_has_member(deps: Deps, env: Env, member: String) -> StdResult<HasMemberResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let addr = deps.api.addr_validate(&member)?;
+
+    let is_hook = match &config.minter {
+        Some(minter) => {
+            query_pre_mint_hooks(deps, minter)?.contains(&env.contract.address.to_string())
+        }
+        None => false,
+    };
+    Ok(HasMemberResponse {
+        has_member: is_hook && claimable_token(deps, &config, &addr)?.is_some(),
+    })
+}
+
+pub fn query_claims(deps: Deps, collection: String, token_id: String) -> StdResult<ClaimsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let addr = deps.api.addr_validate(&collection)?;
+    let claimed = CLAIMS
+        .may_load(deps.storage, (&addr, &token_id))?
+        .unwrap_or(0);
+
+    Ok(ClaimsResponse {
+        collection,
+        token_id,
+        claimed,
+        remaining: config.mints_per_token.saturating_sub(claimed),
+    })
+}
+
+pub fn query_claim_token(deps: Deps, member: String) -> StdResult<Option<ClaimsResponse>> {
+    let addr = deps.api.addr_validate(&member)?;
+    CLAIM_TOKENS
+        .may_load(deps.storage, &addr)?
+        .map(|(collection, token_id)| query_claims(deps, collection.to_string(), token_id))
+        .transpose()
+}
+
+pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        num_members: 0,
+        per_address_limit: config.per_address_limit,
+        member_limit: 0,
+        start_time: config.start_time,
+        end_time: config.end_time,
+        mint_price: config.mint_price.clone(),
+        is_active: is_active(&config, &env),
+    })
+}
+
+pub fn query_gate(deps: Deps) -> StdResult<GateResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(GateResponse {
+        collections: config
+            .collections
+            .into_iter()
+            .map(|collection| collection.to_string())
+            .collect(),
+        mints_per_token: config.mints_per_token,
+        minter: config.minter.map(|minter| minter.to_string()),
+    })
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{StdError, Timestamp};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("AlreadyStarted")]
+    AlreadyStarted {},
+
+    #[error("InvalidStartTime {0} > {1}")]
+    InvalidStartTime(Timestamp, Timestamp),
+
+    #[error("InvalidEndTime {0} > {1}")]
+    InvalidEndTime(Timestamp, Timestamp),
+
+    #[error("InvalidPerAddressLimit, max: {max}, got: {got}")]
+    InvalidPerAddressLimit { max: String, got: String },
+
+    #[error("InvalidMintsPerToken {0}")]
+    InvalidMintsPerToken(u32),
+
+    #[error("NoCollections")]
+    NoCollections {},
+
+    #[error("DuplicateCollection: {0}")]
+    DuplicateCollection(String),
+
+    #[error("MinterWithoutPreMintHooks: {0}")]
+    MinterWithoutPreMintHooks(String),
+
+    #[error("NoClaimableToken: {0}")]
+    NoClaimableToken(String),
+
+    #[error("CollectionNotGated: {0}")]
+    CollectionNotGated(String),
+
+    #[error("NotTokenOwner: {collection} {token_id}")]
+    NotTokenOwner {
+        collection: String,
+        token_id: String,
+    },
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+}
@@ -0,0 +1,5 @@
+use cosmwasm_std::{Addr, Api, StdResult};
+
+pub fn map_validate(api: &dyn Api, admins: &[String]) -> StdResult<Vec<Addr>> {
+    admins.iter().map(|addr| api.addr_validate(addr)).collect()
+}
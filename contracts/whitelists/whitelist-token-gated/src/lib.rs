@@ -0,0 +1,7 @@
+pub mod admin;
+pub mod contract;
+pub mod error;
+pub mod helpers;
+pub mod msg;
+pub mod state;
+pub use crate::error::ContractError;
@@ -0,0 +1,137 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, CosmosMsg, Empty, Timestamp};
+use sg_mint_hooks::pre::PreMintHookMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// sg721 collections whose holders are members
+    pub collections: Vec<String>,
+    /// Number of mints each held token grants
+    pub mints_per_token: u32,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub mint_price: Coin,
+    pub per_address_limit: u32,
+    /// Minter allowed to consume claims through the pre-mint hook, it must support pre-mint hooks
+    pub minter: Option<String>,
+    pub admins: Vec<String>,
+    pub admins_mutable: bool,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    UpdateStartTime(Timestamp),
+    UpdateEndTime(Timestamp),
+    UpdatePerAddressLimit(u32),
+    UpdateMinter {
+        minter: String,
+    },
+    UpdateAdmins {
+        admins: Vec<String>,
+    },
+    Freeze {},
+    /// Picks the held token the sender's whitelist mints consume claims on
+    SetClaimToken {
+        collection: String,
+        token_id: String,
+    },
+    /// Called by the minter before each mint, consumes a claim on the buyer's claim token
+    PreMintHook(PreMintHookMsg),
+}
+
+/// Query of the minter's pre-mint hooks, in the format of `sg_mint_hooks_query`
+#[cw_serde]
+pub enum MinterQueryMsg {
+    PreMintHooks {},
+}
+
+#[cw_serde]
+pub struct AdminListResponse {
+    pub admins: Vec<String>,
+    pub mutable: bool,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(HasStartedResponse)]
+    HasStarted {},
+    #[returns(HasEndedResponse)]
+    HasEnded {},
+    #[returns(IsActiveResponse)]
+    IsActive {},
+    #[returns(HasMemberResponse)]
+    HasMember { member: String },
+    #[returns(ClaimsResponse)]
+    Claims {
+        collection: String,
+        token_id: String,
+    },
+    /// Claims of the token the member picked with `SetClaimToken`
+    #[returns(Option<ClaimsResponse>)]
+    ClaimToken { member: String },
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(GateResponse)]
+    Gate {},
+    #[returns(AdminListResponse)]
+    AdminList {},
+    #[returns(CanExecuteResponse)]
+    CanExecute {
+        sender: String,
+        msg: CosmosMsg<Empty>,
+    },
+}
+
+#[cw_serde]
+pub struct HasMemberResponse {
+    pub has_member: bool,
+}
+
+#[cw_serde]
+pub struct ClaimsResponse {
+    pub collection: String,
+    pub token_id: String,
+    pub claimed: u32,
+    pub remaining: u32,
+}
+
+#[cw_serde]
+pub struct HasEndedResponse {
+    pub has_ended: bool,
+}
+
+#[cw_serde]
+pub struct HasStartedResponse {
+    pub has_started: bool,
+}
+
+#[cw_serde]
+pub struct IsActiveResponse {
+    pub is_active: bool,
+}
+
+/// Same as the whitelist `ConfigResponse` the minters query. There is no member list, so
+/// `num_members` and `member_limit` are always 0.
+#[cw_serde]
+pub struct ConfigResponse {
+    pub num_members: u32,
+    pub per_address_limit: u32,
+    pub member_limit: u32,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub mint_price: Coin,
+    pub is_active: bool,
+}
+
+#[cw_serde]
+pub struct GateResponse {
+    pub collections: Vec<String>,
+    pub mints_per_token: u32,
+    pub minter: Option<String>,
+}
+
+#[cw_serde]
+pub struct CanExecuteResponse {
+    pub can_execute: bool,
+}
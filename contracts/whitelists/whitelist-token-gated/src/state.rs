@@ -0,0 +1,42 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    pub collections: Vec<Addr>,
+    pub mints_per_token: u32,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub mint_price: Coin,
+    pub per_address_limit: u32,
+    /// Only the minter can consume claims through the pre-mint hook
+    pub minter: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct AdminList {
+    pub admins: Vec<Addr>,
+    pub mutable: bool,
+}
+
+impl AdminList {
+    pub fn is_admin(&self, addr: impl AsRef<str>) -> bool {
+        let addr = addr.as_ref();
+        self.admins.iter().any(|a| a.as_ref() == addr)
+    }
+
+    pub fn can_modify(&self, addr: &str) -> bool {
+        self.mutable && self.is_admin(addr)
+    }
+}
+
+pub const ADMIN_LIST: Item<AdminList> = Item::new("admin_list");
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+// (collection, token_id) <> mints claimed with the token
+pub const CLAIMS: Map<(&Addr, &str), u32> = Map::new("claims");
+
+// buyer <> (collection, token_id) their whitelist mints consume claims on
+pub const CLAIM_TOKENS: Map<&Addr, (Addr, String)> = Map::new("claim_tokens");
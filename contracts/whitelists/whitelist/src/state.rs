@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Timestamp};
 use cw_storage_plus::{Item, Map};
+use sg1::FeeRouting;
 
 #[cw_serde]
 pub struct Config {
@@ -32,4 +33,5 @@ impl AdminList {
 pub const ADMIN_LIST: Item<AdminList> = Item::new("admin_list");
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const FEE_ROUTING: Item<FeeRouting> = Item::new("fee_routing");
 pub const WHITELIST: Map<Addr, bool> = Map::new("wl");
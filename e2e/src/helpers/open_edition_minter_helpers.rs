@@ -52,8 +52,12 @@ pub fn instantiate_factory(
                     amount: Uint128::new(50),
                     denom: denom.to_string(),
                 },
+                additional_min_mint_prices: vec![],
                 mint_fee_bps: 1000, // 10%
                 max_trading_offset_secs: (60 * 60) * 24,
+                fee_routing: sg1::FeeRouting::default(),
+                max_whitelist_stages: 3,
+                splits_code_ids: None,
                 extension: ParamsExtension {
                     max_token_limit: 1_000u32,
                     max_per_address_limit: 50,
@@ -113,8 +117,10 @@ pub fn create_minter_msg(
                 start_trading_time,
                 external_link: None,
                 explicit_content: None,
+                enforce_start_trading_time: None,
                 royalty_info: None,
             },
         },
+        splits: None,
     }
 }
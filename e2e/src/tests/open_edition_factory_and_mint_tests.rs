@@ -267,7 +267,7 @@ fn test_create_minter(chain: &mut Chain) {
             total_mints += 1;
             reqs.push(ExecReq {
                 contract_name: "minter".to_string(),
-                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint {}),
+                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint { quantity: None }),
                 funds: vec![OrcCoin {
                     amount: MINT_PRICE,
                     denom: denom.parse().unwrap(),
@@ -322,7 +322,7 @@ fn test_create_minter(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &chain.cfg.users[1].key,
         vec![OrcCoin {
             amount: MINT_PRICE,
@@ -485,7 +485,7 @@ fn test_start_trading_time(chain: &mut Chain) {
             total_mints += 1;
             reqs.push(ExecReq {
                 contract_name: "minter".to_string(),
-                msg: Box::new(vending_minter::msg::ExecuteMsg::Mint {}),
+                msg: Box::new(vending_minter::msg::ExecuteMsg::Mint { quantity: None }),
                 funds: vec![OrcCoin {
                     amount: MINT_PRICE,
                     denom: denom.parse().unwrap(),
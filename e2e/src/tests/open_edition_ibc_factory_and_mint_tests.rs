@@ -250,7 +250,7 @@ fn test_create_open_edition_ibc_minter(chain: &mut Chain) {
             total_mints += 1;
             reqs.push(ExecReq {
                 contract_name: "minter".to_string(),
-                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint {}),
+                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint { quantity: None }),
                 funds: vec![OrcCoin {
                     amount: MINT_PRICE,
                     denom: MINT_DENOM.parse().unwrap(),
@@ -305,7 +305,7 @@ fn test_create_open_edition_ibc_minter(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &chain.cfg.users[1].key,
         vec![OrcCoin {
             amount: MINT_PRICE,
@@ -135,7 +135,7 @@ fn test_open_edition_ibc_exec_functions(chain: &mut Chain) {
             total_mints += 1;
             reqs.push(ExecReq {
                 contract_name: "minter".to_string(),
-                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint {}),
+                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint { quantity: None }),
                 funds: vec![OrcCoin {
                     amount: MINT_PRICE,
                     denom: MINT_DENOM.parse().unwrap(),
@@ -188,6 +188,7 @@ fn test_open_edition_ibc_exec_functions(chain: &mut Chain) {
             "minter_exec_mint_to_token",
             &vending_minter::msg::ExecuteMsg::MintTo {
                 recipient: dev.account.address.to_string(),
+                quantity: None,
             },
             &user.key,
             vec![],
@@ -200,6 +201,7 @@ fn test_open_edition_ibc_exec_functions(chain: &mut Chain) {
         "minter_exec_mint_to_token_err",
         &vending_minter::msg::ExecuteMsg::MintTo {
             recipient: user.account.address.to_string(),
+            quantity: None,
         },
         &dev.key,
         vec![],
@@ -248,7 +250,7 @@ fn test_open_edition_ibc_exec_functions(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &users[0],
         vec![OrcCoin {
             amount: MINT_PRICE,
@@ -271,7 +273,7 @@ fn test_open_edition_ibc_exec_functions(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &chain.cfg.users[1].key,
         vec![OrcCoin {
             amount: MINT_PRICE,
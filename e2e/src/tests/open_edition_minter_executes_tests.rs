@@ -131,7 +131,7 @@ fn test_open_edition_exec_functions(chain: &mut Chain) {
             total_mints += 1;
             reqs.push(ExecReq {
                 contract_name: "minter".to_string(),
-                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint {}),
+                msg: Box::new(open_edition_minter::msg::ExecuteMsg::Mint { quantity: None }),
                 funds: vec![OrcCoin {
                     amount: MINT_PRICE,
                     denom: denom.parse().unwrap(),
@@ -184,6 +184,8 @@ fn test_open_edition_exec_functions(chain: &mut Chain) {
             "minter_exec_mint_to_token",
             &vending_minter::msg::ExecuteMsg::MintTo {
                 recipient: dev.account.address.to_string(),
+                quantity: None,
+                royalty_info: None,
             },
             &user.key,
             vec![OrcCoin {
@@ -199,6 +201,8 @@ fn test_open_edition_exec_functions(chain: &mut Chain) {
         "minter_exec_mint_to_token_err",
         &vending_minter::msg::ExecuteMsg::MintTo {
             recipient: user.account.address.to_string(),
+            quantity: None,
+            royalty_info: None,
         },
         &dev.key,
         vec![],
@@ -247,7 +251,7 @@ fn test_open_edition_exec_functions(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &users[0],
         vec![OrcCoin {
             amount: MINT_PRICE,
@@ -270,7 +274,7 @@ fn test_open_edition_exec_functions(chain: &mut Chain) {
     let res = chain.orc.execute(
         "minter",
         "minter_exec_mint_token_err",
-        &vending_minter::msg::ExecuteMsg::Mint {},
+        &vending_minter::msg::ExecuteMsg::Mint { quantity: None },
         &chain.cfg.users[1].key,
         vec![OrcCoin {
             amount: MINT_PRICE,
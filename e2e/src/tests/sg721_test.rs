@@ -25,6 +25,7 @@ fn test_unauthorized_sg721_instantiation(chain: &mut Chain) {
                 description: "Description".to_string(),
                 image: "https://example.com/image.png".to_string(),
                 external_link: Some("https://github.com/public-awesome".to_string()),
+                enforce_start_trading_time: None,
                 royalty_info: None,
                 explicit_content: None,
                 start_trading_time: None,
@@ -0,0 +1,116 @@
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Binary, Deps, StdError, StdResult};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// Returns the amino JSON sign doc of an [ADR-036] `signArbitrary` signature of `data` by `signer`.
+///
+/// [ADR-036]: https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+pub fn adr036_sign_doc(signer: &str, data: &str) -> String {
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        Binary::from(data.as_bytes()).to_base64(),
+        signer
+    )
+}
+
+/// Returns the bech32 address of a compressed secp256k1 public key for the given prefix
+pub fn cosmos_address(pubkey: &[u8], prefix: &str) -> StdResult<String> {
+    if pubkey.len() != 33 {
+        return Err(StdError::generic_err(
+            "Public key must be 33 bytes long (compressed)",
+        ));
+    }
+    let hash = Ripemd160::digest(Sha256::digest(pubkey));
+    bech32::encode(prefix, hash.to_base32(), Variant::Bech32)
+        .map_err(|err| StdError::generic_err(format!("bech32 encoding error: {err}")))
+}
+
+/// Verifies a Cosmos wallet `signArbitrary` signature of `message`. The public key must derive
+/// the signer address, whatever the chain prefix of that address.
+pub fn verify_cosmos_arbitrary(
+    deps: Deps,
+    message: &str,
+    signature: &[u8],
+    pubkey: &[u8],
+    signer_address: &str,
+) -> StdResult<bool> {
+    let (prefix, _, _) = bech32::decode(signer_address)
+        .map_err(|_| StdError::generic_err("Signer must be a bech32 address"))?;
+    if cosmos_address(pubkey, &prefix)? != signer_address {
+        return Ok(false);
+    }
+
+    let hash = Sha256::digest(adr036_sign_doc(signer_address, message));
+    let result = deps.api.secp256k1_verify(&hash, signature, pubkey);
+    match result {
+        Ok(verifies) => Ok(verifies),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Binary;
+
+    use super::*;
+
+    const MESSAGE: &str =
+        "I agree to claim the airdrop to stars1wsfsd06zfz7h2gamf3s6h9ysmsjhysr6ffuq4l";
+
+    // Keplr `signArbitrary` of MESSAGE: a low-S secp256k1 signature of the sha256 of the
+    // ADR-036 sign doc, by the key sha256("claim-verify cosmos test key")
+    const SIGNER: &str = "cosmos1c40yumaj06x2n5k4x59rftl9mvewkw8aa9yclw";
+    const PUB_KEY: &str = "A87oh5UuaySbdWUQr1G0pe608apffIhB/nMiu6uSG87b";
+    const SIGNATURE: &str =
+        "v0l1LNuwIHWIAcpkm9/wr1xs1VkWWauxBRPakC7F7JBxfspWEQdE9PvKnw+u+5GuxG68AORIFK3iH6aXSIa9NA==";
+
+    #[test]
+    fn cosmos_address_of_pubkey() {
+        let pubkey = Binary::from_base64(PUB_KEY).unwrap();
+        assert_eq!(cosmos_address(&pubkey, "cosmos").unwrap(), SIGNER);
+        assert!(cosmos_address(&pubkey[1..], "cosmos").is_err());
+    }
+
+    #[test]
+    fn verify_sign_arbitrary() {
+        let deps = mock_dependencies();
+        let pubkey = Binary::from_base64(PUB_KEY).unwrap();
+        let signature = Binary::from_base64(SIGNATURE).unwrap();
+
+        assert!(
+            verify_cosmos_arbitrary(deps.as_ref(), MESSAGE, &signature, &pubkey, SIGNER).unwrap()
+        );
+
+        // another message, or the same key under another chain prefix, does not verify
+        let other_message = MESSAGE.replace("stars1", "stars2");
+        assert!(!verify_cosmos_arbitrary(
+            deps.as_ref(),
+            &other_message,
+            &signature,
+            &pubkey,
+            SIGNER
+        )
+        .unwrap());
+        let osmo_signer = cosmos_address(&pubkey, "osmo").unwrap();
+        assert!(!verify_cosmos_arbitrary(
+            deps.as_ref(),
+            MESSAGE,
+            &signature,
+            &pubkey,
+            &osmo_signer
+        )
+        .unwrap());
+
+        // the pubkey must derive the signer address
+        assert!(!verify_cosmos_arbitrary(
+            deps.as_ref(),
+            MESSAGE,
+            &signature,
+            &pubkey,
+            "cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnrk363e"
+        )
+        .unwrap());
+    }
+}
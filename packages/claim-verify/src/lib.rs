@@ -0,0 +1,5 @@
+mod cosmos;
+pub use cosmos::{adr036_sign_doc, cosmos_address, verify_cosmos_arbitrary};
+
+mod solana;
+pub use solana::{decode_solana_address, verify_solana_text};
@@ -0,0 +1,69 @@
+use cosmwasm_std::{Deps, StdError, StdResult};
+
+/// Returns the ed25519 public key of a base58 Solana address
+pub fn decode_solana_address(input: &str) -> StdResult<[u8; 32]> {
+    let data = bs58::decode(input)
+        .into_vec()
+        .map_err(|_| StdError::generic_err("base58 decoding error"))?;
+    data.try_into()
+        .map_err(|_| StdError::generic_err("Solana address must be 32 bytes long"))
+}
+
+/// Verifies a Solana wallet `signMessage` signature of `message`
+pub fn verify_solana_text(
+    deps: Deps,
+    message: &str,
+    signature: &[u8],
+    signer_address: &str,
+) -> StdResult<bool> {
+    let pubkey = decode_solana_address(signer_address)?;
+    let result = deps
+        .api
+        .ed25519_verify(message.as_bytes(), signature, &pubkey);
+    match result {
+        Ok(verifies) => Ok(verifies),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Binary;
+
+    use super::*;
+
+    const MESSAGE: &str =
+        "I agree to claim the airdrop to stars1wsfsd06zfz7h2gamf3s6h9ysmsjhysr6ffuq4l";
+
+    // Phantom `signMessage` of MESSAGE: the ed25519 signature of its UTF-8 bytes,
+    // by the key with seed sha256("claim-verify solana test key")
+    const SIGNER: &str = "83itFq8eJ2fbHTDmpLXipEYng17roPkxn5G58PnAD4p7";
+    const SIGNATURE: &str =
+        "NtRnfnBcrripk6QHzHAXbY05ET0zQi3lWxaP/Lht207sNGrIFFUF2ZlY1dSvGTin2DcrlOJsdQkSxrYZdXtTBw==";
+
+    #[test]
+    fn decode_address() {
+        assert_eq!(decode_solana_address(SIGNER).unwrap().len(), 32);
+        assert!(decode_solana_address("0OIl").is_err());
+        assert!(decode_solana_address("83itFq8eJ2fbHTDmpLXipEYng17roPkx").is_err());
+    }
+
+    #[test]
+    fn verify_sign_message() {
+        let deps = mock_dependencies();
+        let signature = Binary::from_base64(SIGNATURE).unwrap();
+
+        assert!(verify_solana_text(deps.as_ref(), MESSAGE, &signature, SIGNER).unwrap());
+
+        let other_message = MESSAGE.replace("stars1", "stars2");
+        assert!(!verify_solana_text(deps.as_ref(), &other_message, &signature, SIGNER).unwrap());
+        assert!(!verify_solana_text(
+            deps.as_ref(),
+            MESSAGE,
+            &signature,
+            "11111111111111111111111111111111"
+        )
+        .unwrap());
+    }
+}
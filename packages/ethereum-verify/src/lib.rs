@@ -2,4 +2,7 @@ mod decode;
 pub use decode::{decode_address, ethereum_address_raw, get_recovery_param};
 
 mod signature_verify;
-pub use signature_verify::verify_ethereum_text;
+pub use signature_verify::{
+    typed_data_hash, verify_ethereum_text, verify_ethereum_typed_data, TypedClaim, TypedDataDomain,
+    EIP712_DOMAIN_TYPE, TYPED_CLAIM_TYPE,
+};
@@ -22,6 +22,78 @@ pub fn verify_ethereum_text(
     hasher.update(message);
     let hash = hasher.finalize();
 
+    verify_hash(deps, &hash, signature, &signer_address)
+}
+
+/// Domain of EIP-712 typed data. Cosmos chain ids and contract addresses don't fit the
+/// `uint256 chainId` and `address verifyingContract` domain fields, so they are part of the
+/// signed [`TypedClaim`] instead.
+pub struct TypedDataDomain {
+    pub name: String,
+    pub version: String,
+}
+
+/// Claim signed as EIP-712 typed data, by an Ethereum address for a Stargaze recipient
+pub struct TypedClaim {
+    pub chain_id: String,
+    pub verifying_contract: String,
+    pub recipient: String,
+    pub nonce: u64,
+}
+
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version)";
+pub const TYPED_CLAIM_TYPE: &str =
+    "Claim(string chainId,string verifyingContract,string recipient,uint256 nonce)";
+
+/// Returns the EIP-712 hash of the claim, as signed by `eth_signTypedData_v4`
+pub fn typed_data_hash(domain: &TypedDataDomain, claim: &TypedClaim) -> [u8; 32] {
+    let domain_separator = keccak256(
+        &[
+            keccak256(EIP712_DOMAIN_TYPE.as_bytes()),
+            keccak256(domain.name.as_bytes()),
+            keccak256(domain.version.as_bytes()),
+        ]
+        .concat(),
+    );
+
+    let mut nonce = [0u8; 32];
+    nonce[24..].copy_from_slice(&claim.nonce.to_be_bytes());
+    let struct_hash = keccak256(
+        &[
+            keccak256(TYPED_CLAIM_TYPE.as_bytes()),
+            keccak256(claim.chain_id.as_bytes()),
+            keccak256(claim.verifying_contract.as_bytes()),
+            keccak256(claim.recipient.as_bytes()),
+            nonce,
+        ]
+        .concat(),
+    );
+
+    keccak256(&[&[0x19, 0x01], &domain_separator[..], &struct_hash[..]].concat())
+}
+
+pub fn verify_ethereum_typed_data(
+    deps: Deps,
+    domain: &TypedDataDomain,
+    claim: &TypedClaim,
+    signature: &[u8],
+    signer_address: &str,
+) -> StdResult<bool> {
+    let signer_address = decode_address(signer_address)?;
+    let hash = typed_data_hash(domain, claim);
+    verify_hash(deps, &hash, signature, &signer_address)
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn verify_hash(
+    deps: Deps,
+    hash: &[u8],
+    signature: &[u8],
+    signer_address: &[u8; 20],
+) -> StdResult<bool> {
     // Decompose signature
     let (v, rs) = match signature.split_last() {
         Some(pair) => pair,
@@ -30,12 +102,12 @@ pub fn verify_ethereum_text(
     let recovery = get_recovery_param(*v)?;
 
     // Verification
-    let calculated_pubkey = deps.api.secp256k1_recover_pubkey(&hash, rs, recovery)?;
+    let calculated_pubkey = deps.api.secp256k1_recover_pubkey(hash, rs, recovery)?;
     let calculated_address = ethereum_address_raw(&calculated_pubkey)?;
-    if signer_address != calculated_address {
+    if *signer_address != calculated_address {
         return Ok(false);
     }
-    let result = deps.api.secp256k1_verify(&hash, rs, &calculated_pubkey);
+    let result = deps.api.secp256k1_verify(hash, rs, &calculated_pubkey);
     match result {
         Ok(verifies) => Ok(verifies),
         Err(err) => Err(err.into()),
@@ -67,6 +67,7 @@ fn merge_variants(metadata: TokenStream, left: TokenStream, right: TokenStream)
 /// enum ExecuteMsg {
 ///     AddPreMintHook { hook: String },
 ///     AddPostMintHook { hook: String },
+///     RemoveHook { hook: String },
 ///     Foo {},
 ///     Bar {},
 /// }
@@ -82,6 +83,7 @@ pub fn sg_mint_hooks_execute(metadata: TokenStream, input: TokenStream) -> Token
             enum Right {
                 AddPreMintHook { hook: String },
                 AddPostMintHook { hook: String },
+                RemoveHook { hook: String },
             }
         }
         .into(),
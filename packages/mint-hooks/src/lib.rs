@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{DepsMut, Response, StdError};
 use sg_controllers::{HookError, Hooks};
 
 // re-export the proc macros
@@ -35,6 +35,24 @@ pub fn handle_reply(reply_id: u64) -> Result<(), MintHookError> {
     Ok(())
 }
 
+/// Removes a hook from the pre-mint and post-mint hooks it is registered in
+pub fn remove_hook(deps: DepsMut, hook: String) -> Result<Response, MintHookError> {
+    let addr = deps.api.addr_validate(&hook)?;
+
+    let removed_premint = PREMINT_HOOKS
+        .remove_hook(deps.storage, addr.clone())
+        .is_ok();
+    let removed_postmint = POSTMINT_HOOKS.remove_hook(deps.storage, addr).is_ok();
+    if !removed_premint && !removed_postmint {
+        return Err(HookError::HookNotRegistered {}.into());
+    }
+
+    let res = Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook", hook);
+    Ok(res)
+}
+
 pub mod pre {
     use cosmwasm_schema::cw_serde;
     use cosmwasm_std::{
@@ -7,13 +7,13 @@ use cw_utils::{may_pay, must_pay, PaymentError};
 use sg_utils::NATIVE_DENOM;
 use thiserror::Error;
 
-// governance parameters
-const FEE_BURN_PERCENT: u64 = 50;
-const FOUNDATION: &str = "stars1xqz6xujjyz0r9uzn7srasle5uynmpa0zkjr5l8";
-const LAUNCHPAD_DAO_ADDRESS: &str =
-    "stars1huqk6ha02jgrm69lxh8xfgl6wch9wlg7s65ujxydwdr725cxvuus423tj0";
-const LIQUIDITY_DAO_ADDRESS: &str =
-    "stars12he2ldxl950wfypvelqwkac4mdul7clzgd8wdlnmjvll8z2cc47qsatvl2";
+mod routing;
+mod splits;
+
+pub use routing::{
+    query_factory_fee_routing, query_minter_fee_routing, split_by_weight, FeeRecipient, FeeRouting,
+};
+pub use splits::{payment_msg, SplitsExecuteMsg, SplitsInfoResponse, SplitsQueryMsg};
 
 /// Burn and distribute fees and return an error if the fee is not enough
 pub fn checked_fair_burn(
@@ -22,6 +22,7 @@ pub fn checked_fair_burn(
     fee: u128,
     developer: Option<Addr>,
     res: &mut Response,
+    routing: &FeeRouting,
 ) -> Result<(), FeeError> {
     // Use may_pay because fees could be 0. Add check to avoid transferring 0 funds
     let payment = may_pay(info, NATIVE_DENOM)?;
@@ -30,7 +31,13 @@ pub fn checked_fair_burn(
     };
 
     if payment.u128() != 0u128 {
-        fair_burn(env.contract.address.to_string(), fee, developer, res);
+        fair_burn(
+            env.contract.address.to_string(),
+            fee,
+            developer,
+            res,
+            routing,
+        );
     }
 
     Ok(())
@@ -42,13 +49,14 @@ pub fn ibc_denom_fair_burn(
     fee: Coin,
     developer: Option<Addr>,
     res: &mut Response,
+    routing: &FeeRouting,
 ) -> Result<(), FeeError> {
     let mut event = Event::new("ibc-fair-burn");
 
     match &developer {
         Some(developer) => {
-            // Calculate the fees. 50% to dev, 50% to foundation
-            let dev_fee = (fee.amount.mul_ceil(Decimal::percent(FEE_BURN_PERCENT))).u128();
+            // Calculate the fees. Dev gets the burn share, the rest goes to the foundation
+            let dev_fee = (fee.amount.mul_ceil(Decimal::percent(routing.burn_percent))).u128();
             let dev_coin = coin(dev_fee, fee.denom.to_string());
             let foundation_coin = coin(fee.amount.u128() - dev_fee, fee.denom);
 
@@ -61,7 +69,7 @@ pub fn ibc_denom_fair_burn(
                 amount: vec![dev_coin],
             }));
             res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: FOUNDATION.to_string(),
+                to_address: routing.foundation.clone(),
                 amount: vec![foundation_coin],
             }));
         }
@@ -69,7 +77,7 @@ pub fn ibc_denom_fair_burn(
             // No dev, send all to foundation.
             event = event.add_attribute("foundation_coin", fee.to_string());
             res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: FOUNDATION.to_string(),
+                to_address: routing.foundation.clone(),
                 amount: vec![fee],
             }));
         }
@@ -84,70 +92,40 @@ pub fn distribute_mint_fees(
     res: &mut Response,
     is_featured: bool,
     developer: Option<Addr>,
+    routing: &FeeRouting,
 ) -> Result<(), FeeError> {
-    let liquidity_dao_ratio: Decimal = Decimal::from_ratio(1u128, 5u128);
-    let liquidity_dao_ratio_featured: Decimal = Decimal::from_ratio(1u128, 8u128);
-
     let mut event = Event::new("mint-fee-distribution");
 
-    let liquidity_dao_percentage = if is_featured {
-        liquidity_dao_ratio_featured
-    } else {
-        liquidity_dao_ratio
-    };
-
-    match &developer {
+    let remaining_coin = match &developer {
         Some(developer) => {
             let dev_fee = fee
                 .amount
-                .mul_ceil(Decimal::percent(FEE_BURN_PERCENT))
+                .mul_ceil(Decimal::percent(routing.burn_percent))
                 .u128();
             let dev_coin = coin(dev_fee, fee.denom.to_string());
-            let remaining_coin = coin(fee.amount.u128() - dev_fee, fee.denom.clone());
-
-            let liquidity_dao_fee =
-                (remaining_coin.amount.mul_ceil(liquidity_dao_percentage)).u128();
-            let liquidity_dao_coin = coin(liquidity_dao_fee, fee.denom.to_string());
-            let launchpad_dao_coin =
-                coin(remaining_coin.amount.u128() - liquidity_dao_fee, fee.denom);
 
             event = event.add_attribute("dev_addr", developer.to_string());
             event = event.add_attribute("dev_coin", dev_coin.to_string());
-            event = event.add_attribute("liquidity_DAO_addr", LIQUIDITY_DAO_ADDRESS.to_string());
-            event = event.add_attribute("liquidity_DAO_coin", liquidity_dao_coin.to_string());
-            event = event.add_attribute("launchpad_DAO_addr", LAUNCHPAD_DAO_ADDRESS.to_string());
-            event = event.add_attribute("launchpad_DAO_coin", launchpad_dao_coin.to_string());
 
             res.messages.push(SubMsg::new(BankMsg::Send {
                 to_address: developer.to_string(),
                 amount: vec![dev_coin],
             }));
-            res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: LIQUIDITY_DAO_ADDRESS.to_string(),
-                amount: vec![liquidity_dao_coin],
-            }));
-            res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: LAUNCHPAD_DAO_ADDRESS.to_string(),
-                amount: vec![launchpad_dao_coin],
-            }));
+            coin(fee.amount.u128() - dev_fee, fee.denom)
         }
-        None => {
-            let liquidity_dao_fee = fee.amount.mul_ceil(liquidity_dao_percentage).u128();
-            let liquidity_dao_coin = coin(liquidity_dao_fee, fee.denom.to_string());
-            let launchpad_dao_coin = coin(fee.amount.u128() - liquidity_dao_fee, fee.denom);
-
-            event = event.add_attribute("liquidity_DAO_addr", LIQUIDITY_DAO_ADDRESS.to_string());
-            event = event.add_attribute("liquidity_DAO_coin", liquidity_dao_coin.to_string());
-            event = event.add_attribute("launchpad_DAO_addr", LAUNCHPAD_DAO_ADDRESS.to_string());
-            event = event.add_attribute("launchpad_DAO_coin", launchpad_dao_coin.to_string());
+        None => fee,
+    };
 
+    for (recipient, share) in
+        split_by_weight(&remaining_coin, routing.mint_fee_recipients(is_featured))
+    {
+        event = event.add_attribute(format!("{}_addr", recipient.label), &recipient.address);
+        event = event.add_attribute(format!("{}_coin", recipient.label), share.to_string());
+        // Sending 0 coins fails, so only send if the share is non-zero
+        if !share.amount.is_zero() {
             res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: LIQUIDITY_DAO_ADDRESS.to_string(),
-                amount: vec![liquidity_dao_coin],
-            }));
-            res.messages.push(SubMsg::new(BankMsg::Send {
-                to_address: LAUNCHPAD_DAO_ADDRESS.to_string(),
-                amount: vec![launchpad_dao_coin],
+                to_address: recipient.address,
+                amount: vec![share],
             }));
         }
     }
@@ -157,11 +135,17 @@ pub fn distribute_mint_fees(
 }
 
 /// Burn and distribute fees, assuming the right fee is passed in
-pub fn fair_burn(sender: String, fee: u128, developer: Option<Addr>, res: &mut Response) {
+pub fn fair_burn(
+    sender: String,
+    fee: u128,
+    developer: Option<Addr>,
+    res: &mut Response,
+    routing: &FeeRouting,
+) {
     let mut event = Event::new("fair-burn");
 
     // calculate the fair burn fee
-    let burn_fee = (Uint128::from(fee) * Decimal::percent(FEE_BURN_PERCENT)).u128();
+    let burn_fee = (Uint128::from(fee) * Decimal::percent(routing.burn_percent)).u128();
     let burn_coin = coins(burn_fee, NATIVE_DENOM);
     res.messages
         .push(SubMsg::new(BankMsg::Burn { amount: burn_coin }));
@@ -210,6 +194,7 @@ pub fn transfer_funds_to_launchpad_dao(
     fee: u128,
     accepted_denom: &str,
     res: &mut Response,
+    routing: &FeeRouting,
 ) -> Result<(), FeeError> {
     let payment = must_pay(info, accepted_denom)?;
     ensure!(
@@ -218,7 +203,7 @@ pub fn transfer_funds_to_launchpad_dao(
     );
 
     let msg = BankMsg::Send {
-        to_address: LAUNCHPAD_DAO_ADDRESS.to_string(),
+        to_address: routing.launchpad_dao.clone(),
         amount: vec![coin(payment.u128(), accepted_denom)],
     };
     res.messages.push(SubMsg::new(msg));
@@ -233,20 +218,33 @@ pub enum FeeError {
 
     #[error("{0}")]
     Payment(#[from] PaymentError),
+
+    #[error("Invalid fee routing: {0}")]
+    InvalidFeeRouting(String),
 }
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
     use cosmwasm_std::{coin, coins, Addr, BankMsg, Response};
     use sg_utils::NATIVE_DENOM;
 
-    use crate::{create_fund_fairburn_pool_msg, fair_burn, SubMsg};
+    use crate::{
+        create_fund_fairburn_pool_msg, distribute_mint_fees, fair_burn, split_by_weight, FeeError,
+        FeeRecipient, FeeRouting, SubMsg,
+    };
 
     #[test]
     fn check_fair_burn_no_dev_rewards() {
         let mut res = Response::new();
 
-        fair_burn(Addr::unchecked("sender").to_string(), 9u128, None, &mut res);
+        fair_burn(
+            Addr::unchecked("sender").to_string(),
+            9u128,
+            None,
+            &mut res,
+            &FeeRouting::default(),
+        );
         let burn_msg = SubMsg::new(BankMsg::Burn {
             amount: coins(4, "ustars".to_string()),
         });
@@ -268,6 +266,7 @@ mod tests {
             9u128,
             Some(Addr::unchecked("geordi")),
             &mut res,
+            &FeeRouting::default(),
         );
         let bank_msg = SubMsg::new(BankMsg::Send {
             to_address: "geordi".to_string(),
@@ -290,6 +289,7 @@ mod tests {
             1420u128,
             Some(Addr::unchecked("geordi")),
             &mut res,
+            &FeeRouting::default(),
         );
         let bank_msg = SubMsg::new(BankMsg::Send {
             to_address: "geordi".to_string(),
@@ -302,4 +302,67 @@ mod tests {
         assert_eq!(res.messages[0], burn_msg);
         assert_eq!(res.messages[1], bank_msg);
     }
+
+    #[test]
+    fn check_distribute_mint_fees_by_weight() {
+        let mut res = Response::new();
+        let routing = FeeRouting {
+            mint_fee_recipients: vec![
+                FeeRecipient {
+                    label: "a".to_string(),
+                    address: "a".to_string(),
+                    weight: 1,
+                },
+                FeeRecipient {
+                    label: "b".to_string(),
+                    address: "b".to_string(),
+                    weight: 2,
+                },
+            ],
+            ..FeeRouting::default()
+        };
+
+        distribute_mint_fees(coin(10, NATIVE_DENOM), &mut res, false, None, &routing).unwrap();
+        // Shares are rounded up, the last recipient gets the rest
+        let a_msg = SubMsg::new(BankMsg::Send {
+            to_address: "a".to_string(),
+            amount: coins(4, NATIVE_DENOM),
+        });
+        let b_msg = SubMsg::new(BankMsg::Send {
+            to_address: "b".to_string(),
+            amount: coins(6, NATIVE_DENOM),
+        });
+        assert_eq!(res.messages, vec![a_msg, b_msg]);
+    }
+
+    #[test]
+    fn check_fee_routing_total_weight_overflow() {
+        let recipients = vec![
+            FeeRecipient {
+                label: "a".to_string(),
+                address: "recipient_a".to_string(),
+                weight: u64::MAX,
+            },
+            FeeRecipient {
+                label: "b".to_string(),
+                address: "recipient_b".to_string(),
+                weight: 1,
+            },
+        ];
+        let routing = FeeRouting {
+            mint_fee_recipients: recipients.clone(),
+            ..FeeRouting::default()
+        };
+        let deps = mock_dependencies();
+        let err = routing.validate(&deps.api).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            FeeError::InvalidFeeRouting("total weight overflows".to_string()).to_string()
+        );
+
+        // Splitting sums weights without overflowing
+        let shares = split_by_weight(&coin(10, NATIVE_DENOM), &recipients);
+        assert_eq!(shares[0].1, coin(10, NATIVE_DENOM));
+        assert_eq!(shares[1].1, coin(0, NATIVE_DENOM));
+    }
 }
@@ -0,0 +1,190 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{coin, Api, Coin, QuerierWrapper, StdResult, Uint128};
+use serde::Deserialize;
+
+use crate::FeeError;
+
+const FEE_BURN_PERCENT: u64 = 50;
+const FOUNDATION: &str = "stars1xqz6xujjyz0r9uzn7srasle5uynmpa0zkjr5l8";
+const LAUNCHPAD_DAO_ADDRESS: &str =
+    "stars1huqk6ha02jgrm69lxh8xfgl6wch9wlg7s65ujxydwdr725cxvuus423tj0";
+const LIQUIDITY_DAO_ADDRESS: &str =
+    "stars12he2ldxl950wfypvelqwkac4mdul7clzgd8wdlnmjvll8z2cc47qsatvl2";
+
+/// A recipient of a weighted share of mint fees
+#[cw_serde]
+pub struct FeeRecipient {
+    /// Used to name the recipient's event attributes, e.g. `{label}_addr` and `{label}_coin`
+    pub label: String,
+    pub address: String,
+    pub weight: u64,
+}
+
+/// Where fees go, set by governance on the factory and read by its minters
+#[cw_serde]
+pub struct FeeRouting {
+    /// Percent of a fee that is burned, or paid to the developer when there is one
+    pub burn_percent: u64,
+    /// Receives fees in denoms that can't go through fair burn
+    pub foundation: String,
+    /// Receives creation fees paid in denoms other than the native denom
+    pub launchpad_dao: String,
+    /// Split of mint fees left after the developer's share
+    pub mint_fee_recipients: Vec<FeeRecipient>,
+    /// Split of mint fees left after the developer's share, for featured minters
+    pub featured_mint_fee_recipients: Vec<FeeRecipient>,
+}
+
+impl Default for FeeRouting {
+    fn default() -> Self {
+        let recipients = |liquidity_dao_weight, launchpad_dao_weight| {
+            vec![
+                FeeRecipient {
+                    label: "liquidity_DAO".to_string(),
+                    address: LIQUIDITY_DAO_ADDRESS.to_string(),
+                    weight: liquidity_dao_weight,
+                },
+                FeeRecipient {
+                    label: "launchpad_DAO".to_string(),
+                    address: LAUNCHPAD_DAO_ADDRESS.to_string(),
+                    weight: launchpad_dao_weight,
+                },
+            ]
+        };
+        FeeRouting {
+            burn_percent: FEE_BURN_PERCENT,
+            foundation: FOUNDATION.to_string(),
+            launchpad_dao: LAUNCHPAD_DAO_ADDRESS.to_string(),
+            // The liquidity DAO gets 1/5 of mint fees, or 1/8 for featured minters
+            mint_fee_recipients: recipients(1, 4),
+            featured_mint_fee_recipients: recipients(1, 7),
+        }
+    }
+}
+
+impl FeeRouting {
+    pub fn validate(&self, api: &dyn Api) -> Result<(), FeeError> {
+        if self.burn_percent > 100 {
+            return Err(FeeError::InvalidFeeRouting(
+                "burn percent can't be more than 100".to_string(),
+            ));
+        }
+        validate_address(api, &self.foundation)?;
+        validate_address(api, &self.launchpad_dao)?;
+        for recipients in [
+            &self.mint_fee_recipients,
+            &self.featured_mint_fee_recipients,
+        ] {
+            if recipients.is_empty() {
+                return Err(FeeError::InvalidFeeRouting(
+                    "mint fee recipients can't be empty".to_string(),
+                ));
+            }
+            let mut total_weight = 0u64;
+            for recipient in recipients.iter() {
+                if recipient.weight == 0 {
+                    return Err(FeeError::InvalidFeeRouting(format!(
+                        "weight of {} must be greater than zero",
+                        recipient.label
+                    )));
+                }
+                total_weight = total_weight.checked_add(recipient.weight).ok_or_else(|| {
+                    FeeError::InvalidFeeRouting("total weight overflows".to_string())
+                })?;
+                validate_address(api, &recipient.address)?;
+            }
+            if total_weight == 0 {
+                return Err(FeeError::InvalidFeeRouting(
+                    "total weight must be greater than zero".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn mint_fee_recipients(&self, is_featured: bool) -> &[FeeRecipient] {
+        if is_featured {
+            &self.featured_mint_fee_recipients
+        } else {
+            &self.mint_fee_recipients
+        }
+    }
+}
+
+#[cw_serde]
+enum FeeRoutingQueryMsg {
+    Config {},
+    Params {},
+}
+
+#[derive(Deserialize)]
+struct FactoryParams {
+    #[serde(default)]
+    fee_routing: FeeRouting,
+}
+
+#[derive(Deserialize)]
+struct FactoryParamsResponse {
+    params: FactoryParams,
+}
+
+/// Factory of a minter config, at the top level or nested like in base minters
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MinterFactoryResponse {
+    Flat { factory: String },
+    Nested { config: MinterFactory },
+}
+
+#[derive(Deserialize)]
+struct MinterFactory {
+    factory: String,
+}
+
+/// Fee routing in the params of a factory
+pub fn query_factory_fee_routing(
+    querier: &QuerierWrapper,
+    factory: impl Into<String>,
+) -> StdResult<FeeRouting> {
+    let res: FactoryParamsResponse =
+        querier.query_wasm_smart(factory, &FeeRoutingQueryMsg::Params {})?;
+    Ok(res.params.fee_routing)
+}
+
+/// Fee routing of the factory that created a minter
+pub fn query_minter_fee_routing(
+    querier: &QuerierWrapper,
+    minter: impl Into<String>,
+) -> StdResult<FeeRouting> {
+    let factory = match querier.query_wasm_smart(minter, &FeeRoutingQueryMsg::Config {})? {
+        MinterFactoryResponse::Flat { factory } => factory,
+        MinterFactoryResponse::Nested { config } => config.factory,
+    };
+    query_factory_fee_routing(querier, factory)
+}
+
+fn validate_address(api: &dyn Api, address: &str) -> Result<(), FeeError> {
+    api.addr_validate(address)
+        .map_err(|_| FeeError::InvalidFeeRouting(format!("invalid address {address}")))?;
+    Ok(())
+}
+
+/// Splits `fee` between `recipients` by weight. Shares are rounded up, and the last recipient
+/// gets whatever is left.
+pub fn split_by_weight(fee: &Coin, recipients: &[FeeRecipient]) -> Vec<(FeeRecipient, Coin)> {
+    let total_weight: Uint128 = recipients.iter().map(|r| Uint128::from(r.weight)).sum();
+    let mut remaining = fee.amount;
+    let mut shares = Vec::with_capacity(recipients.len());
+    for (i, recipient) in recipients.iter().enumerate() {
+        let share = if i == recipients.len() - 1 {
+            remaining
+        } else {
+            fee.amount
+                .mul_ceil((Uint128::from(recipient.weight), total_weight))
+                .min(remaining)
+        };
+        remaining -= share;
+        shares.push((recipient.clone(), coin(share.u128(), &fee.denom)));
+    }
+    shares
+}
@@ -0,0 +1,49 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, QuerierWrapper, StdResult, WasmMsg,
+};
+
+/// Query a splits contract answers, so payers can tell it apart from a plain account
+#[cw_serde]
+pub enum SplitsQueryMsg {
+    SplitsInfo {},
+}
+
+#[cw_serde]
+pub struct SplitsInfoResponse {
+    /// The cw4 group the deposits are split by
+    pub group: Addr,
+    /// Booked amounts per denom that trigger a distribution on deposit
+    pub distribution_threshold: Vec<Coin>,
+}
+
+/// Books the attached funds for the splits members
+#[cw_serde]
+pub enum SplitsExecuteMsg {
+    Deposit {},
+}
+
+/// Pays `amount` to `recipient`, as a splits deposit when the recipient is a splits contract
+pub fn payment_msg(
+    querier: &QuerierWrapper,
+    recipient: &Addr,
+    amount: Vec<Coin>,
+) -> StdResult<CosmosMsg> {
+    let is_splits = querier
+        .query_wasm_smart::<SplitsInfoResponse>(recipient, &SplitsQueryMsg::SplitsInfo {})
+        .is_ok();
+    if !is_splits {
+        return Ok(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount,
+        }
+        .into());
+    }
+
+    Ok(WasmMsg::Execute {
+        contract_addr: recipient.to_string(),
+        msg: to_json_binary(&SplitsExecuteMsg::Deposit {})?,
+        funds: amount,
+    }
+    .into())
+}
@@ -1,5 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Addr, Coin, QuerierWrapper, StdError, StdResult};
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use sg1::FeeRouting;
 
 pub mod msg;
 pub mod query;
@@ -7,6 +10,62 @@ pub mod tests;
 
 pub type CodeId = u64;
 
+/// Tiered whitelist stages minters accept when governance hasn't set a maximum
+pub const DEFAULT_MAX_WHITELIST_STAGES: u32 = 3;
+
+fn default_max_whitelist_stages() -> u32 {
+    DEFAULT_MAX_WHITELIST_STAGES
+}
+
+#[cw_serde]
+enum TieredWhitelistQueryMsg {
+    Stages {},
+}
+
+/// Stages of any tiered whitelist, only counted
+#[derive(Deserialize)]
+struct TieredWhitelistStagesResponse {
+    stages: Vec<IgnoredAny>,
+}
+
+/// Number of stages of a tiered whitelist, `None` for whitelists that don't answer the tiered
+/// whitelist `Stages` query
+fn whitelist_stages(querier: &QuerierWrapper, whitelist: &Addr) -> Option<usize> {
+    querier
+        .query_wasm_smart::<TieredWhitelistStagesResponse>(
+            whitelist,
+            &TieredWhitelistQueryMsg::Stages {},
+        )
+        .ok()
+        .map(|res| res.stages.len())
+}
+
+/// Whether a whitelist is a tiered whitelist
+pub fn is_tiered_whitelist(querier: &QuerierWrapper, whitelist: &Addr) -> bool {
+    whitelist_stages(querier, whitelist).is_some()
+}
+
+/// Checks a tiered whitelist has at most `max_whitelist_stages` stages, and returns whether the
+/// whitelist is tiered. Other whitelists have no stages to check.
+/// Minters call it once, when the whitelist is set.
+pub fn validate_whitelist_stages(
+    querier: &QuerierWrapper,
+    whitelist: &Addr,
+    max_whitelist_stages: u32,
+) -> StdResult<bool> {
+    let num_stages = match whitelist_stages(querier, whitelist) {
+        Some(num_stages) => num_stages,
+        None => return Ok(false),
+    };
+    if num_stages > max_whitelist_stages as usize {
+        return Err(StdError::generic_err(format!(
+            "Whitelist has {} stages, more than the maximum of {} whitelist stages",
+            num_stages, max_whitelist_stages
+        )));
+    }
+    Ok(true)
+}
+
 /// Common params for all minters used for storage
 #[cw_serde]
 pub struct MinterParams<T> {
@@ -16,7 +75,34 @@ pub struct MinterParams<T> {
     pub frozen: bool,
     pub creation_fee: Coin,
     pub min_mint_price: Coin,
+    /// Minimum mint prices for denoms accepted in addition to `min_mint_price`
+    #[serde(default)]
+    pub additional_min_mint_prices: Vec<Coin>,
     pub mint_fee_bps: u64,
     pub max_trading_offset_secs: u64,
+    /// Where minters send their fees
+    #[serde(default)]
+    pub fee_routing: FeeRouting,
+    /// Maximum number of tiered whitelist stages minters accept
+    #[serde(default = "default_max_whitelist_stages")]
+    pub max_whitelist_stages: u32,
+    /// Code ids of the splits contract and cw4 group the factory can create along with a minter
+    #[serde(default)]
+    pub splits_code_ids: Option<SplitsCodeIds>,
     pub extension: T,
 }
+
+#[cw_serde]
+pub struct SplitsCodeIds {
+    pub splits: CodeId,
+    pub group: CodeId,
+}
+
+impl<T> MinterParams<T> {
+    /// Minimum mint price for `denom`, if minting is allowed in that denom
+    pub fn min_mint_price_for(&self, denom: &str) -> Option<&Coin> {
+        std::iter::once(&self.min_mint_price)
+            .chain(self.additional_min_mint_prices.iter())
+            .find(|price| price.denom == denom)
+    }
+}
@@ -1,11 +1,29 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Coin;
+use cw4::Member;
+use sg1::FeeRouting;
 use sg721::{CollectionInfo, RoyaltyInfoResponse};
 
+use crate::SplitsCodeIds;
+
 #[cw_serde]
 pub struct CreateMinterMsg<T> {
     pub init_msg: T,
     pub collection_params: CollectionParams,
+    /// Creates a splits contract that receives the sale proceeds, in place of the payment address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub splits: Option<SplitsParams>,
+}
+
+/// Splits contract created along with a minter
+#[cw_serde]
+pub struct SplitsParams {
+    /// Members of the group the proceeds are split by. The group has no admin, so it can't change.
+    pub members: Vec<Member>,
+    /// Can distribute and update the distribution threshold. Any member can distribute when not set.
+    pub admin: Option<String>,
+    /// Booked amounts per denom at which a mint also distributes the proceeds
+    pub distribution_threshold: Option<Vec<Coin>>,
 }
 
 #[cw_serde]
@@ -27,8 +45,13 @@ pub struct UpdateMinterParamsMsg<T> {
     pub frozen: Option<bool>,
     pub creation_fee: Option<Coin>,
     pub min_mint_price: Option<Coin>,
+    pub additional_min_mint_prices: Option<Vec<Coin>>,
     pub mint_fee_bps: Option<u64>,
     pub max_trading_offset_secs: Option<u64>,
+    pub fee_routing: Option<FeeRouting>,
+    pub max_whitelist_stages: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub splits_code_ids: Option<SplitsCodeIds>,
     pub extension: T,
 }
 
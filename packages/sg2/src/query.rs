@@ -1,4 +1,5 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
 
 use crate::CodeId;
 use crate::MinterParams;
@@ -9,6 +10,21 @@ pub enum Sg2QueryMsg {
     Params {},
     AllowedCollectionCodeIds {},
     AllowedCollectionCodeId(CodeId),
+    /// Returns `MintersResponse`, all minters created by the factory
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns `MintersResponse`, all minters created by the given creator
+    MintersByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns `MinterResponse`, the minter of the given collection
+    MinterByCollection {
+        collection: String,
+    },
 }
 
 #[cw_serde]
@@ -25,3 +41,24 @@ pub struct AllowedCollectionCodeIdsResponse {
 pub struct AllowedCollectionCodeIdResponse {
     pub allowed: bool,
 }
+
+/// A minter created by a factory
+#[cw_serde]
+pub struct MinterInfo {
+    pub minter: Addr,
+    pub creator: Addr,
+    pub collection: Addr,
+    /// The minter code id
+    pub code_id: CodeId,
+    pub created_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<MinterInfo>,
+}
+
+#[cw_serde]
+pub struct MinterResponse {
+    pub minter: Option<MinterInfo>,
+}
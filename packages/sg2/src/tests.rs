@@ -15,6 +15,7 @@ pub fn mock_collection_params() -> CollectionParams {
             external_link: Some("https://example.com/external.html".to_string()),
             start_trading_time: None,
             explicit_content: Some(false),
+            enforce_start_trading_time: None,
             royalty_info: Some(RoyaltyInfoResponse {
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(10),
@@ -35,6 +36,7 @@ pub fn mock_collection_params_1(start_trading_time: Option<Timestamp>) -> Collec
             external_link: Some("https://example.com/external.html".to_string()),
             start_trading_time,
             explicit_content: Some(false),
+            enforce_start_trading_time: None,
             royalty_info: Some(RoyaltyInfoResponse {
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(10),
@@ -53,6 +55,7 @@ pub fn mock_curator_payment_address(start_trading_time: Option<Timestamp>) -> Co
             description: String::from("Stargaze Monkeys"),
             image: "https://example.com/image.png".to_string(),
             external_link: Some("https://example.com/external.html".to_string()),
+            enforce_start_trading_time: None,
             royalty_info: Some(RoyaltyInfoResponse {
                 payment_address: "curator".to_string(),
                 share: Decimal::percent(10),
@@ -75,6 +78,7 @@ pub fn mock_collection_params_high_fee(start_trading_time: Option<Timestamp>) ->
                 "ipfs://bafybeigi3bwpvyvsmnbj46ra4hyffcxdeaj6ntfk5jpic5mx27x6ih2qvq/images/1.png"
                     .to_string(),
             external_link: Some("https://example.com/external.html".to_string()),
+            enforce_start_trading_time: None,
             royalty_info: Some(RoyaltyInfoResponse {
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(100),
@@ -97,6 +101,7 @@ pub fn mock_collection_two(start_trading_time: Option<Timestamp>) -> CollectionP
                 "ipfs://bafybeigi3bwpvyvsmnbj46ra4hyffcxdeaj6ntfk5jpic5mx27x6ih2qvq/images/1.png"
                     .to_string(),
             external_link: Some("https://example.com/external.html".to_string()),
+            enforce_start_trading_time: None,
             royalty_info: Some(RoyaltyInfoResponse {
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(10),
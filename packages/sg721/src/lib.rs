@@ -3,6 +3,9 @@ use cosmwasm_std::{Addr, Binary, Decimal, Timestamp};
 use cw_ownable::cw_ownable_execute;
 use cw_utils::Expiration;
 
+/// One time fee a creator pays to enable token metadata updates on a collection
+pub const ENABLE_UPDATABLE_FEE: u128 = 1_500_000_000;
+
 #[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg<T, E> {
@@ -52,6 +55,10 @@ pub enum ExecuteMsg<T, E> {
         token_uri: Option<String>,
         /// Any custom extension used by this contract
         extension: T,
+        /// Royalty override for this token, the collection royalty applies when not set.
+        /// Only the base minter and vending and open edition admin mints set it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        royalty_info: Option<RoyaltyInfoResponse>,
     },
     /// Burn an NFT the sender has access to
     Burn {
@@ -65,6 +72,11 @@ pub enum ExecuteMsg<T, E> {
     UpdateCollectionInfo {
         collection_info: UpdateCollectionInfoMsg<RoyaltyInfoResponse>,
     },
+    /// Set or clear the royalty override of a token, only callable by the creator
+    UpdateTokenRoyaltyInfo {
+        token_id: String,
+        royalty_info: Option<RoyaltyInfoResponse>,
+    },
     /// Called by the minter to update trading start time
     UpdateStartTradingTime(Option<Timestamp>),
     // Freeze collection info from further updates
@@ -79,6 +91,9 @@ pub struct CollectionInfo<T> {
     pub external_link: Option<String>,
     pub explicit_content: Option<bool>,
     pub start_trading_time: Option<Timestamp>,
+    /// Reject transfers and sends before `start_trading_time`, except from the minter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_start_trading_time: Option<bool>,
     pub royalty_info: Option<T>,
 }
 
@@ -88,6 +103,8 @@ pub struct UpdateCollectionInfoMsg<T> {
     pub image: Option<String>,
     pub external_link: Option<Option<String>>,
     pub explicit_content: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_start_trading_time: Option<bool>,
     pub royalty_info: Option<Option<T>>,
     pub creator: Option<String>,
 }
@@ -1,5 +1,7 @@
-use crate::common_setup::contract_boxes::contract_sg721_base;
-use crate::common_setup::setup_accounts_and_block::{CREATION_FEE, INITIAL_BALANCE};
+use crate::common_setup::contract_boxes::{contract_sg721_base, App};
+use crate::common_setup::setup_accounts_and_block::{
+    setup_block_time, CREATION_FEE, INITIAL_BALANCE,
+};
 use crate::common_setup::setup_minter::base_minter::mock_params::mock_params;
 use crate::common_setup::setup_minter::common::constants::MIN_MINT_PRICE;
 use crate::common_setup::templates::{
@@ -8,16 +10,28 @@ use crate::common_setup::templates::{
 use base_factory::msg::{BaseMinterCreateMsg, BaseUpdateParamsMsg, SudoMsg};
 
 use base_minter::msg::{ConfigResponse, ExecuteMsg};
-use cosmwasm_std::{coin, coins, Addr, Timestamp};
-use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, OwnerOfResponse};
+use cosmwasm_std::{coin, coins, Addr, Decimal, Empty, Timestamp, Uint128};
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, NftInfoResponse, OwnerOfResponse};
 use cw_multi_test::Executor;
 use sg2::msg::Sg2ExecuteMsg;
 use sg2::query::{AllowedCollectionCodeIdsResponse, Sg2QueryMsg};
 use sg2::tests::mock_collection_params_1;
 use sg4::QueryMsg;
-use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use sg721::{ExecuteMsg as Sg721ExecuteMsg, RoyaltyInfoResponse, UpdateCollectionInfoMsg};
+use sg721_base::msg::{
+    CheckRoyaltiesResponse, CollectionInfoResponse, Cw2981QueryMsg, QueryMsg as Sg721QueryMsg,
+    RoyaltiesInfoResponse,
+};
+use sg721_nt::msg::{
+    ExecuteMsg as Sg721NtExecuteMsg, QueryMsg as Sg721NtQueryMsg, RevocationResponse,
+    SoulboundExtensionMsg, TokenExpirationResponse,
+};
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
+fn soulbound_msg(msg: SoulboundExtensionMsg) -> Sg721NtExecuteMsg<Empty, SoulboundExtensionMsg> {
+    Sg721NtExecuteMsg::Extension { msg }
+}
+
 #[test]
 fn init() {
     let bmt = base_minter_with_sg721nt(1);
@@ -44,8 +58,12 @@ fn update_code_id() {
         code_id: None,
         creation_fee: None,
         min_mint_price: None,
+        additional_min_mint_prices: None,
         mint_fee_bps: None,
         max_trading_offset_secs: None,
+        fee_routing: None,
+        max_whitelist_stages: None,
+        splits_code_ids: None,
         extension: None,
     };
     let sudo_msg = SudoMsg::UpdateParams(Box::new(update_msg));
@@ -72,6 +90,7 @@ fn update_code_id() {
     let mut msg = BaseMinterCreateMsg {
         init_msg: None,
         collection_params,
+        splits: None,
     };
     msg.collection_params.info.creator = creator.to_string();
     let creation_fee = coins(CREATION_FEE, NATIVE_DENOM);
@@ -97,6 +116,7 @@ fn check_mint() {
     // Fail with incorrect token uri
     let mint_msg = ExecuteMsg::Mint {
         token_uri: "test uri".to_string(),
+        royalty_info: None,
     };
     let err = router.execute_contract(creator.clone(), minter_addr.clone(), &mint_msg, &[]);
     assert!(err.is_err());
@@ -104,6 +124,7 @@ fn check_mint() {
     // Fail with incorrect mint price
     let mint_msg = ExecuteMsg::Mint {
         token_uri: "ipfs://example".to_string(),
+        royalty_info: None,
     };
     let err = router.execute_contract(
         creator.clone(),
@@ -116,6 +137,7 @@ fn check_mint() {
     // Not authorized to mint
     let mint_msg = ExecuteMsg::Mint {
         token_uri: "ipfs://example".to_string(),
+        royalty_info: None,
     };
     let err = router.execute_contract(
         buyer,
@@ -128,6 +150,7 @@ fn check_mint() {
     // Succeeds if funds are sent
     let mint_msg = ExecuteMsg::Mint {
         token_uri: "ipfs://example".to_string(),
+        royalty_info: None,
     };
     let res = router.execute_contract(
         creator.clone(),
@@ -225,3 +248,390 @@ fn update_start_trading_time() {
         .unwrap();
     assert_eq!(res.start_trading_time, Some(default_start_trading_time));
 }
+
+#[test]
+fn token_royalty_overrides() {
+    let bmt = base_minter_with_sg721(2);
+    let (mut router, creator, buyer) = (bmt.router, bmt.accts.creator, bmt.accts.buyer);
+    let minter_addr = bmt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = bmt.collection_response_vec[0].collection.clone().unwrap();
+    let collection_royalty = router
+        .wrap()
+        .query_wasm_smart::<CollectionInfoResponse>(
+            collection_addr.clone(),
+            &Sg721QueryMsg::CollectionInfo {},
+        )
+        .unwrap()
+        .royalty_info
+        .unwrap();
+
+    // Token 1 gets an 8% override, token 2 keeps the collection royalty
+    for royalty_info in [
+        Some(RoyaltyInfoResponse {
+            payment_address: "artist".to_string(),
+            share: Decimal::percent(8),
+        }),
+        None,
+    ] {
+        let mint_msg = ExecuteMsg::Mint {
+            token_uri: "ipfs://example".to_string(),
+            royalty_info,
+        };
+        router
+            .execute_contract(
+                creator.clone(),
+                minter_addr.clone(),
+                &mint_msg,
+                &[coin(MIN_MINT_PRICE, NATIVE_DENOM)],
+            )
+            .unwrap();
+    }
+
+    // Overrides can't go above the maximum share
+    let mint_msg = ExecuteMsg::Mint {
+        token_uri: "ipfs://example".to_string(),
+        royalty_info: Some(RoyaltyInfoResponse {
+            payment_address: "artist".to_string(),
+            share: Decimal::percent(11),
+        }),
+    };
+    let err = router
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &mint_msg,
+            &[coin(MIN_MINT_PRICE, NATIVE_DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "InvalidRoyalties: Share cannot be greater than 10%"
+    );
+
+    let royalty_info = |router: &App, token_id: &str| -> RoyaltiesInfoResponse {
+        router
+            .wrap()
+            .query_wasm_smart(
+                collection_addr.clone(),
+                &Sg721QueryMsg::Extension {
+                    msg: Cw2981QueryMsg::RoyaltyInfo {
+                        token_id: token_id.to_string(),
+                        sale_price: Uint128::new(1_000),
+                    },
+                },
+            )
+            .unwrap()
+    };
+    let res = royalty_info(&router, "1");
+    assert_eq!(res.address, "artist");
+    assert_eq!(res.royalty_amount, Uint128::new(80));
+    let res = royalty_info(&router, "2");
+    assert_eq!(res.address, collection_royalty.payment_address);
+    assert_eq!(
+        res.royalty_amount,
+        Uint128::new(1_000) * collection_royalty.share
+    );
+    let res: CheckRoyaltiesResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721QueryMsg::Extension {
+                msg: Cw2981QueryMsg::CheckRoyalties {},
+            },
+        )
+        .unwrap();
+    assert!(res.royalty_payments);
+
+    let update_msg = |share: Option<u64>| Sg721ExecuteMsg::<Empty, Empty>::UpdateTokenRoyaltyInfo {
+        token_id: "1".to_string(),
+        royalty_info: share.map(|share| RoyaltyInfoResponse {
+            payment_address: "artist".to_string(),
+            share: Decimal::percent(share),
+        }),
+    };
+
+    // Only the creator can update token royalties
+    let err = router
+        .execute_contract(buyer, collection_addr.clone(), &update_msg(Some(9)), &[])
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    // Overrides follow the same rules as collection royalty updates
+    let err = router
+        .execute_contract(
+            creator.clone(),
+            collection_addr.clone(),
+            &update_msg(Some(9)),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "InvalidRoyalties: Royalties can only be updated once per day"
+    );
+    let next_day = router.block_info().time.plus_seconds(24 * 60 * 60);
+    setup_block_time(&mut router, next_day.nanos(), None);
+    let err = router
+        .execute_contract(
+            creator.clone(),
+            collection_addr.clone(),
+            &update_msg(Some(11)),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "InvalidRoyalties: Share cannot be greater than 10%"
+    );
+    router
+        .execute_contract(
+            creator.clone(),
+            collection_addr.clone(),
+            &update_msg(Some(9)),
+            &[],
+        )
+        .unwrap();
+    assert_eq!(royalty_info(&router, "1").royalty_amount, Uint128::new(90));
+
+    // Clearing the override falls back to the collection royalty
+    let next_day = router.block_info().time.plus_seconds(24 * 60 * 60);
+    setup_block_time(&mut router, next_day.nanos(), None);
+    router
+        .execute_contract(creator, collection_addr.clone(), &update_msg(None), &[])
+        .unwrap();
+    assert_eq!(
+        royalty_info(&router, "1").address,
+        collection_royalty.payment_address
+    );
+}
+
+#[test]
+fn soulbound_revocation_recovery_and_expiry() {
+    let bmt = base_minter_with_sg721nt(1);
+    let (mut router, creator, buyer) = (bmt.router, bmt.accts.creator, bmt.accts.buyer);
+    let minter_addr = bmt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = bmt.collection_response_vec[0].collection.clone().unwrap();
+
+    for _ in 0..2 {
+        let mint_msg = ExecuteMsg::Mint {
+            token_uri: "ipfs://example".to_string(),
+            royalty_info: None,
+        };
+        let res = router.execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &mint_msg,
+            &[coin(MIN_MINT_PRICE, NATIVE_DENOM)],
+        );
+        assert!(res.is_ok());
+    }
+
+    // only the issuer can revoke, and the reason is kept
+    let revoke_msg = soulbound_msg(SoulboundExtensionMsg::RevokeToken {
+        token_id: "1".to_string(),
+        reason: "badge awarded by mistake".to_string(),
+    });
+    let res = router.execute_contract(buyer.clone(), collection_addr.clone(), &revoke_msg, &[]);
+    assert!(res.is_err());
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &revoke_msg, &[]);
+    assert!(res.is_ok());
+    let res: RevocationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721NtQueryMsg::Revocation {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    let revocation = res.revocation.unwrap();
+    assert_eq!(revocation.reason, "badge awarded by mistake");
+    assert_eq!(revocation.revoked_by, creator);
+    let res: Result<OwnerOfResponse, _> = router.wrap().query_wasm_smart(
+        collection_addr.clone(),
+        &Cw721QueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        },
+    );
+    assert!(res.is_err());
+
+    // recovery is off by default, then allowed once per token
+    let recover_msg = soulbound_msg(SoulboundExtensionMsg::RecoverToken {
+        token_id: "2".to_string(),
+        recipient: buyer.to_string(),
+    });
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &recover_msg, &[]);
+    assert!(res.is_err());
+    let res = router.execute_contract(
+        creator.clone(),
+        collection_addr.clone(),
+        &soulbound_msg(SoulboundExtensionMsg::UpdateSoulboundPolicy {
+            allow_recovery: true,
+        }),
+        &[],
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &recover_msg, &[]);
+    assert!(res.is_ok());
+    let res: OwnerOfResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Cw721QueryMsg::OwnerOf {
+                token_id: "2".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.owner, buyer.to_string());
+    let res = router.execute_contract(
+        creator.clone(),
+        collection_addr.clone(),
+        &soulbound_msg(SoulboundExtensionMsg::RecoverToken {
+            token_id: "2".to_string(),
+            recipient: creator.to_string(),
+        }),
+        &[],
+    );
+    assert!(res.is_err());
+
+    // expired tokens are reported as expired
+    let expires = router.block_info().time.plus_seconds(100);
+    let res = router.execute_contract(
+        creator,
+        collection_addr.clone(),
+        &soulbound_msg(SoulboundExtensionMsg::SetTokenExpiration {
+            token_id: "2".to_string(),
+            expires: Some(expires),
+        }),
+        &[],
+    );
+    assert!(res.is_ok());
+    setup_block_time(&mut router, expires.nanos(), None);
+    let res: TokenExpirationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721NtQueryMsg::TokenExpiration {
+                token_id: "2".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.expired);
+    let res: Result<NftInfoResponse<Empty>, _> = router.wrap().query_wasm_smart(
+        collection_addr.clone(),
+        &Cw721QueryMsg::NftInfo {
+            token_id: "2".to_string(),
+        },
+    );
+    assert!(res.unwrap_err().to_string().contains("Token 2 expired"));
+    // cw721's `include_expired` only covers approvals
+    let res: Result<OwnerOfResponse, _> = router.wrap().query_wasm_smart(
+        collection_addr.clone(),
+        &Cw721QueryMsg::OwnerOf {
+            token_id: "2".to_string(),
+            include_expired: Some(true),
+        },
+    );
+    assert!(res.unwrap_err().to_string().contains("Token 2 expired"));
+    let res: OwnerOfResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Sg721NtQueryMsg::OwnerOf {
+                token_id: "2".to_string(),
+                include_expired: None,
+                include_expired_token: Some(true),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.owner, buyer.to_string());
+}
+
+#[test]
+fn enforce_start_trading_time() {
+    let bmt = base_minter_with_sg721(1);
+    let (mut router, creator, buyer) = (bmt.router, bmt.accts.creator, bmt.accts.buyer);
+    let minter_addr = bmt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = bmt.collection_response_vec[0].collection.clone().unwrap();
+
+    let mint_msg = ExecuteMsg::Mint {
+        token_uri: "ipfs://example".to_string(),
+        royalty_info: None,
+    };
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &[coin(MIN_MINT_PRICE, NATIVE_DENOM)],
+    );
+    assert!(res.is_ok());
+
+    // not enforced by default
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: buyer.to_string(),
+        token_id: "1".to_string(),
+    };
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &transfer_msg, &[]);
+    assert!(res.is_ok());
+
+    let res = router.execute_contract(
+        creator,
+        collection_addr.clone(),
+        &Sg721ExecuteMsg::<Empty, Empty>::UpdateCollectionInfo {
+            collection_info: UpdateCollectionInfoMsg {
+                description: None,
+                image: None,
+                external_link: None,
+                explicit_content: None,
+                enforce_start_trading_time: Some(true),
+                royalty_info: None,
+                creator: None,
+            },
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+    let collection_info: CollectionInfoResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr.clone(), &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+    assert_eq!(collection_info.enforce_start_trading_time, Some(true));
+    let start_trading_time = collection_info.start_trading_time.unwrap();
+    assert!(router.block_info().time < start_trading_time);
+
+    // owners can't move tokens before trading starts
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: "otc_buyer".to_string(),
+        token_id: "1".to_string(),
+    };
+    let res = router.execute_contract(buyer.clone(), collection_addr.clone(), &transfer_msg, &[]);
+    assert_eq!(
+        res.unwrap_err().source().unwrap().to_string(),
+        sg721_base::ContractError::TradingNotStarted { start_trading_time }.to_string()
+    );
+
+    // the minter still can, e.g. for airdrops
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: minter_addr.to_string(),
+        token_id: "1".to_string(),
+        expires: None,
+    };
+    let res = router.execute_contract(buyer.clone(), collection_addr.clone(), &approve_msg, &[]);
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        minter_addr,
+        collection_addr.clone(),
+        &Cw721ExecuteMsg::TransferNft {
+            recipient: buyer.to_string(),
+            token_id: "1".to_string(),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    setup_block_time(&mut router, start_trading_time.nanos(), None);
+    let res = router.execute_contract(buyer, collection_addr, &transfer_msg, &[]);
+    assert!(res.is_ok());
+}
@@ -2,7 +2,9 @@ pub mod contract_boxes;
 pub mod contract_boxes_empty;
 pub mod helpers;
 pub mod keeper;
+pub mod mint_hooks;
 pub mod msg;
+pub mod randomness_beacon;
 pub mod setup_accounts_and_block;
 pub mod setup_collection_whitelist;
 pub mod setup_minter;
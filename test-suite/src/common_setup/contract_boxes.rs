@@ -28,7 +28,8 @@ pub fn contract_vending_factory() -> Box<dyn Contract<Empty>> {
         vending_factory::contract::instantiate,
         vending_factory::contract::query,
     )
-    .with_sudo(vending_factory::contract::sudo);
+    .with_sudo(vending_factory::contract::sudo)
+    .with_reply(vending_factory::contract::reply);
     Box::new(contract)
 }
 
@@ -38,7 +39,8 @@ pub fn contract_open_edition_factory() -> Box<dyn Contract<Empty>> {
         open_edition_factory::contract::instantiate,
         open_edition_factory::contract::query,
     )
-    .with_sudo(open_edition_factory::contract::sudo);
+    .with_sudo(open_edition_factory::contract::sudo)
+    .with_reply(open_edition_factory::contract::reply);
     Box::new(contract)
 }
 
@@ -48,7 +50,8 @@ pub fn contract_base_factory() -> Box<dyn Contract<Empty>> {
         base_factory::contract::instantiate,
         base_factory::contract::query,
     )
-    .with_sudo(base_factory::contract::sudo);
+    .with_sudo(base_factory::contract::sudo)
+    .with_reply(base_factory::contract::reply);
     Box::new(contract)
 }
 
@@ -124,7 +127,8 @@ pub fn contract_splits() -> Box<dyn Contract<Empty>> {
         sg_splits::contract::execute,
         sg_splits::contract::instantiate,
         sg_splits::contract::query,
-    );
+    )
+    .with_reply(sg_splits::contract::reply);
     Box::new(contract)
 }
 
@@ -147,6 +151,15 @@ pub fn contract_eth_airdrop() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn contract_whitelist_flex() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        sg_whitelist_flex::contract::execute,
+        sg_whitelist_flex::contract::instantiate,
+        sg_whitelist_flex::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn contract_whitelist_immutable() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         whitelist_immutable::contract::execute,
@@ -164,3 +177,21 @@ pub fn contract_whitelist_merkletree() -> Box<dyn Contract<Empty>> {
     );
     Box::new(contract)
 }
+
+pub fn contract_whitelist_token_gated() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        sg_whitelist_token_gated::contract::execute,
+        sg_whitelist_token_gated::contract::instantiate,
+        sg_whitelist_token_gated::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn contract_tiered_whitelist() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        sg_tiered_whitelist::contract::execute,
+        sg_tiered_whitelist::contract::instantiate,
+        sg_tiered_whitelist::contract::query,
+    );
+    Box::new(contract)
+}
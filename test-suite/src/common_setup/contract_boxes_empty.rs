@@ -10,12 +10,31 @@ pub fn contract_group() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn contract_cw20() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn contract_cw721() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw721_base::entry::execute,
+        cw721_base::entry::instantiate,
+        cw721_base::entry::query,
+    );
+    Box::new(contract)
+}
+
 pub fn contract_splits() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         sg_splits::contract::execute,
         sg_splits::contract::instantiate,
         sg_splits::contract::query,
     )
-    .with_reply(sg_splits::contract::reply);
+    .with_reply(sg_splits::contract::reply)
+    .with_migrate(sg_splits::contract::migrate);
     Box::new(contract)
 }
@@ -0,0 +1,60 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult};
+use cw_multi_test::{Contract, ContractWrapper};
+use sg_mint_hooks::post::PostMintHookMsg;
+use sg_mint_hooks::pre::PreMintHookMsg;
+
+const FAIL_KEY: &[u8] = b"fail";
+
+#[cw_serde]
+pub struct MockHookInstantiateMsg {
+    /// Makes every hook call fail, to test that mints are rolled back
+    pub fail: bool,
+}
+
+#[cw_serde]
+pub enum MockHookExecuteMsg {
+    PreMintHook(PreMintHookMsg),
+    PostMintHook(PostMintHookMsg),
+}
+
+fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockHookInstantiateMsg,
+) -> StdResult<Response> {
+    if msg.fail {
+        deps.storage.set(FAIL_KEY, &[1]);
+    }
+    Ok(Response::new())
+}
+
+fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockHookExecuteMsg,
+) -> StdResult<Response> {
+    if deps.storage.get(FAIL_KEY).is_some() {
+        return Err(StdError::generic_err("mint hook failed"));
+    }
+
+    let (action, hook_msg) = match msg {
+        MockHookExecuteMsg::PreMintHook(msg) => ("pre_mint_hook", (msg.token_id, msg.buyer)),
+        MockHookExecuteMsg::PostMintHook(msg) => ("post_mint_hook", (msg.token_id, msg.buyer)),
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("token_id", hook_msg.0.unwrap_or_default())
+        .add_attribute("buyer", hook_msg.1))
+}
+
+fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    Err(StdError::generic_err("not implemented"))
+}
+
+pub fn contract_mint_hook() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
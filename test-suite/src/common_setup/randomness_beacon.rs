@@ -0,0 +1,79 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Addr, Binary, Deps, DepsMut, Empty, Env, HexBinary,
+    MessageInfo, Response, StdError, StdResult, WasmMsg,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use vending_minter::msg::{ExecuteMsg as VendingMinterExecuteMsg, NoisCallback};
+
+const JOBS_KEY: &[u8] = b"jobs";
+
+/// Accepts the vending minter's `BeaconExecuteMsg` requests
+#[cw_serde]
+pub enum MockBeaconExecuteMsg {
+    GetNextRandomness {
+        job_id: String,
+    },
+    /// Delivers `randomness` to every job requested since the last publish
+    Publish {
+        randomness: HexBinary,
+    },
+}
+
+fn load_jobs(deps: Deps) -> StdResult<Vec<(Addr, String)>> {
+    match deps.storage.get(JOBS_KEY) {
+        Some(jobs) => from_json(jobs),
+        None => Ok(vec![]),
+    }
+}
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MockBeaconExecuteMsg,
+) -> StdResult<Response> {
+    let mut jobs = load_jobs(deps.as_ref())?;
+    match msg {
+        MockBeaconExecuteMsg::GetNextRandomness { job_id } => {
+            jobs.push((info.sender, job_id));
+            deps.storage.set(JOBS_KEY, &to_json_vec(&jobs)?);
+            Ok(Response::new().add_attribute("action", "get_next_randomness"))
+        }
+        MockBeaconExecuteMsg::Publish { randomness } => {
+            deps.storage.remove(JOBS_KEY);
+            let msgs = jobs
+                .into_iter()
+                .map(|(requester, job_id)| {
+                    Ok(WasmMsg::Execute {
+                        contract_addr: requester.to_string(),
+                        msg: to_json_binary(&VendingMinterExecuteMsg::NoisReceive {
+                            callback: NoisCallback {
+                                job_id,
+                                published: env.block.time,
+                                randomness: randomness.clone(),
+                            },
+                        })?,
+                        funds: vec![],
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(Response::new()
+                .add_attribute("action", "publish")
+                .add_messages(msgs))
+        }
+    }
+}
+
+fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    Err(StdError::generic_err("not implemented"))
+}
+
+pub fn contract_randomness_beacon() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
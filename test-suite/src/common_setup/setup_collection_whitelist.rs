@@ -37,6 +37,7 @@ pub fn setup_whitelist_contract(
         member_limit: 1000,
         admins: vec![creator.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     router
         .instantiate_contract(
@@ -69,6 +70,7 @@ pub fn setup_zero_fee_whitelist_contract(
         member_limit: 1000,
         admins: vec![creator.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     router
         .instantiate_contract(
@@ -14,8 +14,12 @@ pub fn mock_params() -> BaseMinterParams {
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: coin(MIN_MINT_PRICE, NATIVE_DENOM),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: MINT_FEE_BPS,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: None,
     }
 }
@@ -24,5 +28,6 @@ pub fn mock_create_minter(collection_params: CollectionParams) -> BaseMinterCrea
     BaseMinterCreateMsg {
         init_msg: None,
         collection_params,
+        splits: None,
     }
 }
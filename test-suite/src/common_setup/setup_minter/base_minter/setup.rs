@@ -109,8 +109,12 @@ pub fn sudo_update_params(
                     amount: MIN_MINT_PRICE.into(),
                     denom: NATIVE_DENOM.into(),
                 }),
+                additional_min_mint_prices: None,
                 mint_fee_bps: None,
                 max_trading_offset_secs: Some(100),
+                fee_routing: None,
+                max_whitelist_stages: None,
+                splits_code_ids: None,
                 extension: Empty {},
             },
         };
@@ -59,6 +59,7 @@ pub fn mock_create_minter(
             whitelist,
         ),
         collection_params,
+        splits: None,
     }
 }
 
@@ -69,6 +70,7 @@ pub fn mock_create_minter_init_msg(
     OpenEditionMinterCreateMsg {
         init_msg,
         collection_params,
+        splits: None,
     }
 }
 
@@ -79,8 +81,12 @@ pub fn mock_params_proper() -> OpenEditionMinterParams {
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: coin(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: MINT_FEE_FAIR_BURN,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: MAX_TOKEN_LIMIT,
             max_per_address_limit: 10,
@@ -106,8 +112,12 @@ pub fn mock_params_custom(custom_params: OpenEditionMinterCustomParams) -> OpenE
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: coin(MIN_MINT_PRICE_OPEN_EDITION, denom),
+        additional_min_mint_prices: vec![],
         mint_fee_bps,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: MAX_TOKEN_LIMIT,
             max_per_address_limit: 10,
@@ -131,8 +141,12 @@ pub fn mock_params_custom_min_mint_price(
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price,
+        additional_min_mint_prices: vec![],
         mint_fee_bps: MINT_FEE_FAIR_BURN,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: MAX_TOKEN_LIMIT,
             max_per_address_limit: 10,
@@ -23,6 +23,10 @@ pub fn mock_init_extension(
         mint_price: coin(MIN_MINT_PRICE, NATIVE_DENOM),
         per_address_limit: 3,
         whitelist: None,
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     }
 }
 
@@ -34,6 +38,7 @@ pub fn mock_create_minter(
     VendingMinterCreateMsg {
         init_msg: mock_init_extension(splits_addr, start_time),
         collection_params,
+        splits: None,
     }
 }
 
@@ -44,6 +49,7 @@ pub fn mock_create_minter_init_msg(
     VendingMinterCreateMsg {
         init_msg,
         collection_params,
+        splits: None,
     }
 }
 
@@ -57,8 +63,12 @@ pub fn mock_params(mint_denom: Option<String>) -> VendingMinterParams {
             MIN_MINT_PRICE,
             mint_denom.unwrap_or_else(|| NATIVE_DENOM.to_string()),
         ),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: MINT_FEE_FAIR_BURN,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: MAX_TOKEN_LIMIT,
             max_per_address_limit: MAX_PER_ADDRESS_LIMIT,
@@ -152,8 +152,12 @@ pub fn sudo_update_params(
                 frozen: None,
                 creation_fee: Some(coin(0, NATIVE_DENOM)),
                 min_mint_price: Some(coin(MIN_MINT_PRICE, NATIVE_DENOM)),
+                additional_min_mint_prices: None,
                 mint_fee_bps: None,
                 max_trading_offset_secs: Some(100),
+                fee_routing: None,
+                max_whitelist_stages: None,
+                splits_code_ids: None,
                 extension: VendingUpdateParamsExtension {
                     max_token_limit: None,
                     max_per_address_limit: None,
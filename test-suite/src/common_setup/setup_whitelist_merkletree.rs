@@ -33,6 +33,7 @@ pub fn setup_whitelist_mtree_contract(
         admins_mutable: true,
         merkle_root,
         merkle_tree_uri: None,
+        factory: None,
     };
     router
         .instantiate_contract(
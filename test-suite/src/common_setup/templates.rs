@@ -64,6 +64,10 @@ pub fn vending_minter_per_address_limit(
         mint_price: coin(MIN_MINT_PRICE, NATIVE_DENOM),
         per_address_limit,
         whitelist: Some("invalid address".to_string()),
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
@@ -99,6 +103,10 @@ pub fn vending_minter_with_ibc_asset(
         mint_price: coin(MINT_PRICE, denom),
         per_address_limit,
         whitelist: None,
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
@@ -35,8 +35,12 @@ mod tests {
                 code_id: None,
                 creation_fee: None,
                 min_mint_price: None,
+                additional_min_mint_prices: None,
                 mint_fee_bps: Some(2000),
                 max_trading_offset_secs: None,
+                fee_routing: None,
+                max_whitelist_stages: None,
+                splits_code_ids: None,
                 extension: OpenEditionUpdateParamsExtension {
                     max_token_limit: Some(9_999u32),
                     max_per_address_limit: None,
@@ -72,8 +72,12 @@ fn sudo_params_update_creation_fee() {
         frozen: None,
         creation_fee: Some(coin(999, NATIVE_DENOM)),
         min_mint_price: Some(coin(10, NATIVE_DENOM)),
+        additional_min_mint_prices: None,
         mint_fee_bps: None,
         max_trading_offset_secs: Some(100),
+        fee_routing: None,
+        max_whitelist_stages: None,
+        splits_code_ids: None,
         extension: OpenEditionUpdateParamsExtension {
             max_token_limit: None,
             min_mint_price: Some(coin(10, NATIVE_DENOM)),
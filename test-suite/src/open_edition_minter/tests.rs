@@ -1,9 +1,11 @@
 mod address_limit;
 mod allowed_code_ids;
+mod batch_mint;
 mod complete_mint_all_outcomes_validation;
 mod factory_create_minter;
 mod frozen_factory;
 mod ibc_asset_mint;
 mod max_tokens_limit;
+mod mint_hooks;
 mod update_mint_price;
 mod update_start_and_end_time;
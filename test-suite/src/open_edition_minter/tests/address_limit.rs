@@ -95,7 +95,7 @@ fn check_per_address_limit() {
 
     // Only the first 2 mints
     for _ in 1..=2 {
-        let mint_msg = ExecuteMsg::Mint {};
+        let mint_msg = ExecuteMsg::Mint { quantity: None };
         let res = router.execute_contract(
             buyer.clone(),
             minter_addr.clone(),
@@ -106,7 +106,7 @@ fn check_per_address_limit() {
     }
 
     // 3rd mint fails from exceeding per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
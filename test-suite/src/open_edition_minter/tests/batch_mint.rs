@@ -0,0 +1,112 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{
+    ExecuteMsg, MintableNumTokensResponse, QueryMsg, TotalMintCountResponse,
+};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn batch_mint() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(4),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(5),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    // Set to a valid mint time
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Batch larger than the per address limit fails
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(5) };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &coins(MINT_PRICE * 5, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+
+    // Batch of 3 mints sequential token ids for a single payment
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(3) };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens, vec!["1", "2", "3"]);
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+
+    // Batch larger than the remaining supply fails
+    let mint_to_msg = ExecuteMsg::MintTo {
+        recipient: buyer.to_string(),
+        quantity: Some(3),
+        royalty_info: None,
+    };
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &mint_to_msg,
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Invalid mint quantity 3, must be between 1 and 2"
+    );
+
+    let res: MintableNumTokensResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintableNumTokens {})
+        .unwrap();
+    assert_eq!(res.count, Some(2));
+}
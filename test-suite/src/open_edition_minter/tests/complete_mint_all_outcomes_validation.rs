@@ -121,7 +121,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     assert_eq!(res.count, 0u32);
 
     // Invalid price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -134,7 +134,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     );
 
     // Invalid price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -147,7 +147,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     );
 
     // Invalid price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer.clone(), minter_addr.clone(), &mint_msg, &[]);
     assert_eq!(
         res.err().unwrap().source().unwrap().to_string(),
@@ -164,7 +164,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
         }))
         .map_err(|err| println!("{err:?}"))
         .ok();
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -177,7 +177,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     );
 
     for _i in 1..=2 {
-        let mint_msg = ExecuteMsg::Mint {};
+        let mint_msg = ExecuteMsg::Mint { quantity: None };
         let res = router.execute_contract(
             buyer.clone(),
             minter_addr.clone(),
@@ -240,6 +240,8 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     // Creator mints an extra NFT for the buyer (who is a friend)
     let mint_to_msg = ExecuteMsg::MintTo {
         recipient: buyer.to_string(),
+        quantity: None,
+        royalty_info: None,
     };
     let res = router.execute_contract(
         creator.clone(),
@@ -311,7 +313,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
 
     // If time end has been reached, can't mint anymore
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1_000_000, None);
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -330,6 +332,8 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     // Try to execute admin only entry point from buyer
     let exec_msg = ExecuteMsg::MintTo {
         recipient: buyer.to_string(),
+        quantity: None,
+        royalty_info: None,
     };
     let res = router.execute_contract(buyer.clone(), minter_addr.clone(), &exec_msg, &[]);
     assert_eq!(
@@ -388,7 +392,7 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
     }
 
     // It should not be possible to mint anymore in both cases
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         creator.clone(),
         minter_addr.clone(),
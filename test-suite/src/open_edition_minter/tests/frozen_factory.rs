@@ -50,8 +50,12 @@ fn frozen_factory_cannot_create_new_minters() {
         code_id: None,
         creation_fee: None,
         min_mint_price: None,
+        additional_min_mint_prices: None,
         mint_fee_bps: None,
         max_trading_offset_secs: None,
+        fee_routing: None,
+        max_whitelist_stages: None,
+        splits_code_ids: None,
         extension: OpenEditionUpdateParamsExtension {
             max_token_limit: None,
             max_per_address_limit: None,
@@ -49,8 +49,12 @@ fn check_custom_create_minter_denom() {
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: init_msg.mint_price.clone(),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: MINT_FEE_FAIR_BURN,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: 10,
             max_per_address_limit: 10,
@@ -77,7 +81,7 @@ fn check_custom_create_minter_denom() {
 
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 100, None);
     //     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer.clone(), minter_addr, &mint_msg, &[mint_price.clone()]);
     assert!(res.is_ok());
 
@@ -129,8 +133,12 @@ fn one_hundred_percent_burned_ibc_minter() {
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: init_msg.mint_price.clone(),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: 10000,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: 10,
             max_per_address_limit: 10,
@@ -158,7 +166,7 @@ fn one_hundred_percent_burned_ibc_minter() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 100, None);
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer.clone(), minter_addr, &mint_msg, &[mint_price.clone()]);
     assert!(res.is_ok());
 
@@ -220,8 +228,12 @@ fn zero_mint_fee() {
         frozen: false,
         creation_fee: coin(CREATION_FEE, NATIVE_DENOM),
         min_mint_price: init_msg.mint_price.clone(),
+        additional_min_mint_prices: vec![],
         mint_fee_bps: 0,
         max_trading_offset_secs: 60 * 60 * 24 * 7,
+        fee_routing: sg1::FeeRouting::default(),
+        max_whitelist_stages: 3,
+        splits_code_ids: None,
         extension: ParamsExtension {
             max_token_limit: 10,
             max_per_address_limit: 10,
@@ -249,7 +261,7 @@ fn zero_mint_fee() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 100, None);
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer, minter_addr, &mint_msg, &[mint_price]);
     assert!(res.is_ok());
 }
@@ -73,7 +73,7 @@ fn check_max_tokens_limit_init() {
 
     // Only the first 2 mints
     for _ in 1..=2 {
-        let mint_msg = ExecuteMsg::Mint {};
+        let mint_msg = ExecuteMsg::Mint { quantity: None };
         let res = router.execute_contract(
             buyer.clone(),
             minter_addr.clone(),
@@ -83,7 +83,7 @@ fn check_max_tokens_limit_init() {
         assert!(res.is_ok());
     }
     // 3rd mint fails from exceeding num of tokens
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         creator,
         minter_addr,
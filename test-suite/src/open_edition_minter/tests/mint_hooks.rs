@@ -0,0 +1,172 @@
+use cosmwasm_std::{coins, Addr, Coin, Timestamp, Uint128};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_controllers::HooksResponse;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, TotalMintCountResponse};
+
+use crate::common_setup::mint_hooks::{contract_mint_hook, MockHookInstantiateMsg};
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn mint_hooks() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let code_id = router.store_code(contract_mint_hook());
+    let hook = router
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("hook_owner"),
+            &MockHookInstantiateMsg { fail: false },
+            &[],
+            "mint-hook",
+            None,
+        )
+        .unwrap();
+    let failing_hook = router
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("hook_owner"),
+            &MockHookInstantiateMsg { fail: true },
+            &[],
+            "failing-mint-hook",
+            None,
+        )
+        .unwrap();
+
+    // Only the admin can add hooks
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::AddPostMintHook {
+                hook: hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    router
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::AddPostMintHook {
+                hook: hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let res: HooksResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::PostMintHooks {})
+        .unwrap();
+    assert_eq!(res.hooks, vec![hook.to_string()]);
+
+    // The post-mint hook receives the minted token id and the buyer
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    let hook_event = res
+        .events
+        .iter()
+        .find(|e| {
+            e.ty == "wasm"
+                && e.attributes
+                    .iter()
+                    .any(|a| a.key == "_contract_address" && a.value == hook.as_str())
+        })
+        .unwrap();
+    let attr = |key: &str| {
+        hook_event
+            .attributes
+            .iter()
+            .find(|a| a.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(attr("action"), "post_mint_hook");
+    assert_eq!(attr("token_id"), "1");
+    assert_eq!(attr("buyer"), buyer.to_string());
+
+    // A failing post-mint hook rolls back the mint
+    router
+        .execute_contract(
+            creator,
+            minter_addr.clone(),
+            &ExecuteMsg::AddPostMintHook {
+                hook: failing_hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "post-mint hook failed");
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens, vec!["1"]);
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 1);
+}
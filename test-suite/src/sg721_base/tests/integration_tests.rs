@@ -152,6 +152,7 @@ mod tests {
                     external_link: None,
                     explicit_content: None,
                     start_trading_time: None,
+                    enforce_start_trading_time: None,
                     royalty_info: None,
                 },
             };
@@ -172,6 +173,23 @@ mod tests {
             let (_, _) = proper_instantiate();
         }
 
+        #[test]
+        fn unsupported_extension() {
+            let (mut app, contract) = proper_instantiate();
+
+            // the base collection has no extension messages and rejects them instead of aborting
+            let res = app.execute_contract(
+                Addr::unchecked("creator"),
+                contract,
+                &Sg721ExecuteMsg::<Empty, Empty>::Extension { msg: Empty {} },
+                &[],
+            );
+            assert_error(
+                res,
+                sg721_base::ContractError::UnsupportedExtension {}.to_string(),
+            );
+        }
+
         #[test]
         fn sanitize_base_token_uri() {
             let base_token_uri = " ipfs://somecidhere ".to_string();
@@ -284,6 +302,7 @@ mod tests {
                         image: None,
                         external_link: None,
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: Some(royalty_info),
                     },
                 },
@@ -328,6 +347,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: None,
                     },
                 },
@@ -350,6 +370,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: Some(royalty_info),
                     },
                 },
@@ -384,6 +405,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: Some(royalty_info),
                     },
                 },
@@ -412,6 +434,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: Some(royalty_info),
                     },
                 },
@@ -440,6 +463,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: Some(Some(royalty_info.clone())),
                     },
                 },
@@ -464,6 +488,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link.clone()),
                         explicit_content: Some(true),
+                        enforce_start_trading_time: None,
                         royalty_info: None,
                     },
                 },
@@ -506,6 +531,7 @@ mod tests {
                         image: Some(params.info.image.clone()),
                         external_link: Some(params.info.external_link),
                         explicit_content: None,
+                        enforce_start_trading_time: None,
                         royalty_info: None,
                     },
                 },
@@ -559,6 +585,7 @@ mod tests {
                     external_link: Some("https://example.com/external.html".to_string()),
                     start_trading_time: None,
                     explicit_content: Some(false),
+                    enforce_start_trading_time: None,
                     royalty_info: Some(RoyaltyInfoResponse {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(0),
@@ -600,6 +627,7 @@ mod tests {
                     external_link: Some("https://example.com/external.html".to_string()),
                     start_trading_time: None,
                     explicit_content: Some(false),
+                    enforce_start_trading_time: None,
                     royalty_info: Some(RoyaltyInfoResponse {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(91),
@@ -641,6 +669,7 @@ mod tests {
                     external_link: Some("https://example.com/external.html".to_string()),
                     start_trading_time: None,
                     explicit_content: Some(false),
+                    enforce_start_trading_time: None,
                     royalty_info: Some(RoyaltyInfoResponse {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(3),
@@ -683,6 +712,7 @@ mod tests {
                     external_link: Some("https://example.com/external.html".to_string()),
                     start_trading_time: None,
                     explicit_content: Some(false),
+                    enforce_start_trading_time: None,
                     royalty_info: None,
                 },
                 ..mock_collection_params()
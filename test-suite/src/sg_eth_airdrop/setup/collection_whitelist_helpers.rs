@@ -34,7 +34,7 @@ pub fn send_funds_to_address(app: &mut App, target_address_str: &str, amount: u1
 pub fn execute_mint_fail_not_on_whitelist(app: &mut App, minter_addr: Addr) {
     //before mintlist add, fail
     let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
-    let mint_msg = vending_minter::msg::ExecuteMsg::Mint {};
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
     let res = app.execute_contract(
         stargaze_wallet_01,
         minter_addr,
@@ -56,13 +56,15 @@ pub fn execute_airdrop_claim(
     let claim_message = sg_eth_airdrop::msg::ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str,
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let _ = execute_contract_with_msg(claim_message, app, target_wallet, airdrop_contract).unwrap();
 }
 
 pub fn execute_mint_success(app: &mut App, sender: Addr, minter_addr: Addr) {
     //execute the mint
-    let mint_msg = vending_minter::msg::ExecuteMsg::Mint {};
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
     let res = app.execute_contract(
         sender,
         minter_addr,
@@ -16,7 +16,7 @@ use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
 use crate::sg_eth_airdrop::setup::mock_whitelist_contract::mock_whitelist;
 
-fn configure_mock_minter(app: &mut App, creator: Addr) {
+pub fn configure_mock_minter(app: &mut App, creator: Addr) {
     let minter_code_id = app.store_code(mock_minter());
 
     println!("minter_code_id: {minter_code_id}");
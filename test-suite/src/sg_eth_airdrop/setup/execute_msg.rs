@@ -43,6 +43,11 @@ pub fn instantiate_contract(params: InstantiateParams) -> Result<cosmwasm_std::A
         whitelist_code_id,
         minter_address,
         per_address_limit,
+        merkle_root: None,
+        signature_type: None,
+        start_time: None,
+        end_time: None,
+        claim_action: None,
     };
     params.app.instantiate_contract(
         sg_eth_id,
@@ -54,6 +59,32 @@ pub fn instantiate_contract(params: InstantiateParams) -> Result<cosmwasm_std::A
     )
 }
 
+/// Instantiates the airdrop from a full message, funded by the admin
+pub fn instantiate_contract_with_msg(
+    app: &mut App,
+    msg: InstantiateMsg,
+    funds_amount: u128,
+) -> Result<cosmwasm_std::Addr, anyhow_error> {
+    app.sudo(SudoMsg::Bank({
+        BankSudo::Mint {
+            to_address: OWNER.to_string(),
+            amount: coins(funds_amount, NATIVE_DENOM),
+        }
+    }))
+    .map_err(|err| println!("{err:?}"))
+    .ok();
+
+    let sg_eth_id = app.store_code(contract_eth_airdrop());
+    app.instantiate_contract(
+        sg_eth_id,
+        Addr::unchecked(OWNER),
+        &msg,
+        &coins(funds_amount, NATIVE_DENOM),
+        "sg-eg-airdrop",
+        Some(OWNER.to_string()),
+    )
+}
+
 #[allow(clippy::result_large_err)]
 pub fn execute_contract_with_msg(
     msg: ExecuteMsg,
@@ -30,7 +30,8 @@ pub fn instantiate(
     _info: MessageInfo,
     _msg: VendingMinterCreateMsg,
 ) -> Result<Response, ContractError> {
-    let res = Response::new();
+    // Report the collection like a real minter does, so the factory can register it
+    let res = Response::new().add_attribute("sg721_address", query_config().sg721_address);
     Ok(res)
 }
 
@@ -62,7 +63,7 @@ fn query_config() -> ConfigResponse {
         sg721_code_id: 4,
         start_time: Timestamp::from_seconds(30),
         mint_price: Coin::new(1000, "ustars"),
-        factory: "some_factory".to_string(),
+        factory: "contract0".to_string(),
     }
 }
 
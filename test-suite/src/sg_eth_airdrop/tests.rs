@@ -1,3 +1,8 @@
 mod test_claim;
+mod test_claim_actions;
+mod test_claim_window;
 mod test_collection_whitelist;
+mod test_cross_chain_claim;
 mod test_immutable_whitelist;
+mod test_merkle_claim;
+mod test_typed_data_claim;
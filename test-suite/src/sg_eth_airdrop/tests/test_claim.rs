@@ -7,7 +7,7 @@ use crate::sg_eth_airdrop::setup::setup_signatures::{
 use crate::sg_eth_airdrop::setup::test_msgs::InstantiateParams;
 use async_std::task;
 use cosmwasm_std::{Addr, Attribute, Coin, Uint128};
-use sg_eth_airdrop::msg::{ExecuteMsg, QueryMsg};
+use sg_eth_airdrop::msg::{AirdropEligibleResponse, ExecuteMsg, QueryMsg};
 
 use ethers_core::rand::thread_rng;
 use ethers_signers::{LocalWallet, Signer};
@@ -35,13 +35,14 @@ fn query_minter_as_expected(app: &mut App, airdrop_contract: Addr, minter_addr:
 #[test]
 fn test_instantiate() {
     let mut app = custom_mock_app();
-    let minter_address = Addr::unchecked("contract1");
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let minter_address = Addr::unchecked(MOCK_MINTER_ADDR_STR);
     let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
     let (_, _, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
     let params = InstantiateParams {
         addresses: vec![eth_addr_str],
         funds_amount: WHITELIST_AMOUNT + INSTANTIATION_FEE,
-        expected_airdrop_contract_id: 1,
+        expected_airdrop_contract_id: 4,
         minter_address,
         admin_account: Addr::unchecked(OWNER),
         app: &mut app,
@@ -122,21 +123,23 @@ fn test_airdrop_eligible_query() {
     query_minter_as_expected(&mut app, airdrop_contract.clone(), minter_addr);
     let query_msg = QueryMsg::AirdropEligible {
         eth_address: eth_addr_str,
+        proof: None,
     };
-    let result: bool = app
+    let result: AirdropEligibleResponse = app
         .wrap()
         .query_wasm_smart(airdrop_contract.clone(), &query_msg)
         .unwrap();
-    assert!(result);
+    assert!(result.eligible);
 
     let query_msg = QueryMsg::AirdropEligible {
         eth_address: "0x-some-fake-address".to_string(),
+        proof: None,
     };
-    let result: bool = app
+    let result: AirdropEligibleResponse = app
         .wrap()
         .query_wasm_smart(airdrop_contract, &query_msg)
         .unwrap();
-    assert!(!result);
+    assert!(!result.eligible);
 }
 
 #[test]
@@ -163,6 +166,8 @@ fn test_valid_eth_sig_claim() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str,
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
 
@@ -212,6 +217,8 @@ fn test_invalid_eth_sig_claim() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str.clone(),
         eth_sig: eth_sig_str_2,
+        pub_key: None,
+        proof: None,
     };
     let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
     let res = execute_contract_error_with_msg(
@@ -248,6 +255,8 @@ fn test_can_not_claim_twice() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str.clone(),
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
     let res = execute_contract_with_msg(
@@ -312,6 +321,8 @@ fn test_claim_one_valid_airdrop() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str,
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let _ = execute_contract_with_msg(
         claim_message,
@@ -362,6 +373,8 @@ fn test_claim_twice_receive_funds_once() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str.clone(),
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let _ = execute_contract_with_msg(
         claim_message.clone(),
@@ -431,6 +444,8 @@ fn test_ineligible_does_not_receive_funds() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str_2.clone(),
         eth_sig: eth_sig_str_2,
+        pub_key: None,
+        proof: None,
     };
     let res = execute_contract_error_with_msg(
         claim_message,
@@ -479,6 +494,8 @@ fn test_one_eth_claim_two_stargaze_addresses_invalid() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str_1.clone(),
         eth_sig: eth_sig_str_1,
+        pub_key: None,
+        proof: None,
     };
     let res = execute_contract_with_msg(
         claim_message,
@@ -509,6 +526,8 @@ fn test_one_eth_claim_two_stargaze_addresses_invalid() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str_1.clone(),
         eth_sig: eth_sig_str_2,
+        pub_key: None,
+        proof: None,
     };
     let expected_error =
         format!("Address {eth_addr_str_1} has already claimed all available mints");
@@ -554,6 +573,8 @@ fn test_two_claims_allowed_success() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str.clone(),
         eth_sig: eth_sig_str.clone(),
+        pub_key: None,
+        proof: None,
     };
     let _ = execute_contract_with_msg(
         claim_message,
@@ -576,6 +597,8 @@ fn test_two_claims_allowed_success() {
     let claim_message = ExecuteMsg::ClaimAirdrop {
         eth_address: eth_addr_str,
         eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
     };
     let _ = execute_contract_with_msg(
         claim_message,
@@ -0,0 +1,369 @@
+use crate::common_setup::contract_boxes::{
+    contract_tiered_whitelist, contract_whitelist_flex, contract_whitelist_immutable,
+    custom_mock_app, App,
+};
+use crate::common_setup::msg::MinterCollectionResponse;
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::constants::{CREATION_FEE, DEV_ADDRESS};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg, minter_params_open_edition,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::{
+    configure_open_edition_minter, open_edition_minter_code_ids,
+};
+use crate::sg_eth_airdrop::constants::claim_constants::{
+    CONFIG_PLAINTEXT, MOCK_AIRDROP_ADDR_STR, MOCK_MINTER_ADDR_STR, NATIVE_DENOM, OWNER,
+    STARGAZE_WALLET_01,
+};
+use crate::sg_eth_airdrop::constants::collection_constants::WHITELIST_AMOUNT;
+use crate::sg_eth_airdrop::setup::collection_whitelist_helpers::send_funds_to_address;
+use crate::sg_eth_airdrop::setup::configure_mock_minter::configure_mock_minter;
+use crate::sg_eth_airdrop::setup::execute_msg::{
+    execute_contract_with_msg, instantiate_contract_with_msg,
+};
+use crate::sg_eth_airdrop::setup::setup_signatures::{get_msg_plaintext, get_wallet_and_sig};
+use anyhow::Error as anyhow_error;
+use cosmwasm_std::{coin, coins, Addr, Timestamp, Uint128};
+use cw721::TokensResponse;
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use open_edition_minter::msg::{MintPriceResponse, QueryMsg as OpenEditionMinterQueryMsg};
+use sg2::tests::mock_collection_params_1;
+use sg_eth_airdrop::contract::INSTANTIATION_FEE;
+use sg_eth_airdrop::msg::{ClaimAction, ClaimStatsResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use sg_tiered_whitelist::msg::{
+    AllStageMemberInfoResponse, InstantiateMsg as TieredWhitelistInstantiateMsg,
+    QueryMsg as TieredWhitelistQueryMsg,
+};
+use sg_tiered_whitelist::state::Stage;
+use sg_utils::GENESIS_MINT_START_TIME;
+use sg_whitelist_flex::msg::{
+    InstantiateMsg as WhitelistFlexInstantiateMsg, Member, QueryMsg as WhitelistFlexQueryMsg,
+};
+
+fn instantiate_with_claim_action(
+    app: &mut App,
+    eth_address: String,
+    per_address_limit: u32,
+    claim_action: ClaimAction,
+    funds_amount: u128,
+) -> Result<Addr, anyhow_error> {
+    let msg = InstantiateMsg {
+        admin: Addr::unchecked(OWNER),
+        claim_msg_plaintext: CONFIG_PLAINTEXT.to_string(),
+        airdrop_amount: WHITELIST_AMOUNT,
+        addresses: vec![eth_address],
+        whitelist_code_id: app.store_code(contract_whitelist_immutable()),
+        minter_address: Addr::unchecked(MOCK_MINTER_ADDR_STR),
+        per_address_limit,
+        merkle_root: None,
+        signature_type: None,
+        start_time: None,
+        end_time: None,
+        claim_action: Some(claim_action),
+    };
+    instantiate_contract_with_msg(app, msg, funds_amount)
+}
+
+/// Eligible Ethereum address and its claim for `STARGAZE_WALLET_01`
+fn eth_address_and_claim() -> (String, ExecuteMsg) {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    (eth_addr_str, claim_message)
+}
+
+#[test]
+fn test_invalid_claim_action() {
+    let (eth_address, _) = eth_address_and_claim();
+    let mut app = custom_mock_app();
+    let res = instantiate_with_claim_action(
+        &mut app,
+        eth_address,
+        1,
+        ClaimAction::MintTo { quantity: 0 },
+        INSTANTIATION_FEE,
+    )
+    .unwrap_err();
+    assert_eq!(
+        res.root_cause().to_string(),
+        "Claim actions must mint or allow at least one token"
+    );
+}
+
+#[test]
+fn test_send_tokens_claim() {
+    let (eth_address, claim_message) = eth_address_and_claim();
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    configure_mock_minter(&mut app, creator);
+    let airdrop_contract = instantiate_with_claim_action(
+        &mut app,
+        eth_address,
+        1,
+        ClaimAction::SendTokens,
+        INSTANTIATION_FEE + WHITELIST_AMOUNT,
+    )
+    .unwrap();
+
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract,
+    )
+    .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(WHITELIST_AMOUNT));
+}
+
+#[test]
+fn test_whitelist_flex_claim() {
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    configure_mock_minter(&mut app, creator.clone());
+
+    // The mock minter reports its whitelist at contract2
+    let whitelist_code_id = app.store_code(contract_whitelist_flex());
+    let whitelist_addr = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator,
+            &WhitelistFlexInstantiateMsg {
+                members: vec![],
+                start_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100),
+                end_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000_000),
+                mint_price: coin(1_000_000, NATIVE_DENOM),
+                member_limit: 1000,
+                admins: vec![MOCK_AIRDROP_ADDR_STR.to_string()],
+                admins_mutable: false,
+                whale_cap: None,
+                factory: None,
+            },
+            &coins(100_000_000, NATIVE_DENOM),
+            "whitelist-flex",
+            None,
+        )
+        .unwrap();
+    assert_eq!(whitelist_addr, Addr::unchecked("contract2"));
+
+    let (eth_address, claim_message) = eth_address_and_claim();
+    let airdrop_contract = instantiate_with_claim_action(
+        &mut app,
+        eth_address,
+        2,
+        ClaimAction::WhitelistFlexAdd { mint_count: 3 },
+        INSTANTIATION_FEE + 2 * WHITELIST_AMOUNT,
+    )
+    .unwrap();
+    assert_eq!(airdrop_contract, Addr::unchecked(MOCK_AIRDROP_ADDR_STR));
+
+    // The second claim of the same wallet only sends the airdrop
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    for claims in 1..=2 {
+        execute_contract_with_msg(
+            claim_message.clone(),
+            &mut app,
+            stargaze_wallet_01.clone(),
+            airdrop_contract.clone(),
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(stargaze_wallet_01.clone(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(claims * WHITELIST_AMOUNT));
+    }
+
+    let member: Member = app
+        .wrap()
+        .query_wasm_smart(
+            whitelist_addr,
+            &WhitelistFlexQueryMsg::Member {
+                member: STARGAZE_WALLET_01.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(member.mint_count, 3);
+}
+
+#[test]
+fn test_tiered_whitelist_claim() {
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    configure_mock_minter(&mut app, creator.clone());
+
+    // The mock minter reports its whitelist at contract2
+    let stages: Vec<Stage> = (0..2)
+        .map(|i| Stage {
+            name: format!("Stage {}", i + 1),
+            start_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100 + i * 10_000_000),
+            end_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + (i + 1) * 10_000_000),
+            mint_price: coin(1_000_000, NATIVE_DENOM),
+            per_address_limit: 2,
+            mint_count_limit: None,
+        })
+        .collect();
+    let whitelist_code_id = app.store_code(contract_tiered_whitelist());
+    let whitelist_addr = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator,
+            &TieredWhitelistInstantiateMsg {
+                members: vec![vec![], vec![]],
+                stages,
+                member_limit: 1000,
+                admins: vec![MOCK_AIRDROP_ADDR_STR.to_string()],
+                admins_mutable: false,
+                factory: None,
+            },
+            &coins(100_000_000, NATIVE_DENOM),
+            "tiered-whitelist",
+            None,
+        )
+        .unwrap();
+    assert_eq!(whitelist_addr, Addr::unchecked("contract2"));
+
+    let (eth_address, claim_message) = eth_address_and_claim();
+    let airdrop_contract = instantiate_with_claim_action(
+        &mut app,
+        eth_address,
+        2,
+        ClaimAction::TieredWhitelistAdd { stage_id: 1 },
+        INSTANTIATION_FEE + 2 * WHITELIST_AMOUNT,
+    )
+    .unwrap();
+    assert_eq!(airdrop_contract, Addr::unchecked(MOCK_AIRDROP_ADDR_STR));
+
+    // The second claim of the same wallet only sends the airdrop
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    for claims in 1..=2 {
+        execute_contract_with_msg(
+            claim_message.clone(),
+            &mut app,
+            stargaze_wallet_01.clone(),
+            airdrop_contract.clone(),
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(stargaze_wallet_01.clone(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(claims * WHITELIST_AMOUNT));
+    }
+
+    let res: AllStageMemberInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            whitelist_addr,
+            &TieredWhitelistQueryMsg::AllStageMemberInfo {
+                member: STARGAZE_WALLET_01.to_string(),
+            },
+        )
+        .unwrap();
+    let is_member: Vec<bool> = res
+        .all_stage_member_info
+        .iter()
+        .map(|info| info.is_member)
+        .collect();
+    assert_eq!(is_member, vec![false, true]);
+}
+
+#[test]
+fn test_mint_to_claim() {
+    let mut app = custom_mock_app();
+    let (_, _) = setup_accounts(&mut app);
+
+    // The airdrop contract, instantiated after the factory, minter and collection,
+    // is the creator and admin of the open edition minter
+    let airdrop_addr = Addr::unchecked(MOCK_AIRDROP_ADDR_STR);
+    send_funds_to_address(&mut app, MOCK_AIRDROP_ADDR_STR, CREATION_FEE);
+    let params_extension = ParamsExtension {
+        max_token_limit: 10_000,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: coin(100_000_000, NATIVE_DENOM),
+        dev_fee_address: DEV_ADDRESS.to_string(),
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(10),
+        None,
+        None,
+        Some(10),
+        None,
+        None,
+    );
+    let minter_params =
+        minter_params_open_edition(params_extension, init_msg, None, None, None, None, None);
+    let code_ids = open_edition_minter_code_ids(&mut app);
+    let minter_collection_response: Vec<MinterCollectionResponse> = configure_open_edition_minter(
+        &mut app,
+        airdrop_addr.clone(),
+        vec![mock_collection_params_1(None)],
+        vec![minter_params],
+        code_ids,
+    );
+    let minter_addr = minter_collection_response[0].minter.clone().unwrap();
+    let collection_addr = minter_collection_response[0].collection.clone().unwrap();
+    assert_eq!(minter_addr, Addr::unchecked(MOCK_MINTER_ADDR_STR));
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 101, None);
+    let mint_price: MintPriceResponse = app
+        .wrap()
+        .query_wasm_smart(minter_addr, &OpenEditionMinterQueryMsg::MintPrice {})
+        .unwrap();
+    let airdrop_price = mint_price.airdrop_price.amount.u128();
+
+    let (eth_address, claim_message) = eth_address_and_claim();
+    let airdrop_contract = instantiate_with_claim_action(
+        &mut app,
+        eth_address,
+        1,
+        ClaimAction::MintTo { quantity: 2 },
+        INSTANTIATION_FEE + 2 * airdrop_price,
+    )
+    .unwrap();
+    assert_eq!(airdrop_contract, airdrop_addr);
+
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+
+    let tokens: TokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &sg721_base::msg::QueryMsg::Tokens {
+                owner: STARGAZE_WALLET_01.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(tokens.tokens.len(), 2);
+    // The airdrop price is paid from the airdrop funds instead of sending tokens
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::zero());
+    let stats: ClaimStatsResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract, &QueryMsg::ClaimStats {})
+        .unwrap();
+    assert_eq!(stats.total_claimed, Uint128::new(2 * airdrop_price));
+}
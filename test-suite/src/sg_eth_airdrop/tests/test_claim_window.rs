@@ -0,0 +1,360 @@
+use crate::common_setup::contract_boxes::{contract_whitelist_immutable, custom_mock_app, App};
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::sg_eth_airdrop::constants::claim_constants::{
+    CONFIG_PLAINTEXT, MOCK_MINTER_ADDR_STR, NATIVE_DENOM, OWNER, STARGAZE_WALLET_01,
+    STARGAZE_WALLET_02,
+};
+use crate::sg_eth_airdrop::constants::collection_constants::WHITELIST_AMOUNT;
+use crate::sg_eth_airdrop::setup::collection_whitelist_helpers::send_funds_to_address;
+use crate::sg_eth_airdrop::setup::configure_mock_minter::configure_mock_minter_with_mock_whitelist;
+use crate::sg_eth_airdrop::setup::execute_msg::{
+    execute_contract_error_with_msg, execute_contract_with_msg, instantiate_contract_with_msg,
+};
+use crate::sg_eth_airdrop::setup::setup_signatures::{get_msg_plaintext, get_wallet_and_sig};
+use anyhow::Error as anyhow_error;
+use cosmwasm_std::{coins, Addr, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use sg_eth_airdrop::contract::INSTANTIATION_FEE;
+use sg_eth_airdrop::msg::{ClaimStatsResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn instantiate_with_window(
+    app: &mut App,
+    addresses: Vec<String>,
+    start_time: Option<Timestamp>,
+    end_time: Option<Timestamp>,
+) -> Result<Addr, anyhow_error> {
+    let msg = InstantiateMsg {
+        admin: Addr::unchecked(OWNER),
+        claim_msg_plaintext: CONFIG_PLAINTEXT.to_string(),
+        airdrop_amount: WHITELIST_AMOUNT,
+        addresses,
+        whitelist_code_id: app.store_code(contract_whitelist_immutable()),
+        minter_address: Addr::unchecked(MOCK_MINTER_ADDR_STR),
+        per_address_limit: 1,
+        merkle_root: None,
+        signature_type: None,
+        start_time,
+        end_time,
+        claim_action: None,
+    };
+    instantiate_contract_with_msg(app, msg, INSTANTIATION_FEE + WHITELIST_AMOUNT)
+}
+
+fn query_stats(app: &App, airdrop_contract: &Addr) -> ClaimStatsResponse {
+    app.wrap()
+        .query_wasm_smart(airdrop_contract, &QueryMsg::ClaimStats {})
+        .unwrap()
+}
+
+#[test]
+fn test_invalid_claim_window() {
+    let mut app = custom_mock_app();
+    let now = app.block_info().time;
+
+    let res = instantiate_with_window(
+        &mut app,
+        vec!["addr1".to_string()],
+        Some(now.plus_seconds(100)),
+        Some(now.plus_seconds(100)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        res.root_cause().to_string(),
+        "Claim start time must be before its end time, and the end time in the future"
+    );
+
+    let res =
+        instantiate_with_window(&mut app, vec!["addr1".to_string()], None, Some(now)).unwrap_err();
+    assert_eq!(
+        res.root_cause().to_string(),
+        "Claim start time must be before its end time, and the end time in the future"
+    );
+}
+
+#[test]
+fn test_claim_window_top_up_and_withdraw() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let now = app.block_info().time;
+    let start_time = now.plus_seconds(100);
+    let end_time = now.plus_seconds(1000);
+    let airdrop_contract = instantiate_with_window(
+        &mut app,
+        vec![eth_addr_str.clone()],
+        Some(start_time),
+        Some(end_time),
+    )
+    .unwrap();
+
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.remaining_budget, Uint128::new(WHITELIST_AMOUNT));
+    assert_eq!(stats.total_claimed, Uint128::zero());
+    assert_eq!(stats.claimers, 0);
+
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str,
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message.clone(),
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, "Airdrop claims have not started");
+
+    setup_block_time(&mut app, start_time.nanos(), None);
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01,
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.remaining_budget, Uint128::zero());
+    assert_eq!(stats.total_claimed, Uint128::new(WHITELIST_AMOUNT));
+    assert_eq!(stats.claimers, 1);
+
+    // Anyone can add funds to the airdrop
+    let funder = Addr::unchecked(STARGAZE_WALLET_02);
+    send_funds_to_address(&mut app, STARGAZE_WALLET_02, 3 * WHITELIST_AMOUNT);
+    app.execute_contract(
+        funder.clone(),
+        airdrop_contract.clone(),
+        &ExecuteMsg::TopUp {},
+        &coins(3 * WHITELIST_AMOUNT, NATIVE_DENOM),
+    )
+    .unwrap();
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.remaining_budget, Uint128::new(3 * WHITELIST_AMOUNT));
+
+    let withdraw_msg = ExecuteMsg::WithdrawUnclaimed { recipient: None };
+    let res = execute_contract_error_with_msg(
+        withdraw_msg.clone(),
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        "Unclaimed funds can only be withdrawn after the claim end time"
+    );
+
+    setup_block_time(&mut app, end_time.nanos(), None);
+    let res = execute_contract_error_with_msg(
+        withdraw_msg.clone(),
+        &mut app,
+        funder.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        format!("Unauthorized admin, sender is {STARGAZE_WALLET_02}")
+    );
+
+    execute_contract_with_msg(
+        withdraw_msg.clone(),
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+    let balance = app.wrap().query_balance(OWNER, NATIVE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(3 * WHITELIST_AMOUNT));
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.remaining_budget, Uint128::zero());
+    assert_eq!(stats.total_claimed, Uint128::new(WHITELIST_AMOUNT));
+
+    let res = execute_contract_error_with_msg(
+        withdraw_msg,
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract,
+    );
+    assert_eq!(res, "Contract has no funds");
+}
+
+#[test]
+fn test_claim_after_end_time() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let end_time = app.block_info().time.plus_seconds(1000);
+    let airdrop_contract =
+        instantiate_with_window(&mut app, vec![eth_addr_str.clone()], None, Some(end_time))
+            .unwrap();
+
+    setup_block_time(&mut app, end_time.nanos(), None);
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str,
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract,
+    );
+    assert_eq!(res, "Airdrop claims have ended");
+}
+
+#[test]
+fn test_update_claim_window() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let now = app.block_info().time;
+    let end_time = now.plus_seconds(1000);
+    let airdrop_contract =
+        instantiate_with_window(&mut app, vec![eth_addr_str.clone()], None, Some(end_time))
+            .unwrap();
+
+    let update_msg = ExecuteMsg::UpdateClaimWindow {
+        start_time: Some(now.plus_seconds(100)),
+        end_time: Some(now.plus_seconds(2000)),
+    };
+    let res = execute_contract_error_with_msg(
+        update_msg.clone(),
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        format!("Unauthorized admin, sender is {STARGAZE_WALLET_01}")
+    );
+
+    let res = execute_contract_error_with_msg(
+        ExecuteMsg::UpdateClaimWindow {
+            start_time: None,
+            end_time: Some(now),
+        },
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        "Claim start time must be before its end time, and the end time in the future"
+    );
+
+    execute_contract_with_msg(
+        update_msg,
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.start_time, Some(now.plus_seconds(100)));
+    assert_eq!(stats.end_time, Some(now.plus_seconds(2000)));
+
+    // Claims are open until the new end time
+    setup_block_time(&mut app, end_time.nanos(), None);
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str,
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+
+    // The window can't be reopened once it ended
+    setup_block_time(&mut app, now.plus_seconds(2000).nanos(), None);
+    let res = execute_contract_error_with_msg(
+        ExecuteMsg::UpdateClaimWindow {
+            start_time: None,
+            end_time: Some(now.plus_seconds(3000)),
+        },
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract,
+    );
+    assert_eq!(res, "Airdrop claims have ended");
+}
+
+#[test]
+fn test_add_eligible_addresses() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let airdrop_contract =
+        instantiate_with_window(&mut app, vec!["addr1".to_string()], None, None).unwrap();
+
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message.clone(),
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, format!("Address {eth_addr_str} is not eligible"));
+
+    let add_msg = ExecuteMsg::AddEligibleAddresses {
+        addresses: vec![eth_addr_str],
+    };
+    let res = execute_contract_error_with_msg(
+        add_msg.clone(),
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        format!("Unauthorized admin, sender is {STARGAZE_WALLET_01}")
+    );
+    execute_contract_with_msg(
+        add_msg,
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+    let stats = query_stats(&app, &airdrop_contract);
+    assert_eq!(stats.claimers, 1);
+
+    // Airdrops without a merkle root have no root to update
+    let res = execute_contract_error_with_msg(
+        ExecuteMsg::UpdateMerkleRoot {
+            merkle_root: "0".repeat(64),
+        },
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract,
+    );
+    assert_eq!(res, "Airdrop does not use a merkle root");
+}
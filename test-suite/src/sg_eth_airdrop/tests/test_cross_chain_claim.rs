@@ -0,0 +1,173 @@
+use crate::common_setup::contract_boxes::{contract_whitelist_immutable, custom_mock_app, App};
+use crate::sg_eth_airdrop::constants::claim_constants::{
+    CONFIG_PLAINTEXT, MOCK_MINTER_ADDR_STR, NATIVE_DENOM, OWNER, STARGAZE_WALLET_01,
+};
+use crate::sg_eth_airdrop::constants::collection_constants::WHITELIST_AMOUNT;
+use crate::sg_eth_airdrop::setup::configure_mock_minter::configure_mock_minter_with_mock_whitelist;
+use crate::sg_eth_airdrop::setup::execute_msg::{
+    execute_contract_error_with_msg, execute_contract_with_msg, instantiate_contract_with_msg,
+};
+use crate::sg_eth_airdrop::setup::setup_signatures::get_msg_plaintext;
+use claim_verify::cosmos_address;
+use cosmwasm_std::{Addr, Binary, HexBinary, Uint128};
+use ed25519_zebra::{SigningKey as Ed25519SigningKey, VerificationKey};
+use ethers_core::k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use ethers_core::k256::elliptic_curve::sec1::ToEncodedPoint;
+use ethers_core::rand::thread_rng;
+use sg_eth_airdrop::contract::INSTANTIATION_FEE;
+use sg_eth_airdrop::msg::{ExecuteMsg, InstantiateMsg, SignatureType};
+
+fn instantiate_airdrop(app: &mut App, address: String, signature_type: SignatureType) -> Addr {
+    configure_mock_minter_with_mock_whitelist(app);
+    let msg = InstantiateMsg {
+        admin: Addr::unchecked(OWNER),
+        claim_msg_plaintext: CONFIG_PLAINTEXT.to_string(),
+        airdrop_amount: WHITELIST_AMOUNT,
+        addresses: vec![address],
+        whitelist_code_id: app.store_code(contract_whitelist_immutable()),
+        minter_address: Addr::unchecked(MOCK_MINTER_ADDR_STR),
+        per_address_limit: 1,
+        merkle_root: None,
+        signature_type: Some(signature_type),
+        start_time: None,
+        end_time: None,
+        claim_action: None,
+    };
+    instantiate_contract_with_msg(app, msg, INSTANTIATION_FEE + WHITELIST_AMOUNT).unwrap()
+}
+
+/// Amino JSON sign doc of a Keplr `signArbitrary` signature
+fn adr036_sign_doc(signer: &str, data: &str) -> String {
+    let data = Binary::from(data.as_bytes()).to_base64();
+    format!(
+        "{{\"account_number\":\"0\",\"chain_id\":\"\",\"fee\":{{\"amount\":[],\"gas\":\"0\"}},\
+         \"memo\":\"\",\"msgs\":[{{\"type\":\"sign/MsgSignData\",\"value\":{{\"data\":\"{data}\",\
+         \"signer\":\"{signer}\"}}}}],\"sequence\":\"0\"}}"
+    )
+}
+
+fn sign_arbitrary(key: &SigningKey, signer: &str, data: &str) -> String {
+    let signature: Signature = key.sign(adr036_sign_doc(signer, data).as_bytes());
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Binary::from(signature.as_ref()).to_base64()
+}
+
+fn compressed_pub_key(key: &SigningKey) -> Vec<u8> {
+    key.verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec()
+}
+
+#[test]
+fn test_adr036_claim() {
+    let key = SigningKey::random(&mut thread_rng());
+    let pub_key = compressed_pub_key(&key);
+    let osmo_address = cosmos_address(&pub_key, "osmo").unwrap();
+    assert!(osmo_address.starts_with("osmo1"));
+
+    let mut app = custom_mock_app();
+    let airdrop_contract =
+        instantiate_airdrop(&mut app, osmo_address.clone(), SignatureType::Adr036);
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    let claim_plaintext = get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let eth_sig = sign_arbitrary(&key, &osmo_address, &claim_plaintext);
+
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: osmo_address.clone(),
+        eth_sig: eth_sig.clone(),
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, "A public key is required to verify ADR-036 signatures");
+
+    // The public key must be the one of the eligible address
+    let other_key = SigningKey::random(&mut thread_rng());
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: osmo_address.clone(),
+        eth_sig: sign_arbitrary(&other_key, &osmo_address, &claim_plaintext),
+        pub_key: Some(Binary::from(compressed_pub_key(&other_key)).to_base64()),
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, format!("Address {osmo_address} is not eligible"));
+
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: osmo_address,
+        eth_sig,
+        pub_key: Some(Binary::from(pub_key).to_base64()),
+        proof: None,
+    };
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract,
+    )
+    .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(WHITELIST_AMOUNT));
+}
+
+#[test]
+fn test_solana_claim() {
+    let key = Ed25519SigningKey::new(thread_rng());
+    let solana_address = bs58::encode(VerificationKey::from(&key)).into_string();
+
+    let mut app = custom_mock_app();
+    let airdrop_contract =
+        instantiate_airdrop(&mut app, solana_address.clone(), SignatureType::Solana);
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+    let claim_plaintext = get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+
+    // Signed by another key
+    let other_key = Ed25519SigningKey::new(thread_rng());
+    let signature: [u8; 64] = other_key.sign(claim_plaintext.as_bytes()).into();
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: solana_address.clone(),
+        eth_sig: HexBinary::from(signature).to_hex(),
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, format!("Address {solana_address} is not eligible"));
+
+    let signature: [u8; 64] = key.sign(claim_plaintext.as_bytes()).into();
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: solana_address,
+        eth_sig: HexBinary::from(signature).to_hex(),
+        pub_key: None,
+        proof: None,
+    };
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract,
+    )
+    .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(WHITELIST_AMOUNT));
+}
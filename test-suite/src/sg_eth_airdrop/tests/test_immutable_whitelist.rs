@@ -9,7 +9,7 @@ use crate::sg_eth_airdrop::setup::test_msgs::InstantiateParams;
 
 use cosmwasm_std::Addr;
 use sg_eth_airdrop::contract::INSTANTIATION_FEE;
-use sg_eth_airdrop::msg::QueryMsg;
+use sg_eth_airdrop::msg::{AirdropEligibleResponse, QueryMsg};
 use whitelist_immutable::helpers::WhitelistImmutableContract;
 
 #[test]
@@ -39,21 +39,23 @@ fn test_instantiate_with_addresses() {
 
     let query_msg = QueryMsg::AirdropEligible {
         eth_address: "addr1".to_string(),
+        proof: None,
     };
-    let result: bool = app
+    let result: AirdropEligibleResponse = app
         .wrap()
         .query_wasm_smart(airdrop_contract.clone(), &query_msg)
         .unwrap();
-    assert!(result);
+    assert!(result.eligible);
 
     let query_msg = QueryMsg::AirdropEligible {
         eth_address: "lies".to_string(),
+        proof: None,
     };
-    let result: bool = app
+    let result: AirdropEligibleResponse = app
         .wrap()
         .query_wasm_smart(airdrop_contract, &query_msg)
         .unwrap();
-    assert!(!result);
+    assert!(!result.eligible);
 }
 
 #[test]
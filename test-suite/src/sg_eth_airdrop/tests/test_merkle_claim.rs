@@ -0,0 +1,305 @@
+use crate::common_setup::contract_boxes::{custom_mock_app, App};
+use crate::sg_eth_airdrop::constants::claim_constants::{
+    CONFIG_PLAINTEXT, MOCK_AIRDROP_ADDR_STR, MOCK_MINTER_ADDR_STR, NATIVE_DENOM, OWNER,
+    STARGAZE_WALLET_01,
+};
+use crate::sg_eth_airdrop::constants::collection_constants::WHITELIST_AMOUNT;
+use crate::sg_eth_airdrop::setup::configure_mock_minter::configure_mock_minter_with_mock_whitelist;
+use crate::sg_eth_airdrop::setup::execute_msg::{
+    execute_contract_error_with_msg, execute_contract_with_msg, instantiate_contract_with_msg,
+};
+use crate::sg_eth_airdrop::setup::setup_signatures::{get_msg_plaintext, get_wallet_and_sig};
+use anyhow::Error as anyhow_error;
+use cosmwasm_std::{Addr, Uint128};
+use rs_merkle::MerkleTree;
+use sg_eth_airdrop::contract::INSTANTIATION_FEE;
+use sg_eth_airdrop::msg::{
+    AirdropEligibleResponse, Allocation, ExecuteMsg, InstantiateMsg, MerkleProof, QueryMsg,
+};
+use whitelist_mtree::tests::{hasher::SortingSha256Hasher, test_helpers::hash_and_build_tree};
+
+const AIRDROP_AMOUNT: u128 = 20_000_000;
+
+fn leaf(eth_address: &str, allocation: &Allocation) -> String {
+    format!(
+        "{}:{}:{}",
+        eth_address.to_lowercase(),
+        allocation.per_address_limit,
+        allocation.airdrop_amount
+    )
+}
+
+fn build_tree(eth_address: &str, allocation: &Allocation) -> MerkleTree<SortingSha256Hasher> {
+    let other = Allocation {
+        per_address_limit: 1,
+        airdrop_amount: WHITELIST_AMOUNT,
+    };
+    hash_and_build_tree(&[
+        leaf("0x0000000000000000000000000000000000000001", &other),
+        leaf(eth_address, allocation),
+        leaf("0x0000000000000000000000000000000000000002", &other),
+    ])
+}
+
+fn instantiate_with_merkle_root(
+    app: &mut App,
+    merkle_root: String,
+    addresses: Vec<String>,
+    funds_amount: u128,
+) -> Result<Addr, anyhow_error> {
+    let msg = InstantiateMsg {
+        admin: Addr::unchecked(OWNER),
+        claim_msg_plaintext: CONFIG_PLAINTEXT.to_string(),
+        airdrop_amount: WHITELIST_AMOUNT,
+        addresses,
+        whitelist_code_id: 0,
+        minter_address: Addr::unchecked(MOCK_MINTER_ADDR_STR),
+        per_address_limit: 1,
+        merkle_root: Some(merkle_root),
+        signature_type: None,
+        start_time: None,
+        end_time: None,
+        claim_action: None,
+    };
+    instantiate_contract_with_msg(app, msg, funds_amount)
+}
+
+#[test]
+fn test_merkle_root_with_addresses() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, _, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let allocation = Allocation {
+        per_address_limit: 2,
+        airdrop_amount: AIRDROP_AMOUNT,
+    };
+    let tree = build_tree(&eth_addr_str, &allocation);
+
+    let mut app = custom_mock_app();
+    let res = instantiate_with_merkle_root(
+        &mut app,
+        tree.root_hex().unwrap(),
+        vec![eth_addr_str],
+        INSTANTIATION_FEE,
+    )
+    .unwrap_err();
+    assert_eq!(
+        res.root_cause().to_string(),
+        "Addresses can't be set along with a merkle root"
+    );
+}
+
+#[test]
+fn test_merkle_airdrop_eligible_query() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, _, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let allocation = Allocation {
+        per_address_limit: 2,
+        airdrop_amount: AIRDROP_AMOUNT,
+    };
+    let tree = build_tree(&eth_addr_str, &allocation);
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let airdrop_contract = instantiate_with_merkle_root(
+        &mut app,
+        tree.root_hex().unwrap(),
+        vec![],
+        INSTANTIATION_FEE,
+    )
+    .unwrap();
+
+    let proof_hashes = tree.proof(&[1]).proof_hashes_hex();
+    let query_msg = QueryMsg::AirdropEligible {
+        eth_address: eth_addr_str.clone(),
+        proof: Some(MerkleProof {
+            allocation: allocation.clone(),
+            proof_hashes: proof_hashes.clone(),
+        }),
+    };
+    let result: AirdropEligibleResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract.clone(), &query_msg)
+        .unwrap();
+    assert!(result.eligible);
+    assert_eq!(result.allocation, Some(allocation));
+
+    // A leaf can't be claimed with a larger allocation than the one in the tree
+    let query_msg = QueryMsg::AirdropEligible {
+        eth_address: eth_addr_str.clone(),
+        proof: Some(MerkleProof {
+            allocation: Allocation {
+                per_address_limit: 2,
+                airdrop_amount: AIRDROP_AMOUNT * 2,
+            },
+            proof_hashes,
+        }),
+    };
+    let result: AirdropEligibleResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract.clone(), &query_msg)
+        .unwrap();
+    assert!(!result.eligible);
+    assert_eq!(result.allocation, None);
+
+    let query_msg = QueryMsg::AirdropEligible {
+        eth_address: eth_addr_str,
+        proof: None,
+    };
+    let result: AirdropEligibleResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract, &query_msg)
+        .unwrap();
+    assert!(!result.eligible);
+}
+
+#[test]
+fn test_merkle_claim_allocation() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, eth_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let allocation = Allocation {
+        per_address_limit: 2,
+        airdrop_amount: AIRDROP_AMOUNT,
+    };
+    let tree = build_tree(&eth_addr_str, &allocation);
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let airdrop_contract = instantiate_with_merkle_root(
+        &mut app,
+        tree.root_hex().unwrap(),
+        vec![],
+        INSTANTIATION_FEE + 2 * AIRDROP_AMOUNT,
+    )
+    .unwrap();
+    assert_eq!(airdrop_contract, Addr::unchecked(MOCK_AIRDROP_ADDR_STR));
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+
+    // Claiming without a proof is rejected
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: eth_sig_str.clone(),
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        format!("Address {} is not eligible", eth_addr_str.to_lowercase())
+    );
+
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: Some(MerkleProof {
+            allocation,
+            proof_hashes: tree.proof(&[1]).proof_hashes_hex(),
+        }),
+    };
+    for claims in 1..=2 {
+        execute_contract_with_msg(
+            claim_message.clone(),
+            &mut app,
+            stargaze_wallet_01.clone(),
+            airdrop_contract.clone(),
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(stargaze_wallet_01.clone(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(claims * AIRDROP_AMOUNT));
+    }
+
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01,
+        airdrop_contract,
+    );
+    assert_eq!(
+        res,
+        format!(
+            "Address {} has already claimed all available mints",
+            eth_addr_str.to_lowercase()
+        )
+    );
+}
+
+#[test]
+fn test_update_merkle_root() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (_, _, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let allocation = Allocation {
+        per_address_limit: 2,
+        airdrop_amount: AIRDROP_AMOUNT,
+    };
+    let old_tree = build_tree("0x0000000000000000000000000000000000000003", &allocation);
+    let tree = build_tree(&eth_addr_str, &allocation);
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let airdrop_contract = instantiate_with_merkle_root(
+        &mut app,
+        old_tree.root_hex().unwrap(),
+        vec![],
+        INSTANTIATION_FEE,
+    )
+    .unwrap();
+
+    let query_msg = QueryMsg::AirdropEligible {
+        eth_address: eth_addr_str.clone(),
+        proof: Some(MerkleProof {
+            allocation,
+            proof_hashes: tree.proof(&[1]).proof_hashes_hex(),
+        }),
+    };
+    let result: AirdropEligibleResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract.clone(), &query_msg)
+        .unwrap();
+    assert!(!result.eligible);
+
+    let update_msg = ExecuteMsg::UpdateMerkleRoot {
+        merkle_root: tree.root_hex().unwrap(),
+    };
+    let res = execute_contract_error_with_msg(
+        update_msg.clone(),
+        &mut app,
+        Addr::unchecked(STARGAZE_WALLET_01),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(
+        res,
+        format!("Unauthorized admin, sender is {STARGAZE_WALLET_01}")
+    );
+    execute_contract_with_msg(
+        update_msg,
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+
+    let result: AirdropEligibleResponse = app
+        .wrap()
+        .query_wasm_smart(airdrop_contract.clone(), &query_msg)
+        .unwrap();
+    assert!(result.eligible);
+
+    // Eligible addresses come from the merkle tree only
+    let res = execute_contract_error_with_msg(
+        ExecuteMsg::AddEligibleAddresses {
+            addresses: vec![eth_addr_str],
+        },
+        &mut app,
+        Addr::unchecked(OWNER),
+        airdrop_contract,
+    );
+    assert_eq!(res, "Addresses can't be set along with a merkle root");
+}
@@ -0,0 +1,157 @@
+use crate::common_setup::contract_boxes::{contract_whitelist_immutable, custom_mock_app, App};
+use crate::sg_eth_airdrop::constants::claim_constants::{
+    CONFIG_PLAINTEXT, MOCK_MINTER_ADDR_STR, NATIVE_DENOM, OWNER, STARGAZE_WALLET_01,
+};
+use crate::sg_eth_airdrop::constants::collection_constants::WHITELIST_AMOUNT;
+use crate::sg_eth_airdrop::setup::configure_mock_minter::configure_mock_minter_with_mock_whitelist;
+use crate::sg_eth_airdrop::setup::execute_msg::{
+    execute_contract_error_with_msg, execute_contract_with_msg, instantiate_contract_with_msg,
+};
+use crate::sg_eth_airdrop::setup::setup_signatures::{get_msg_plaintext, get_wallet_and_sig};
+use async_std::task;
+use cosmwasm_std::{Addr, Uint128};
+use ethers_core::types::transaction::eip712::TypedData;
+use ethers_signers::{LocalWallet, Signer};
+use sg_eth_airdrop::contract::{
+    INSTANTIATION_FEE, TYPED_DATA_DOMAIN_NAME, TYPED_DATA_DOMAIN_VERSION,
+};
+use sg_eth_airdrop::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SignatureType};
+
+fn sign_typed_claim(
+    app: &App,
+    wallet: &LocalWallet,
+    airdrop_contract: &Addr,
+    recipient: &str,
+    nonce: u32,
+) -> String {
+    let typed_data: TypedData = serde_json::from_value(serde_json::json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+            ],
+            "Claim": [
+                { "name": "chainId", "type": "string" },
+                { "name": "verifyingContract", "type": "string" },
+                { "name": "recipient", "type": "string" },
+                { "name": "nonce", "type": "uint256" },
+            ],
+        },
+        "primaryType": "Claim",
+        "domain": {
+            "name": TYPED_DATA_DOMAIN_NAME,
+            "version": TYPED_DATA_DOMAIN_VERSION,
+        },
+        "message": {
+            "chainId": app.block_info().chain_id,
+            "verifyingContract": airdrop_contract.to_string(),
+            "recipient": recipient,
+            "nonce": nonce,
+        },
+    }))
+    .unwrap();
+    task::block_on(wallet.sign_typed_data(&typed_data))
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_typed_data_claim() {
+    let claim_plaintext = &get_msg_plaintext(STARGAZE_WALLET_01.to_string());
+    let (wallet, personal_sig_str, _, eth_addr_str) = get_wallet_and_sig(claim_plaintext.clone());
+    let stargaze_wallet_01 = Addr::unchecked(STARGAZE_WALLET_01);
+
+    let mut app = custom_mock_app();
+    configure_mock_minter_with_mock_whitelist(&mut app);
+    let msg = InstantiateMsg {
+        admin: Addr::unchecked(OWNER),
+        claim_msg_plaintext: CONFIG_PLAINTEXT.to_string(),
+        airdrop_amount: WHITELIST_AMOUNT,
+        addresses: vec![eth_addr_str.clone()],
+        whitelist_code_id: app.store_code(contract_whitelist_immutable()),
+        minter_address: Addr::unchecked(MOCK_MINTER_ADDR_STR),
+        per_address_limit: 2,
+        merkle_root: None,
+        signature_type: Some(SignatureType::TypedData),
+        start_time: None,
+        end_time: None,
+        claim_action: None,
+    };
+    let airdrop_contract =
+        instantiate_contract_with_msg(&mut app, msg, INSTANTIATION_FEE + 2 * WHITELIST_AMOUNT)
+            .unwrap();
+
+    // A personal_sign signature isn't accepted when claims are typed data
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: personal_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, format!("Address {eth_addr_str} is not eligible"));
+
+    let eth_sig_str = sign_typed_claim(&app, &wallet, &airdrop_contract, STARGAZE_WALLET_01, 0);
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str.clone(),
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    execute_contract_with_msg(
+        claim_message.clone(),
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    )
+    .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01.clone(), NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(WHITELIST_AMOUNT));
+
+    // The signature is bound to its nonce and can't be replayed
+    let res = execute_contract_error_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract.clone(),
+    );
+    assert_eq!(res, format!("Address {eth_addr_str} is not eligible"));
+
+    let nonce: u32 = app
+        .wrap()
+        .query_wasm_smart(
+            airdrop_contract.clone(),
+            &QueryMsg::ClaimCount {
+                eth_address: eth_addr_str.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(nonce, 1);
+    let eth_sig_str = sign_typed_claim(&app, &wallet, &airdrop_contract, STARGAZE_WALLET_01, nonce);
+    let claim_message = ExecuteMsg::ClaimAirdrop {
+        eth_address: eth_addr_str,
+        eth_sig: eth_sig_str,
+        pub_key: None,
+        proof: None,
+    };
+    execute_contract_with_msg(
+        claim_message,
+        &mut app,
+        stargaze_wallet_01.clone(),
+        airdrop_contract,
+    )
+    .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(stargaze_wallet_01, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(2 * WHITELIST_AMOUNT));
+}
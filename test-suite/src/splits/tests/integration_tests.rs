@@ -4,14 +4,15 @@ mod tests {
     use crate::common_setup::{
         contract_boxes_empty::contract_splits, helpers::mock_app_builder_init_funds,
     };
-    use cosmwasm_std::{to_json_binary, Addr, Coin};
-    use cw2::{query_contract_info, ContractVersion};
+    use cosmwasm_std::{coins, to_json_binary, Addr, Binary, Coin, Empty};
+    use cw2::{query_contract_info, set_contract_version, ContractVersion};
     use cw4::{Cw4ExecuteMsg, Member, MemberListResponse};
     use cw4_group::msg::ExecuteMsg as Cw4GroupExecuteMsg;
     use cw_multi_test::{next_block, App, Executor as TestExecutor};
     use sg_controllers::ContractInstantiateMsg;
-    use sg_splits::contract::MAX_GROUP_SIZE;
-    use sg_splits::msg::Group;
+    use sg_splits::contract::{CONTRACT_NAME, PAGINATION_LIMIT};
+    use sg_splits::msg::{ClaimableResponse, ExecuteMsg, Group};
+    use sg_splits::state::TRACKED_MEMBERS;
     use sg_splits::{
         msg::{InstantiateMsg, QueryMsg},
         ContractError,
@@ -33,12 +34,24 @@ mod tests {
             .unwrap()
     }
 
+    // address the next instantiated contract gets
+    fn next_contract_addr(app: &App) -> Addr {
+        (0..)
+            .map(|i| Addr::unchecked(format!("contract{i}")))
+            .find(|addr| app.contract_data(addr).is_err())
+            .unwrap()
+    }
+
     #[track_caller]
     fn instantiate_splits_with_group(app: &mut App, group_addr: Addr) -> Addr {
         let flex_id = app.store_code(contract_splits());
+        let splits_addr = next_contract_addr(app);
+        add_splits_hook(app, &group_addr, &splits_addr);
         let msg = sg_splits::msg::InstantiateMsg {
             group: Group::Cw4Address(group_addr.to_string()),
             admin: None,
+            distribution_threshold: None,
+            cw20_tokens: None,
         };
         app.instantiate_contract(flex_id, Addr::unchecked(OWNER), &msg, &[], "splits", None)
             .unwrap()
@@ -48,7 +61,7 @@ mod tests {
     fn instantiate_splits(app: &mut App) -> Addr {
         let flex_id = app.store_code(contract_splits());
         let group_msg = cw4_group::msg::InstantiateMsg {
-            admin: Some(OWNER.into()),
+            admin: None,
             members: vec![
                 member(OWNER, 50),
                 member(MEMBER1, 25),
@@ -65,22 +78,24 @@ mod tests {
                 label: "cw4-group".to_string(),
             }),
             admin: Some(OWNER.into()),
+            distribution_threshold: None,
+            cw20_tokens: None,
         };
         app.instantiate_contract(flex_id, Addr::unchecked(OWNER), &msg, &[], "splits", None)
             .unwrap()
     }
 
     #[track_caller]
-    fn instantiate_splits_with_overflow_group(app: &mut App) -> Addr {
+    fn instantiate_splits_with_large_group(app: &mut App) -> Addr {
         let flex_id = app.store_code(contract_splits());
 
-        let members: Vec<Member> = (1..=MAX_GROUP_SIZE + 1)
-            .map(|i| member(format!("member{i:04}"), 1))
+        // More than two pages of members with varying weights
+        let members: Vec<Member> = (1..=PAGINATION_LIMIT * 2 + 15)
+            .map(|i| member(format!("member{i:04}"), (i % 3 + 1) as u64))
             .collect();
-        // members.push(member(OWNER, 1));
 
         let group_msg = cw4_group::msg::InstantiateMsg {
-            admin: Some(OWNER.into()),
+            admin: None,
             members,
         };
 
@@ -92,6 +107,8 @@ mod tests {
                 label: "cw4-group".to_string(),
             }),
             admin: Some(OWNER.into()),
+            distribution_threshold: None,
+            cw20_tokens: None,
         };
         app.instantiate_contract(flex_id, Addr::unchecked(OWNER), &msg, &[], "splits", None)
             .unwrap()
@@ -161,9 +178,9 @@ mod tests {
     }
 
     #[track_caller]
-    fn setup_test_case_with_overflow_group(app: &mut App, init_funds: Vec<Coin>) -> (Addr, Addr) {
+    fn setup_test_case_with_large_group(app: &mut App, init_funds: Vec<Coin>) -> (Addr, Addr) {
         // Set up Splits with internal group
-        let splits_addr = instantiate_splits_with_overflow_group(app);
+        let splits_addr = instantiate_splits_with_large_group(app);
         app.update_block(next_block);
 
         // Bonus: set some funds on the splits contract for future proposals
@@ -180,6 +197,14 @@ mod tests {
         (splits_addr, group_addr)
     }
 
+    fn add_splits_hook(app: &mut App, group_addr: &Addr, splits_addr: &Addr) {
+        let msg = Cw4ExecuteMsg::AddHook {
+            addr: splits_addr.to_string(),
+        };
+        app.execute_contract(Addr::unchecked(OWNER), group_addr.clone(), &msg, &[])
+            .unwrap();
+    }
+
     #[test]
     fn test_instantiate_works() {
         let mut app = mock_app_builder_init_funds(&[]);
@@ -192,6 +217,8 @@ mod tests {
         let instantiate_msg = InstantiateMsg {
             group: Group::Cw4Address(group_addr.to_string()),
             admin: None,
+            distribution_threshold: None,
+            cw20_tokens: None,
         };
         let err = app
             .instantiate_contract(
@@ -208,13 +235,66 @@ mod tests {
             err.downcast().unwrap()
         );
 
-        // Single member group with weight is valid
+        // Group with an admin that does not call splits on member changes fails
         let group_addr = instantiate_group(&mut app, vec![member(OWNER, 1)]);
 
         let instantiate_msg = InstantiateMsg {
             group: Group::Cw4Address(group_addr.to_string()),
             admin: None,
+            distribution_threshold: None,
+            cw20_tokens: None,
         };
+        let err = app
+            .instantiate_contract(
+                splits_id,
+                Addr::unchecked(OWNER),
+                &instantiate_msg,
+                &[],
+                "group without hook",
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MissingMemberChangedHook {
+                addr: group_addr.to_string()
+            },
+            err.downcast().unwrap()
+        );
+
+        // Instantiated group with an admin can't call splits on member changes
+        let group_msg = cw4_group::msg::InstantiateMsg {
+            admin: Some(OWNER.into()),
+            members: vec![member(OWNER, 1)],
+        };
+        let instantiate_group_msg = InstantiateMsg {
+            group: Group::Cw4Instantiate(ContractInstantiateMsg {
+                code_id: app.store_code(contract_group()),
+                msg: to_json_binary(&group_msg).unwrap(),
+                admin: None,
+                label: "cw4-group".to_string(),
+            }),
+            admin: None,
+            distribution_threshold: None,
+            cw20_tokens: None,
+        };
+        let err = app
+            .instantiate_contract(
+                splits_id,
+                Addr::unchecked(OWNER),
+                &instantiate_group_msg,
+                &[],
+                "instantiated group with admin",
+                None,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::MissingMemberChangedHook { .. }
+        ));
+
+        // Single member group with weight is valid
+        let hook_addr = next_contract_addr(&app);
+        add_splits_hook(&mut app, &group_addr, &hook_addr);
         let splits_addr = app
             .instantiate_contract(
                 splits_id,
@@ -256,13 +336,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn migrate_tracks_existing_members() {
+        const DENOM: &str = "ustars";
+        let init_funds = coins(1_000, DENOM);
+        let mut app = mock_app_builder_init_funds(&init_funds);
+        let splits_id = app.store_code(contract_splits());
+
+        let group_addr = instantiate_group(
+            &mut app,
+            vec![member(OWNER, 50), member(MEMBER1, 25), member(MEMBER3, 25)],
+        );
+        let splits_addr = next_contract_addr(&app);
+        add_splits_hook(&mut app, &group_addr, &splits_addr);
+        let msg = InstantiateMsg {
+            group: Group::Cw4Address(group_addr.to_string()),
+            admin: None,
+            distribution_threshold: None,
+            cw20_tokens: None,
+        };
+        app.instantiate_contract(
+            splits_id,
+            Addr::unchecked(OWNER),
+            &msg,
+            &[],
+            "splits",
+            Some(OWNER.to_string()),
+        )
+        .unwrap();
+        app.send_tokens(Addr::unchecked(OWNER), splits_addr.clone(), &init_funds)
+            .unwrap();
+
+        // versions before per member accounting didn't track members
+        {
+            let mut storage = app.contract_storage_mut(&splits_addr);
+            for addr in [OWNER, MEMBER1, MEMBER3] {
+                TRACKED_MEMBERS.remove(storage.as_mut(), &Addr::unchecked(addr));
+            }
+            set_contract_version(storage.as_mut(), CONTRACT_NAME, "3.0.0").unwrap();
+        }
+        let claimable = |app: &App| -> ClaimableResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    &splits_addr,
+                    &QueryMsg::Claimable {
+                        address: MEMBER3.to_string(),
+                        denoms: None,
+                        cw20_list: None,
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(claimable(&app).claimable, vec![]);
+
+        app.migrate_contract(
+            Addr::unchecked(OWNER),
+            splits_addr.clone(),
+            &Empty {},
+            splits_id,
+        )
+        .unwrap();
+        assert_eq!(claimable(&app).claimable, coins(250, DENOM));
+        let version = query_contract_info(&app.wrap(), splits_addr.clone()).unwrap();
+        assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+        // the deposit already in the contract is split between the existing members
+        let msg = ExecuteMsg::Distribute {
+            denom_list: None,
+            cw20_list: None,
+        };
+        app.execute_contract(Addr::unchecked(OWNER), splits_addr, &msg, &[])
+            .unwrap();
+        let bal = app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+        assert_eq!(bal.amount.u128(), 250);
+        let bal = app.wrap().query_balance(MEMBER3, DENOM).unwrap();
+        assert_eq!(bal.amount.u128(), 250);
+    }
+
     mod distribute {
-        use cosmwasm_std::{coins, Uint128};
+        use cosmwasm_std::{coins, Event, Uint128};
         use cw4::Cw4Contract;
         use cw_multi_test::{BankSudo, SudoMsg};
 
         use super::*;
-        use sg_splits::msg::{ExecuteMsg, QueryMsg};
+        use sg_splits::msg::{DistributionResponse, ExecuteMsg, QueryMsg};
 
         #[test]
         fn distribute_zero_funds() {
@@ -270,7 +427,10 @@ mod tests {
 
             let (splits_addr, _) = setup_test_case(&mut app, vec![], false);
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             let err = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr, &msg, &[])
@@ -286,7 +446,10 @@ mod tests {
 
             let (splits_addr, _) = setup_test_case(&mut app, init_funds, false);
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             app.execute_contract(
                 Addr::unchecked("non_member".to_string()),
@@ -305,7 +468,10 @@ mod tests {
 
             let (splits_addr, _) = setup_test_case_with_internal_group(&mut app, init_funds);
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
                 .unwrap();
@@ -345,7 +511,10 @@ mod tests {
                 setup_test_case_with_internal_group(&mut app, init_funds);
             let total_weight = Cw4Contract(group_addr).total_weight(&app.wrap()).unwrap();
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             let err = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr, &msg, &[])
@@ -369,7 +538,10 @@ mod tests {
             let multiplier = init_funds[0].amount / Uint128::from(total_weight);
             let contract_balance = init_funds[0].amount - multiplier * Uint128::from(total_weight);
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             let _ = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
@@ -396,24 +568,151 @@ mod tests {
         }
 
         #[test]
-        fn distribute_with_too_many_members() {
+        fn distribute_to_large_group_in_pages() {
             const DENOM: &str = "ustars";
-            let init_funds = coins(255, DENOM);
+            let init_funds = coins(100_005, DENOM);
             let mut app = mock_app_builder_init_funds(&init_funds);
 
-            let (splits_addr, _) = setup_test_case_with_overflow_group(&mut app, init_funds);
+            let (splits_addr, group_addr) =
+                setup_test_case_with_large_group(&mut app, init_funds.clone());
+            let total_weight = Cw4Contract(group_addr).total_weight(&app.wrap()).unwrap();
+            let multiplier = init_funds[0].amount / Uint128::from(total_weight);
+
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
+
+            // first page snapshots the funds and stores the cursor
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let res: DistributionResponse = app
+                .wrap()
+                .query_wasm_smart(&splits_addr, &QueryMsg::Distribution {})
+                .unwrap();
+            let distribution = res.distribution.unwrap();
+            assert_eq!(distribution.total_weight, total_weight);
+            assert_eq!(
+                distribution.funds,
+                coins((multiplier * Uint128::from(total_weight)).u128(), DENOM)
+            );
+            assert_eq!(
+                distribution.cursor,
+                Some(format!("member{:04}", PAGINATION_LIMIT))
+            );
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            // funds received during a distribution are left for the next one
+            app.sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: splits_addr.to_string(),
+                amount: coins(1_000, DENOM),
+            }))
+            .unwrap();
+
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let res = app
+                .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            assert!(res.has_event(
+                &Event::new("wasm")
+                    .add_attribute("action", "distribute")
+                    .add_attribute("complete", "true")
+            ));
+
+            let res: DistributionResponse = app
+                .wrap()
+                .query_wasm_smart(&splits_addr, &QueryMsg::Distribution {})
+                .unwrap();
+            assert_eq!(res.distribution, None);
+
+            // contract keeps the remainder and the funds received mid-distribution
+            let contract_balance = init_funds[0].amount - multiplier * Uint128::from(total_weight)
+                + Uint128::new(1_000);
+            let bal = app.wrap().query_all_balances(splits_addr.clone()).unwrap();
+            assert_eq!(bal, coins(contract_balance.u128(), DENOM));
+
+            // every member was paid its share
+            for i in 1..=PAGINATION_LIMIT * 2 + 15 {
+                let bal = app
+                    .wrap()
+                    .query_balance(format!("member{i:04}"), DENOM)
+                    .unwrap();
+                assert_eq!(bal.amount, Uint128::from(i % 3 + 1) * multiplier);
+            }
+        }
+
+        #[test]
+        fn abort_distribution() {
+            const DENOM: &str = "ustars";
+            let init_funds = coins(100_005, DENOM);
+            let mut app = mock_app_builder_init_funds(&init_funds);
+
+            let (splits_addr, group_addr) =
+                setup_test_case_with_large_group(&mut app, init_funds.clone());
+            let total_weight = Cw4Contract(group_addr).total_weight(&app.wrap()).unwrap();
+            let multiplier = init_funds[0].amount / Uint128::from(total_weight);
+
+            let abort_msg = ExecuteMsg::AbortDistribution {};
             let err = app
-                .execute_contract(Addr::unchecked(OWNER), splits_addr, &msg, &[])
+                .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &abort_msg, &[])
+                .unwrap_err();
+            assert_eq!(ContractError::NoDistribution {}, err.downcast().unwrap());
+
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+
+            // only the admin can abort
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(MEMBER1),
+                    splits_addr.clone(),
+                    &abort_msg,
+                    &[],
+                )
                 .unwrap_err();
+            assert_eq!(err.root_cause().to_string(), "Caller is not admin");
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &abort_msg, &[])
+                .unwrap();
+            let res: DistributionResponse = app
+                .wrap()
+                .query_wasm_smart(&splits_addr, &QueryMsg::Distribution {})
+                .unwrap();
+            assert_eq!(res.distribution, None);
+
+            // members the aborted distribution didn't pay can still claim their share
+            let unpaid = format!("member{:04}", PAGINATION_LIMIT + 1);
+            let msg = ExecuteMsg::Claim {
+                denoms: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(&unpaid), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance(&unpaid, DENOM).unwrap();
             assert_eq!(
-                err.source().unwrap().to_string(),
-                ContractError::InvalidMemberCount {
-                    count: (MAX_GROUP_SIZE + 1) as usize
-                }
-                .to_string()
+                bal.amount,
+                Uint128::from((PAGINATION_LIMIT + 1) % 3 + 1) * multiplier
             );
+
+            // the next distribution pays the rest without paying anyone twice
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
+            for _ in 0..3 {
+                app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                    .unwrap();
+            }
+            for i in 1..=PAGINATION_LIMIT * 2 + 15 {
+                let bal = app
+                    .wrap()
+                    .query_balance(format!("member{i:04}"), DENOM)
+                    .unwrap();
+                assert_eq!(bal.amount, Uint128::from(i % 3 + 1) * multiplier);
+            }
         }
 
         #[test]
@@ -422,8 +721,7 @@ mod tests {
             let init_funds = coins(255, DENOM);
             let mut app = mock_app_builder_init_funds(&init_funds);
 
-            let (splits_addr, group_addr) =
-                setup_test_case_with_internal_group(&mut app, init_funds);
+            let (splits_addr, group_addr) = setup_test_case(&mut app, init_funds, false);
 
             let msg = Cw4GroupExecuteMsg::UpdateMembers {
                 remove: vec![],
@@ -433,7 +731,10 @@ mod tests {
                 .execute_contract(Addr::unchecked(OWNER), group_addr, &msg, &[])
                 .unwrap();
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
             let _ = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr, &msg, &[])
                 .unwrap();
@@ -465,7 +766,10 @@ mod tests {
             let contract_balance = init_funds[0].amount - multiplier * Uint128::from(total_weight);
             let mut payouts = vec![];
 
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
 
             let _ = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
@@ -534,7 +838,10 @@ mod tests {
             );
 
             // distribute again and check accounting
-            let msg = ExecuteMsg::Distribute { denom_list: None };
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
             let _ = app
                 .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
                 .unwrap();
@@ -566,4 +873,526 @@ mod tests {
             }
         }
     }
+
+    mod claim {
+        use cosmwasm_std::{coins, Uint128};
+        use cw_multi_test::{BankSudo, SudoMsg};
+
+        use super::*;
+        use sg_splits::msg::{ClaimableResponse, ExecuteMsg, QueryMsg, ReceivedResponse};
+
+        const DENOM: &str = "ustars";
+
+        fn claimable(app: &App, splits_addr: &Addr, address: &str) -> Vec<Coin> {
+            let res: ClaimableResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    splits_addr,
+                    &QueryMsg::Claimable {
+                        address: address.to_string(),
+                        denoms: None,
+                        cw20_list: None,
+                    },
+                )
+                .unwrap();
+            res.claimable
+        }
+
+        fn deposit(app: &mut App, splits_addr: &Addr, amount: u128) {
+            app.sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: splits_addr.to_string(),
+                amount: coins(amount, DENOM),
+            }))
+            .unwrap();
+        }
+
+        #[test]
+        fn claim_by_weight() {
+            let init_funds = coins(1_050, DENOM);
+            let mut app = mock_app_builder_init_funds(&init_funds);
+
+            let (splits_addr, _) = setup_test_case(&mut app, init_funds, false);
+
+            // deposits are booked modulo the total weight
+            assert_eq!(claimable(&app, &splits_addr, MEMBER1), coins(250, DENOM));
+
+            let msg = ExecuteMsg::Claim {
+                denoms: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(250));
+            assert_eq!(claimable(&app, &splits_addr, MEMBER1), vec![]);
+
+            let err = app
+                .execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(ContractError::NothingToClaim {}, err.downcast().unwrap());
+
+            // the unbooked remainder is booked with the next deposit
+            deposit(&mut app, &splits_addr, 50);
+            assert_eq!(claimable(&app, &splits_addr, MEMBER1), coins(25, DENOM));
+            assert_eq!(claimable(&app, &splits_addr, MEMBER3), coins(55, DENOM));
+
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let res: ReceivedResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &splits_addr,
+                    &QueryMsg::Received {
+                        address: MEMBER1.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.received, coins(275, DENOM));
+
+            // members who did not claim keep their share
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance(OWNER, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(550));
+            let bal = app.wrap().query_balance(&splits_addr, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(275));
+        }
+
+        #[test]
+        fn claim_after_weight_change() {
+            let init_funds = coins(1_000, DENOM);
+            let mut app = mock_app_builder_init_funds(&init_funds);
+
+            let (splits_addr, group_addr) = setup_test_case(&mut app, init_funds, false);
+
+            // the deposit is split with the weights before the change
+            let msg = Cw4GroupExecuteMsg::UpdateMembers {
+                remove: vec![MEMBER3.to_string()],
+                add: vec![member(MEMBER1, 30), member("member0100", 20)],
+            };
+            app.execute_contract(Addr::unchecked(OWNER), group_addr, &msg, &[])
+                .unwrap();
+
+            assert_eq!(claimable(&app, &splits_addr, MEMBER1), coins(250, DENOM));
+            assert_eq!(claimable(&app, &splits_addr, MEMBER3), coins(50, DENOM));
+            assert_eq!(claimable(&app, &splits_addr, "member0100"), vec![]);
+
+            // the next deposit is split with the new weights
+            deposit(&mut app, &splits_addr, 1_200);
+            assert_eq!(claimable(&app, &splits_addr, MEMBER1), coins(550, DENOM));
+            assert_eq!(claimable(&app, &splits_addr, MEMBER3), coins(50, DENOM));
+            assert_eq!(
+                claimable(&app, &splits_addr, "member0100"),
+                coins(200, DENOM)
+            );
+
+            // removed members can still claim what they were owed
+            let msg = ExecuteMsg::Claim {
+                denoms: Some(vec![DENOM.to_string()]),
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(MEMBER3), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance(MEMBER3, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(50));
+        }
+
+        #[test]
+        fn member_added_without_hook_is_not_paid_earlier_deposits() {
+            let init_funds = coins(1_000, DENOM);
+            let mut app = mock_app_builder_init_funds(&init_funds);
+
+            // the group admin removes the splits hook
+            let (splits_addr, group_addr) = setup_test_case(&mut app, init_funds, false);
+            let msg = Cw4ExecuteMsg::RemoveHook {
+                addr: splits_addr.to_string(),
+            };
+            app.execute_contract(Addr::unchecked(OWNER), group_addr.clone(), &msg, &[])
+                .unwrap();
+
+            // the claim books the deposit for the original members
+            let msg = ExecuteMsg::Claim {
+                denoms: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance(MEMBER1, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(250));
+
+            let update_members = Cw4GroupExecuteMsg::UpdateMembers {
+                remove: vec![],
+                add: vec![member("member0100", 100)],
+            };
+            app.execute_contract(Addr::unchecked(OWNER), group_addr, &update_members, &[])
+                .unwrap();
+
+            // the new member is not paid for deposits booked before it was first settled
+            assert_eq!(claimable(&app, &splits_addr, "member0100"), vec![]);
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let bal = app.wrap().query_balance("member0100", DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::zero());
+            let bal = app.wrap().query_balance(MEMBER3, DENOM).unwrap();
+            assert_eq!(bal.amount, Uint128::new(50));
+
+            // the new member shares the next deposit
+            deposit(&mut app, &splits_addr, 2_000);
+            assert_eq!(
+                claimable(&app, &splits_addr, "member0100"),
+                coins(1_000, DENOM)
+            );
+            assert_eq!(claimable(&app, &splits_addr, MEMBER3), coins(50, DENOM));
+        }
+    }
+
+    mod cw20_tokens {
+        use cosmwasm_std::Uint128;
+        use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+
+        use super::*;
+        use crate::common_setup::contract_boxes_empty::contract_cw20;
+        use sg_splits::contract::cw20_denom;
+        use sg_splits::msg::{ClaimableResponse, Cw20TokensResponse, ExecuteMsg, QueryMsg};
+
+        fn instantiate_cw20(app: &mut App, amount: u128) -> Addr {
+            let cw20_id = app.store_code(contract_cw20());
+            let msg = cw20_base::msg::InstantiateMsg {
+                name: "Token".to_string(),
+                symbol: "TOKEN".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: OWNER.to_string(),
+                    amount: Uint128::new(amount),
+                }],
+                mint: None,
+                marketing: None,
+            };
+            app.instantiate_contract(cw20_id, Addr::unchecked(OWNER), &msg, &[], "cw20", None)
+                .unwrap()
+        }
+
+        fn send_cw20(app: &mut App, token: &Addr, splits_addr: &Addr, amount: u128) {
+            let msg = Cw20ExecuteMsg::Send {
+                contract: splits_addr.to_string(),
+                amount: Uint128::new(amount),
+                msg: Binary::default(),
+            };
+            app.execute_contract(Addr::unchecked(OWNER), token.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        fn allow_cw20(app: &mut App, splits_addr: &Addr, token: &Addr) {
+            let msg = ExecuteMsg::UpdateCw20Tokens {
+                add: vec![token.to_string()],
+                remove: vec![],
+            };
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        fn cw20_balance(app: &App, token: &Addr, address: &str) -> Uint128 {
+            let res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token,
+                    &Cw20QueryMsg::Balance {
+                        address: address.to_string(),
+                    },
+                )
+                .unwrap();
+            res.balance
+        }
+
+        #[test]
+        fn distribute_cw20() {
+            let mut app = mock_app_builder_init_funds(&[]);
+            let (splits_addr, _) = setup_test_case_with_internal_group(&mut app, vec![]);
+            let token = instantiate_cw20(&mut app, 10_000);
+
+            allow_cw20(&mut app, &splits_addr, &token);
+            send_cw20(&mut app, &token, &splits_addr, 1_050);
+
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: Some(vec![token.to_string()]),
+            };
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+
+            // tokens are split by weight modulo the total weight
+            assert_eq!(cw20_balance(&app, &token, OWNER), Uint128::new(9_450));
+            assert_eq!(cw20_balance(&app, &token, MEMBER1), Uint128::new(250));
+            assert_eq!(cw20_balance(&app, &token, MEMBER2), Uint128::new(200));
+            assert_eq!(cw20_balance(&app, &token, MEMBER3), Uint128::new(50));
+            assert_eq!(
+                cw20_balance(&app, &token, splits_addr.as_str()),
+                Uint128::new(50)
+            );
+
+            // the remainder is booked with the next deposit
+            send_cw20(&mut app, &token, &splits_addr, 50);
+            let res: ClaimableResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &splits_addr,
+                    &QueryMsg::Claimable {
+                        address: MEMBER1.to_string(),
+                        denoms: None,
+                        cw20_list: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.claimable, vec![Coin::new(25, cw20_denom(&token))]);
+
+            let msg = ExecuteMsg::Claim {
+                denoms: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            assert_eq!(cw20_balance(&app, &token, MEMBER1), Uint128::new(275));
+        }
+
+        #[test]
+        fn receive_only_allowed_cw20() {
+            let mut app = mock_app_builder_init_funds(&[]);
+            let (splits_addr, group_addr) = setup_test_case_with_internal_group(&mut app, vec![]);
+            let token = instantiate_cw20(&mut app, 10_000);
+
+            // tokens not allowed by the admin are rejected
+            let msg = Cw20ExecuteMsg::Send {
+                contract: splits_addr.to_string(),
+                amount: Uint128::new(1_000),
+                msg: Binary::default(),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(OWNER), token.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                ContractError::Cw20NotAllowed {
+                    token: token.to_string()
+                },
+                err.downcast().unwrap()
+            );
+
+            let msg = ExecuteMsg::UpdateCw20Tokens {
+                add: vec![token.to_string()],
+                remove: vec![],
+            };
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap_err();
+
+            // explicit lists only take accepted tokens
+            let msg = ExecuteMsg::Distribute {
+                denom_list: None,
+                cw20_list: Some(vec![token.to_string()]),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                ContractError::Cw20NotAllowed {
+                    token: token.to_string()
+                },
+                err.downcast().unwrap()
+            );
+
+            // a token that does not answer the balance query is skipped
+            allow_cw20(&mut app, &splits_addr, &token);
+            allow_cw20(&mut app, &splits_addr, &group_addr);
+            let res: Cw20TokensResponse = app
+                .wrap()
+                .query_wasm_smart(&splits_addr, &QueryMsg::Cw20Tokens {})
+                .unwrap();
+            assert_eq!(res.tokens.len(), 2);
+
+            send_cw20(&mut app, &token, &splits_addr, 1_000);
+            let msg = ExecuteMsg::Claim {
+                denoms: None,
+                cw20_list: None,
+            };
+            app.execute_contract(Addr::unchecked(MEMBER1), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            assert_eq!(cw20_balance(&app, &token, MEMBER1), Uint128::new(250));
+
+            // removed tokens are no longer accepted
+            let msg = ExecuteMsg::UpdateCw20Tokens {
+                add: vec![],
+                remove: vec![token.to_string(), group_addr.to_string()],
+            };
+            app.execute_contract(Addr::unchecked(OWNER), splits_addr.clone(), &msg, &[])
+                .unwrap();
+            let res: Cw20TokensResponse = app
+                .wrap()
+                .query_wasm_smart(&splits_addr, &QueryMsg::Cw20Tokens {})
+                .unwrap();
+            assert_eq!(res.tokens, Vec::<Addr>::new());
+            let msg = Cw20ExecuteMsg::Send {
+                contract: splits_addr.to_string(),
+                amount: Uint128::new(1_000),
+                msg: Binary::default(),
+            };
+            app.execute_contract(Addr::unchecked(OWNER), token, &msg, &[])
+                .unwrap_err();
+        }
+    }
+    mod nfts {
+        use cosmwasm_std::Empty;
+        use cw721::{Cw721QueryMsg, OwnerOfResponse};
+
+        use super::*;
+        use crate::common_setup::contract_boxes_empty::contract_cw721;
+        use sg_splits::msg::{ExecuteMsg, Nft, NftsResponse, QueryMsg};
+
+        fn instantiate_cw721(app: &mut App) -> Addr {
+            let cw721_id = app.store_code(contract_cw721());
+            let msg = cw721_base::InstantiateMsg {
+                name: "Collection".to_string(),
+                symbol: "COL".to_string(),
+                minter: OWNER.to_string(),
+            };
+            app.instantiate_contract(cw721_id, Addr::unchecked(OWNER), &msg, &[], "cw721", None)
+                .unwrap()
+        }
+
+        fn send_nft(
+            app: &mut App,
+            collection: &Addr,
+            splits_addr: &Addr,
+            token_id: &str,
+        ) -> anyhow::Result<()> {
+            let msg = cw721_base::ExecuteMsg::<Empty, Empty>::Mint {
+                token_id: token_id.to_string(),
+                owner: OWNER.to_string(),
+                token_uri: None,
+                extension: Empty {},
+            };
+            app.execute_contract(Addr::unchecked(OWNER), collection.clone(), &msg, &[])
+                .unwrap();
+            let msg = cw721_base::ExecuteMsg::<Empty, Empty>::SendNft {
+                contract: splits_addr.to_string(),
+                token_id: token_id.to_string(),
+                msg: Binary::default(),
+            };
+            app.execute_contract(Addr::unchecked(OWNER), collection.clone(), &msg, &[])
+                .map(|_| ())
+        }
+
+        #[test]
+        fn admin_routes_nfts_to_members() {
+            let mut app = mock_app_builder_init_funds(&[]);
+            let (splits_addr, _) = setup_test_case_with_internal_group(&mut app, vec![]);
+            let collection = instantiate_cw721(&mut app);
+
+            send_nft(&mut app, &collection, &splits_addr, "1").unwrap();
+            let res: NftsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &splits_addr,
+                    &QueryMsg::Nfts {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.nfts,
+                vec![Nft {
+                    collection: collection.clone(),
+                    token_id: "1".to_string()
+                }]
+            );
+
+            let transfer_msg = |token_id: &str, recipient: &str| ExecuteMsg::TransferNft {
+                collection: collection.to_string(),
+                token_id: token_id.to_string(),
+                recipient: recipient.to_string(),
+            };
+
+            // only the admin routes NFTs, and only to members
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(MEMBER1),
+                    splits_addr.clone(),
+                    &transfer_msg("1", MEMBER1),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(err.root_cause().to_string(), "Caller is not admin");
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(OWNER),
+                    splits_addr.clone(),
+                    &transfer_msg("1", "outsider"),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::NotMember {
+                    addr: "outsider".to_string()
+                },
+                err.downcast().unwrap()
+            );
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(OWNER),
+                    splits_addr.clone(),
+                    &transfer_msg("2", MEMBER1),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::NftNotHeld {
+                    collection: collection.to_string(),
+                    token_id: "2".to_string()
+                },
+                err.downcast().unwrap()
+            );
+
+            app.execute_contract(
+                Addr::unchecked(OWNER),
+                splits_addr.clone(),
+                &transfer_msg("1", MEMBER1),
+                &[],
+            )
+            .unwrap();
+            let res: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &collection,
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.owner, MEMBER1);
+            let res: NftsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &splits_addr,
+                    &QueryMsg::Nfts {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.nfts, vec![]);
+        }
+
+        #[test]
+        fn splits_without_admin_reject_nfts() {
+            let mut app = mock_app_builder_init_funds(&[]);
+            let (splits_addr, _) = setup_test_case(&mut app, vec![], false);
+            let collection = instantiate_cw721(&mut app);
+
+            let err = send_nft(&mut app, &collection, &splits_addr, "1").unwrap_err();
+            assert_eq!(ContractError::NftsWithoutAdmin {}, err.downcast().unwrap());
+        }
+    }
 }
@@ -1,2 +1,3 @@
 mod integration_tests;
+mod minter_registry;
 mod sudo_tests;
@@ -41,5 +41,28 @@ mod tests {
             let (_, factory_contract) = proper_instantiate();
             assert_eq!(factory_contract.addr().to_string(), "contract0");
         }
+
+        #[test]
+        fn invalid_fee_routing() {
+            let mut app = custom_mock_app();
+            let factory_id = app.store_code(contract_vending_factory());
+
+            let mut params = mock_params(None);
+            params.fee_routing.mint_fee_recipients = vec![];
+            let err = app
+                .instantiate_contract(
+                    factory_id,
+                    Addr::unchecked(GOVERNANCE),
+                    &InstantiateMsg { params },
+                    &[],
+                    "factory",
+                    None,
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                "Invalid fee routing: mint fee recipients can't be empty"
+            );
+        }
     }
 }
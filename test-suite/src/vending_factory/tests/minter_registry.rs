@@ -0,0 +1,127 @@
+use cosmwasm_std::{coins, Addr, Timestamp};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use sg2::msg::Sg2ExecuteMsg;
+use sg2::query::{MinterResponse, MintersResponse, Sg2QueryMsg};
+use sg2::tests::mock_collection_params_1;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_minter::common::constants::CREATION_FEE;
+use crate::common_setup::setup_minter::vending_minter::mock_params::mock_create_minter;
+use crate::common_setup::templates::vending_minter_template_with_code_ids_template;
+
+fn create_minter(router: &mut App, factory: &Addr, creator: &Addr, sg721_code_id: u64) {
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let mut msg = mock_create_minter(None, mock_collection_params_1(Some(start_time)), None);
+    msg.collection_params.code_id = sg721_code_id;
+    msg.collection_params.info.creator = creator.to_string();
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: coins(CREATION_FEE, NATIVE_DENOM),
+        }))
+        .unwrap();
+    router
+        .execute_contract(
+            creator.clone(),
+            factory.clone(),
+            &Sg2ExecuteMsg::CreateMinter(msg),
+            &coins(CREATION_FEE, NATIVE_DENOM),
+        )
+        .unwrap();
+}
+
+#[test]
+fn minter_registry() {
+    let vt = vending_minter_template_with_code_ids_template(10);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let sg721_code_id = vt.code_ids.sg721_code_id;
+
+    // The minter created by the template is registered with its collection
+    let res: MinterResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &Sg2QueryMsg::MinterByCollection {
+                collection: collection_addr.to_string(),
+            },
+        )
+        .unwrap();
+    let minter = res.minter.unwrap();
+    assert_eq!(minter.minter, minter_addr);
+    assert_eq!(minter.creator, creator);
+    assert_eq!(minter.collection, collection_addr);
+    assert_eq!(minter.code_id, vt.code_ids.minter_code_id);
+    assert_eq!(minter.created_at, router.block_info().time);
+
+    create_minter(&mut router, &factory_addr, &buyer, sg721_code_id);
+    create_minter(&mut router, &factory_addr, &creator, sg721_code_id);
+
+    let res: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &Sg2QueryMsg::Minters {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minters.len(), 3);
+
+    // Pagination
+    let res: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &Sg2QueryMsg::Minters {
+                start_after: Some(res.minters[0].minter.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minters.len(), 1);
+
+    let res: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &Sg2QueryMsg::MintersByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minters.len(), 2);
+    assert!(res.minters.iter().all(|m| m.creator == creator));
+
+    let res: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &Sg2QueryMsg::MintersByCreator {
+                creator: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minters.len(), 1);
+    assert_eq!(res.minters[0].creator, buyer);
+
+    // Unknown collections have no minter
+    let res: MinterResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &Sg2QueryMsg::MinterByCollection {
+                collection: minter_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minter, None);
+}
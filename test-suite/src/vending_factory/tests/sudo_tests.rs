@@ -1,9 +1,13 @@
 use base_factory::msg::ParamsResponse;
-use cosmwasm_std::coin;
-use sg_utils::NATIVE_DENOM;
-use vending_factory::msg::VendingUpdateParamsExtension;
+use cosmwasm_std::{coin, coins};
+use cw_multi_test::Executor;
+use sg1::{FeeRecipient, FeeRouting};
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::{VendingUpdateParamsExtension, VendingUpdateParamsMsg};
 
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
 use crate::common_setup::setup_minter::base_minter::mock_params::MIN_MINT_PRICE;
+use crate::common_setup::setup_minter::common::constants::MINT_PRICE;
 use crate::common_setup::setup_minter::vending_minter::setup::sudo_update_params;
 use crate::common_setup::templates::vending_minter_template_with_code_ids_template;
 use sg2::query::Sg2QueryMsg::Params;
@@ -29,8 +33,12 @@ fn sudo_params_update_creation_fee() {
         frozen: None,
         creation_fee: Some(coin(999, NATIVE_DENOM)),
         min_mint_price: Some(coin(MIN_MINT_PRICE, NATIVE_DENOM)),
+        additional_min_mint_prices: None,
         mint_fee_bps: None,
         max_trading_offset_secs: Some(100),
+        fee_routing: None,
+        max_whitelist_stages: None,
+        splits_code_ids: None,
         extension: VendingUpdateParamsExtension {
             max_token_limit: None,
             max_per_address_limit: None,
@@ -49,3 +57,89 @@ fn sudo_params_update_creation_fee() {
     let res: ParamsResponse = router.wrap().query_wasm_smart(factory, &Params {}).unwrap();
     assert_eq!(res.params.creation_fee, coin(999, NATIVE_DENOM));
 }
+
+fn fee_routing_update_msg(fee_routing: FeeRouting) -> VendingUpdateParamsMsg {
+    sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        additional_min_mint_prices: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        fee_routing: Some(fee_routing),
+        max_whitelist_stages: None,
+        splits_code_ids: None,
+        extension: VendingUpdateParamsExtension {
+            max_token_limit: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            shuffle_fee: None,
+        },
+    }
+}
+
+#[test]
+fn sudo_params_update_fee_routing() {
+    let vt = vending_minter_template_with_code_ids_template(2);
+    let (mut router, _, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let recipient = |label: &str, weight| FeeRecipient {
+        label: label.to_string(),
+        address: label.to_string(),
+        weight,
+    };
+    let fee_routing = FeeRouting {
+        mint_fee_recipients: vec![recipient("treasury", 3), recipient("validators", 1)],
+        ..FeeRouting::default()
+    };
+
+    // A recipient without a weight is rejected
+    let mut invalid_routing = fee_routing.clone();
+    invalid_routing.mint_fee_recipients[1].weight = 0;
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids.clone(),
+        Some(fee_routing_update_msg(invalid_routing)),
+    );
+    assert_eq!(
+        res[0].as_ref().unwrap_err().root_cause().to_string(),
+        "Invalid fee routing: weight of validators must be greater than zero"
+    );
+
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(fee_routing_update_msg(fee_routing)),
+    );
+    assert!(res[0].is_ok());
+
+    // Mint fees follow the new routing
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let network_fee = MINT_PRICE / 10;
+    let treasury = router
+        .wrap()
+        .query_balance("treasury", NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(treasury.amount.u128(), network_fee * 3 / 4);
+    let validators = router
+        .wrap()
+        .query_balance("validators", NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(validators.amount.u128(), network_fee / 4);
+}
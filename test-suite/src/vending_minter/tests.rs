@@ -1,10 +1,18 @@
 mod address_limit;
 mod allowed_code_ids;
+mod batch_mint;
+mod dutch_auction;
+mod escrow;
 mod frozen_factory;
 mod happy_unhappy;
 mod ibc_asset_mint;
 mod mint_and_burn;
+mod mint_hooks;
+mod multi_denom;
+mod randomness;
 mod splits;
+mod tiered_whitelist;
+mod token_gated_whitelist;
 mod trading_time;
 mod trading_time_updatable;
 mod updatable;
@@ -74,7 +74,7 @@ fn check_per_address_limit() {
     assert!(res.is_ok());
 
     // First mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -85,7 +85,7 @@ fn check_per_address_limit() {
     assert!(res.is_ok());
 
     // Second mint fails from exceeding per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -123,7 +123,7 @@ fn check_per_address_limit_3_percent() {
     assert!(res.is_ok());
 
     for _ in 0..6 {
-        let mint_msg = ExecuteMsg::Mint {};
+        let mint_msg = ExecuteMsg::Mint { quantity: None };
         let res = router.execute_contract(
             buyer.clone(),
             minter_addr.clone(),
@@ -134,7 +134,7 @@ fn check_per_address_limit_3_percent() {
     }
 
     // Second mint fails from exceeding per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -200,7 +200,7 @@ fn check_3_percent_round_up() {
     assert!(res.is_ok());
 
     for _ in 0..7 {
-        let mint_msg = ExecuteMsg::Mint {};
+        let mint_msg = ExecuteMsg::Mint { quantity: None };
         let res = router.execute_contract(
             buyer.clone(),
             minter_addr.clone(),
@@ -211,7 +211,7 @@ fn check_3_percent_round_up() {
     }
 
     // Second mint fails from exceeding per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -318,6 +318,7 @@ fn mint_for_token_id_addr() {
     let mint_for_msg = ExecuteMsg::MintFor {
         token_id: 1,
         recipient: buyer.to_string(),
+        royalty_info: None,
     };
     let err = router
         .execute_contract(
@@ -338,7 +339,7 @@ fn mint_for_token_id_addr() {
     // Test token id already sold
     // 1. random mint token_id
     // 2. mint_for same token_id
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -371,6 +372,7 @@ fn mint_for_token_id_addr() {
     let mint_for_msg = ExecuteMsg::MintFor {
         token_id,
         recipient: buyer.to_string(),
+        royalty_info: None,
     };
     let err = router
         .execute_contract(
@@ -392,6 +394,7 @@ fn mint_for_token_id_addr() {
     let mint_for_msg = ExecuteMsg::MintFor {
         token_id: sold_token_id,
         recipient: buyer.to_string(),
+        royalty_info: None,
     };
     let err = router
         .execute_contract(
@@ -444,6 +447,7 @@ fn mint_for_token_id_addr() {
     let mint_for_msg = ExecuteMsg::MintFor {
         token_id,
         recipient: buyer.to_string(),
+        royalty_info: None,
     };
     let res = router.execute_contract(
         creator,
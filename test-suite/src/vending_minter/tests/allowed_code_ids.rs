@@ -49,8 +49,12 @@ fn update_code_id() {
         code_id: None,
         creation_fee: None,
         min_mint_price: None,
+        additional_min_mint_prices: None,
         mint_fee_bps: None,
         max_trading_offset_secs: None,
+        fee_routing: None,
+        max_whitelist_stages: None,
+        splits_code_ids: None,
         extension,
     };
     let sudo_msg = SudoMsg::UpdateParams(Box::new(update_msg));
@@ -78,6 +82,7 @@ fn update_code_id() {
     let mut msg = VendingMinterCreateMsg {
         init_msg,
         collection_params,
+        splits: None,
     };
     msg.collection_params.info.creator = creator.to_string();
     let creation_fee = coins(CREATION_FEE, NATIVE_DENOM);
@@ -0,0 +1,137 @@
+use crate::common_setup::{
+    setup_accounts_and_block::setup_block_time, templates::vending_minter_template,
+};
+use cosmwasm_std::coins;
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::Executor;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_minter::msg::{ExecuteMsg, MintCountResponse, MintableNumTokensResponse, QueryMsg};
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn batch_mint() {
+    let vt = vending_minter_template(100);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
+
+    // Zero quantity fails
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(0) };
+    let err = router
+        .execute_contract(buyer.clone(), minter_addr.clone(), &mint_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Invalid mint quantity 0, must be between 1 and 100"
+    );
+
+    // Paying for a single token fails for a batch
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(3) };
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &mint_msg,
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "IncorrectPaymentAmount {}{} != {}{}",
+            MINT_PRICE,
+            NATIVE_DENOM,
+            MINT_PRICE * 3,
+            NATIVE_DENOM
+        )
+    );
+
+    // Batch exceeding the per address limit of 3 fails
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(4) };
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &mint_msg,
+            &coins(MINT_PRICE * 4, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+
+    // Batch of 3 succeeds with a single payment
+    let mint_msg = ExecuteMsg::Mint { quantity: Some(3) };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens.len(), 3);
+
+    let res: MintCountResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::MintCount {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.count, 3);
+
+    // Per address limit is now reached
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_err());
+
+    // Admin can airdrop a batch
+    let mint_to_msg = ExecuteMsg::MintTo {
+        recipient: buyer.to_string(),
+        quantity: Some(2),
+        royalty_info: None,
+    };
+    let res = router.execute_contract(creator, minter_addr.clone(), &mint_to_msg, &[]);
+    assert!(res.is_ok());
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens.len(), 5);
+
+    let res: MintableNumTokensResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintableNumTokens {})
+        .unwrap();
+    assert_eq!(res.count, 95);
+}
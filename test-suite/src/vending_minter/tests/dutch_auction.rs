@@ -0,0 +1,186 @@
+use crate::common_setup::contract_boxes::{custom_mock_app, App};
+use crate::common_setup::msg::MinterCollectionResponse;
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::minter_params::minter_params_all;
+use crate::common_setup::setup_minter::vending_minter::mock_params::mock_init_extension;
+use crate::common_setup::setup_minter::vending_minter::setup::{
+    configure_minter, vending_minter_code_ids,
+};
+use cosmwasm_std::{coin, coins, Addr, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use sg2::tests::mock_collection_params_1;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::DutchAuctionParams;
+use vending_minter::msg::{ExecuteMsg, MintPriceResponse, QueryMsg, RebateResponse};
+
+const START_PRICE: u128 = 500_000_000;
+const FLOOR_PRICE: u128 = 100_000_000;
+const DECAY_STEP: u128 = 100_000_000;
+const DECAY_INTERVAL: u64 = 3600;
+
+fn setup_dutch_auction(
+    app: &mut App,
+    creator: Addr,
+    floor_price: u128,
+) -> Vec<MinterCollectionResponse> {
+    let num_tokens = 3;
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let mut init_msg = mock_init_extension(None, Some(start_time));
+    init_msg.num_tokens = num_tokens;
+    init_msg.dutch_auction = Some(DutchAuctionParams {
+        start_price: coin(START_PRICE, NATIVE_DENOM),
+        floor_price: coin(floor_price, NATIVE_DENOM),
+        decay_step: Uint128::new(DECAY_STEP),
+        decay_interval: DECAY_INTERVAL,
+        rebate: true,
+    });
+    let code_ids = vending_minter_code_ids(app);
+    configure_minter(
+        app,
+        creator,
+        vec![mock_collection_params_1(Some(start_time))],
+        vec![minter_params_all(num_tokens, None, None, Some(init_msg))],
+        code_ids,
+    )
+}
+
+fn current_price(app: &App, minter_addr: &Addr) -> u128 {
+    let res: MintPriceResponse = app
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintPrice {})
+        .unwrap();
+    res.current_price.amount.u128()
+}
+
+#[test]
+fn dutch_auction_price_decays_to_floor() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let res = setup_dutch_auction(&mut app, creator.clone(), FLOOR_PRICE);
+    let minter_addr = res[0].minter.clone().unwrap();
+
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+    assert_eq!(current_price(&app, &minter_addr), START_PRICE);
+
+    // The mint price can't be changed while the auction sets it
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::UpdateMintPrice { price: FLOOR_PRICE },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Mint price is set by the dutch auction"
+    );
+
+    let res = app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint { quantity: None },
+        &coins(START_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Nothing to claim before the auction settles
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::ClaimRebate { address: None },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Dutch auction has not settled yet"
+    );
+
+    // Two intervals later the price has dropped by two steps
+    let two_intervals = DECAY_INTERVAL * 2 * 1_000_000_000;
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + two_intervals, None);
+    assert_eq!(
+        current_price(&app, &minter_addr),
+        START_PRICE - 2 * DECAY_STEP
+    );
+
+    // Paying the stale start price fails
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(START_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "IncorrectPaymentAmount {}{} != {}{}",
+            START_PRICE,
+            NATIVE_DENOM,
+            START_PRICE - 2 * DECAY_STEP,
+            NATIVE_DENOM
+        )
+    );
+
+    // The price never drops below the floor
+    let ten_intervals = DECAY_INTERVAL * 10 * 1_000_000_000;
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + ten_intervals, None);
+    assert_eq!(current_price(&app, &minter_addr), FLOOR_PRICE);
+
+    let res: RebateResponse = app
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::Rebate {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.settled);
+    assert_eq!(res.count, 1);
+    assert_eq!(res.clearing_price, Some(coin(FLOOR_PRICE, NATIVE_DENOM)));
+    assert_eq!(res.rebate.u128(), START_PRICE - FLOOR_PRICE);
+
+    // Anyone can settle a rebate on behalf of a buyer
+    let balance_before = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    let res = app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ClaimRebate {
+            address: Some(buyer.to_string()),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+    let balance_after = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        balance_after.amount - balance_before.amount,
+        Uint128::new(START_PRICE - FLOOR_PRICE)
+    );
+
+    let err = app
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::ClaimRebate { address: None },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "No rebate to claim");
+}
+
+#[test]
+fn dutch_auction_floor_below_min_mint_price() {
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    let res = setup_dutch_auction(&mut app, creator, 1_000);
+    let err = res[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Minimum network mint price 50000000 got 1000"
+    );
+}
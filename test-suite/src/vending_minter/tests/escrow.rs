@@ -0,0 +1,216 @@
+use crate::common_setup::contract_boxes::{custom_mock_app, App};
+use crate::common_setup::msg::MinterCollectionResponse;
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::constants::MIN_MINT_PRICE;
+use crate::common_setup::setup_minter::common::minter_params::minter_params_all;
+use crate::common_setup::setup_minter::vending_minter::mock_params::mock_init_extension;
+use crate::common_setup::setup_minter::vending_minter::setup::{
+    configure_minter, vending_minter_code_ids,
+};
+use cosmwasm_std::{coin, coins, Addr, Empty, Timestamp, Uint128};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw721_base::Extension;
+use cw_multi_test::Executor;
+use sg2::tests::mock_collection_params_1;
+use sg721::ExecuteMsg as Sg721ExecuteMsg;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::{EscrowMilestone, EscrowParams};
+use vending_minter::msg::{EscrowResponse, ExecuteMsg, QueryMsg, TokenEscrowResponse};
+
+const NUM_TOKENS: u32 = 4;
+const REFUND_DEADLINE: u64 = GENESIS_MINT_START_TIME + 1_000_000_000_000;
+// Mint price minus the 10% network fee
+const ESCROWED_PER_TOKEN: u128 = MIN_MINT_PRICE * 9 / 10;
+
+fn setup_escrow_minter(app: &mut App, creator: Addr) -> Vec<MinterCollectionResponse> {
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let mut init_msg = mock_init_extension(None, Some(start_time));
+    init_msg.num_tokens = NUM_TOKENS;
+    init_msg.escrow = Some(EscrowParams {
+        milestone: EscrowMilestone::PercentSold(50),
+        refund_deadline: Timestamp::from_nanos(REFUND_DEADLINE),
+    });
+    let code_ids = vending_minter_code_ids(app);
+    configure_minter(
+        app,
+        creator,
+        vec![mock_collection_params_1(Some(start_time))],
+        vec![minter_params_all(NUM_TOKENS, None, None, Some(init_msg))],
+        code_ids,
+    )
+}
+
+fn mint(app: &mut App, buyer: &Addr, minter_addr: &Addr) -> anyhow::Result<()> {
+    app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint { quantity: None },
+        &coins(MIN_MINT_PRICE, NATIVE_DENOM),
+    )?;
+    Ok(())
+}
+
+fn owned_tokens(app: &App, collection_addr: &Addr, owner: &Addr) -> Vec<String> {
+    let res: TokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    res.tokens
+}
+
+fn query_escrow(app: &App, minter_addr: &Addr) -> EscrowResponse {
+    app.wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Escrow {})
+        .unwrap()
+}
+
+#[test]
+fn escrow_released_after_milestone() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let res = setup_escrow_minter(&mut app, creator.clone());
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    // Proceeds stay in the minter
+    let creator_balance = app.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    let escrow = query_escrow(&app, &minter_addr);
+    assert_eq!(escrow.balance, coins(ESCROWED_PER_TOKEN, NATIVE_DENOM));
+    assert!(!escrow.milestone_reached);
+    let token_id: u32 = owned_tokens(&app, &collection_addr, &buyer)[0]
+        .parse()
+        .unwrap();
+    let res: TokenEscrowResponse = app
+        .wrap()
+        .query_wasm_smart(&minter_addr, &QueryMsg::TokenEscrow { token_id })
+        .unwrap();
+    assert_eq!(res.amount, Some(coin(ESCROWED_PER_TOKEN, NATIVE_DENOM)));
+
+    let release_msg = ExecuteMsg::ReleaseEscrow {};
+    let err = app
+        .execute_contract(creator.clone(), minter_addr.clone(), &release_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Escrow milestone not reached"
+    );
+
+    // Half of the tokens sold reaches the milestone
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    app.execute_contract(creator.clone(), minter_addr.clone(), &release_msg, &[])
+        .unwrap();
+    let creator_balance_after = app.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        creator_balance_after.amount - creator_balance.amount,
+        Uint128::new(ESCROWED_PER_TOKEN * 2)
+    );
+    let escrow = query_escrow(&app, &minter_addr);
+    assert!(escrow.released);
+    assert!(escrow.balance.is_empty());
+
+    // Later mints pay out right away
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    let creator_balance_final = app.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        creator_balance_final.amount - creator_balance_after.amount,
+        Uint128::new(ESCROWED_PER_TOKEN)
+    );
+
+    let err = app
+        .execute_contract(creator, minter_addr, &release_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Escrowed proceeds already released"
+    );
+}
+
+#[test]
+fn refund_after_deadline() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let res = setup_escrow_minter(&mut app, creator.clone());
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    let token_id: u32 = owned_tokens(&app, &collection_addr, &buyer)[0]
+        .parse()
+        .unwrap();
+    let refund_msg = ExecuteMsg::ClaimRefund { token_id };
+    let err = app
+        .execute_contract(buyer.clone(), minter_addr.clone(), &refund_msg, &[])
+        .unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "Refunds are not open");
+
+    // The milestone wasn't reached by the deadline, so sales stop and refunds open
+    setup_block_time(&mut app, REFUND_DEADLINE, None);
+    assert!(query_escrow(&app, &minter_addr).refunds_open);
+    let err = mint(&mut app, &buyer, &minter_addr).unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "Refunds are open");
+
+    let err = app
+        .execute_contract(creator.clone(), minter_addr.clone(), &refund_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Unauthorized: Sender is not the token owner"
+    );
+
+    // The minter burns the token, so it has to be approved first
+    app.execute_contract(
+        buyer.clone(),
+        collection_addr.clone(),
+        &Sg721ExecuteMsg::<Extension, Empty>::Approve {
+            spender: minter_addr.to_string(),
+            token_id: token_id.to_string(),
+            expires: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let buyer_balance = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    app.execute_contract(buyer.clone(), minter_addr.clone(), &refund_msg, &[])
+        .unwrap();
+    let buyer_balance_after = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        buyer_balance_after.amount - buyer_balance.amount,
+        Uint128::new(ESCROWED_PER_TOKEN)
+    );
+    assert!(owned_tokens(&app, &collection_addr, &buyer).is_empty());
+    assert!(query_escrow(&app, &minter_addr).balance[0].amount.is_zero());
+
+    let err = app
+        .execute_contract(creator, minter_addr, &ExecuteMsg::ReleaseEscrow {}, &[])
+        .unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "Refunds are open");
+}
+
+#[test]
+fn burn_remaining_opens_refunds() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let res = setup_escrow_minter(&mut app, creator.clone());
+    let minter_addr = res[0].minter.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    app.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::BurnRemaining {},
+        &[],
+    )
+    .unwrap();
+    assert!(query_escrow(&app, &minter_addr).refunds_open);
+}
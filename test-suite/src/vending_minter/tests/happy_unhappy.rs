@@ -6,11 +6,13 @@ use crate::common_setup::{
 use cosmwasm_std::{
     coin, coins,
     testing::{mock_dependencies_with_balance, mock_env, mock_info},
-    Api, Coin, Timestamp, Uint128,
+    Api, Coin, Decimal, Timestamp, Uint128,
 };
 use cw721::{Cw721QueryMsg, OwnerOfResponse};
 use cw_multi_test::Executor;
 use sg2::tests::mock_collection_params_1;
+use sg721::RoyaltyInfoResponse;
+use sg721_base::msg::{Cw2981QueryMsg, QueryMsg as Sg721QueryMsg, RoyaltiesInfoResponse};
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 use vending_minter::msg::{ExecuteMsg, QueryMsg, StartTimeResponse};
 use vending_minter::{contract::instantiate, msg::MintCountResponse};
@@ -102,7 +104,7 @@ fn happy_path() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
 
     // Fail with incorrect tokens
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let err = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -112,7 +114,7 @@ fn happy_path() {
     assert!(err.is_err());
 
     // Succeeds if funds are sent
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -163,6 +165,8 @@ fn happy_path() {
     // Buyer can't call MintTo
     let mint_to_msg = ExecuteMsg::MintTo {
         recipient: buyer.to_string(),
+        quantity: None,
+        royalty_info: None,
     };
     let res = router.execute_contract(
         buyer.clone(),
@@ -219,7 +223,7 @@ fn happy_path() {
     assert_eq!(res.owner, buyer.to_string());
 
     // Errors if sold out
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -267,7 +271,7 @@ fn unhappy_path() {
     let (mut router, _, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
     let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
     // Fails if too little funds are sent
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -277,7 +281,7 @@ fn unhappy_path() {
     assert!(res.is_err());
 
     // Fails if too many funds are sent
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -287,7 +291,52 @@ fn unhappy_path() {
     assert!(res.is_err());
 
     // Fails wrong denom is sent
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer, minter_addr, &mint_msg, &coins(MINT_PRICE, "uatom"));
     assert!(res.is_err());
 }
+
+#[test]
+fn admin_mint_token_royalty() {
+    let vt = vending_minter_template(2);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
+
+    let mint_for_msg = |share: u64| ExecuteMsg::MintFor {
+        token_id: 1,
+        recipient: buyer.to_string(),
+        royalty_info: Some(RoyaltyInfoResponse {
+            payment_address: "artist".to_string(),
+            share: Decimal::percent(share),
+        }),
+    };
+
+    // Token royalties can't go above the maximum share
+    let err = router
+        .execute_contract(creator.clone(), minter_addr.clone(), &mint_for_msg(11), &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "InvalidRoyalties: Share cannot be greater than 10%"
+    );
+
+    router
+        .execute_contract(creator, minter_addr, &mint_for_msg(5), &[])
+        .unwrap();
+    let res: RoyaltiesInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Sg721QueryMsg::Extension {
+                msg: Cw2981QueryMsg::RoyaltyInfo {
+                    token_id: "1".to_string(),
+                    sale_price: Uint128::new(1_000),
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(res.address, "artist");
+    assert_eq!(res.royalty_amount, Uint128::new(50));
+}
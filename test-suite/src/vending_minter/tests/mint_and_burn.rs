@@ -70,7 +70,7 @@ fn update_mint_price() {
     assert!(res.is_ok());
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -185,7 +185,7 @@ fn update_discount_mint_price() {
     );
 
     // Mint succeeds at discount price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr.clone(),
@@ -285,7 +285,7 @@ fn burn_remaining() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
 
     // Succeeds if funds are sent
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -323,6 +323,8 @@ fn burn_remaining() {
     // Buyer can't call MintTo
     let mint_to_msg = ExecuteMsg::MintTo {
         recipient: buyer.to_string(),
+        quantity: None,
+        royalty_info: None,
     };
     // Creator mints an extra NFT for the buyer (who is a friend)
     let res = router.execute_contract(
@@ -371,7 +373,7 @@ fn burn_remaining() {
     );
 
     // Errors if sold out
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr.clone(),
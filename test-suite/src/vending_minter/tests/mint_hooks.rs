@@ -0,0 +1,179 @@
+use crate::common_setup::mint_hooks::{contract_mint_hook, MockHookInstantiateMsg};
+use crate::common_setup::{
+    setup_accounts_and_block::setup_block_time, templates::vending_minter_template,
+};
+use cosmwasm_std::{coins, Addr};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::Executor;
+use sg_controllers::HooksResponse;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_minter::msg::{ExecuteMsg, QueryMsg};
+
+const MINT_PRICE: u128 = 100_000_000;
+
+fn instantiate_hook(router: &mut crate::common_setup::contract_boxes::App, fail: bool) -> Addr {
+    let code_id = router.store_code(contract_mint_hook());
+    router
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("hook_owner"),
+            &MockHookInstantiateMsg { fail },
+            &[],
+            "mint-hook",
+            None,
+        )
+        .unwrap()
+}
+
+#[test]
+fn mint_hooks() {
+    let vt = vending_minter_template(10);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1, None);
+
+    let hook = instantiate_hook(&mut router, false);
+    let failing_hook = instantiate_hook(&mut router, true);
+
+    // Only the admin can add hooks
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::AddPreMintHook {
+                hook: hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    for msg in [
+        ExecuteMsg::AddPreMintHook {
+            hook: hook.to_string(),
+        },
+        ExecuteMsg::AddPostMintHook {
+            hook: hook.to_string(),
+        },
+    ] {
+        router
+            .execute_contract(creator.clone(), minter_addr.clone(), &msg, &[])
+            .unwrap();
+    }
+
+    let res: HooksResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::PreMintHooks {})
+        .unwrap();
+    assert_eq!(res.hooks, vec![hook.to_string()]);
+    let res: HooksResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::PostMintHooks {})
+        .unwrap();
+    assert_eq!(res.hooks, vec![hook.to_string()]);
+
+    // Hooks are called around every minted token
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: Some(2) },
+            &coins(MINT_PRICE * 2, NATIVE_DENOM),
+        )
+        .unwrap();
+    let hook_actions: Vec<String> = res
+        .events
+        .iter()
+        .filter(|e| {
+            e.ty == "wasm"
+                && e.attributes
+                    .iter()
+                    .any(|a| a.key == "_contract_address" && a.value == hook.as_str())
+        })
+        .flat_map(|e| e.attributes.iter().filter(|a| a.key == "action"))
+        .map(|a| a.value.clone())
+        .collect();
+    assert_eq!(
+        hook_actions,
+        vec![
+            "pre_mint_hook",
+            "post_mint_hook",
+            "pre_mint_hook",
+            "post_mint_hook"
+        ]
+    );
+
+    // A failing hook rolls back the whole mint
+    router
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::AddPreMintHook {
+                hook: failing_hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let err = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "pre-mint hook failed");
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens.len(), 2);
+
+    // Removing the failing hook allows minting again
+    router
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::RemoveHook {
+                hook: failing_hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+
+    // Removing a hook that is not registered fails
+    let err = router
+        .execute_contract(
+            creator,
+            minter_addr,
+            &ExecuteMsg::RemoveHook {
+                hook: failing_hook.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Given address not registered as a hook"
+    );
+}
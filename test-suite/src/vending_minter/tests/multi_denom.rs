@@ -0,0 +1,190 @@
+use cosmwasm_std::{coin, coins, Addr, Coin};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use sg2::msg::Sg2ExecuteMsg;
+use sg2::query::{MintersResponse, Sg2QueryMsg};
+use sg2::tests::mock_collection_params;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::VendingMinterInitMsgExtension;
+use vending_minter::msg::{ExecuteMsg, MintPriceResponse, QueryMsg};
+
+use crate::common_setup::contract_boxes::{custom_mock_app, App};
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::constants::{
+    CREATION_FEE, LIQUIDITY_DAO_ADDRESS, MINT_PRICE,
+};
+use crate::common_setup::setup_minter::vending_minter::mock_params::{
+    mock_create_minter_init_msg, mock_init_extension, mock_params,
+};
+use crate::common_setup::setup_minter::vending_minter::setup::vending_minter_code_ids;
+
+const USDC: &str = "ibc/usdc";
+const MIN_USDC_MINT_PRICE: u128 = 1_000_000;
+const USDC_MINT_PRICE: u128 = 5_000_000;
+
+// Factory that accepts USDC next to STARS, returns the factory and sg721 code id
+fn setup_multi_denom_factory(app: &mut App, creator: &Addr) -> (Addr, u64) {
+    let code_ids = vending_minter_code_ids(app);
+    let mut params = mock_params(None);
+    params.code_id = code_ids.minter_code_id;
+    params.additional_min_mint_prices = vec![coin(MIN_USDC_MINT_PRICE, USDC)];
+
+    let factory_addr = app
+        .instantiate_contract(
+            code_ids.factory_code_id,
+            creator.clone(),
+            &vending_factory::msg::InstantiateMsg { params },
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    (factory_addr, code_ids.sg721_code_id)
+}
+
+fn create_minter(
+    app: &mut App,
+    creator: &Addr,
+    factory_addr: &Addr,
+    sg721_code_id: u64,
+    init_msg: VendingMinterInitMsgExtension,
+) -> Result<cw_multi_test::AppResponse, anyhow::Error> {
+    let mut msg = mock_create_minter_init_msg(mock_collection_params(), init_msg);
+    msg.collection_params.code_id = sg721_code_id;
+    msg.collection_params.info.creator = creator.to_string();
+    app.execute_contract(
+        creator.clone(),
+        factory_addr.clone(),
+        &Sg2ExecuteMsg::CreateMinter(msg),
+        &coins(CREATION_FEE, NATIVE_DENOM),
+    )
+}
+
+fn init_msg_with_prices(additional_mint_prices: Vec<Coin>) -> VendingMinterInitMsgExtension {
+    let mut init_msg = mock_init_extension(None, None);
+    init_msg.mint_price = coin(MINT_PRICE, NATIVE_DENOM);
+    init_msg.additional_mint_prices = additional_mint_prices;
+    init_msg
+}
+
+#[test]
+fn mint_in_additional_denom() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let (factory_addr, sg721_code_id) = setup_multi_denom_factory(&mut app, &creator);
+
+    let init_msg = init_msg_with_prices(vec![coin(USDC_MINT_PRICE, USDC)]);
+    create_minter(&mut app, &creator, &factory_addr, sg721_code_id, init_msg).unwrap();
+    let res: MintersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &Sg2QueryMsg::MintersByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let minter_addr = res.minters[0].minter.clone();
+
+    let res: MintPriceResponse = app
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::MintPrice {})
+        .unwrap();
+    assert_eq!(res.public_price, coin(MINT_PRICE, NATIVE_DENOM));
+    assert_eq!(res.additional_prices, vec![coin(USDC_MINT_PRICE, USDC)]);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: buyer.to_string(),
+        amount: coins(USDC_MINT_PRICE * 2, USDC),
+    }))
+    .unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    // Paying the wrong amount in the additional denom fails
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &mint_msg,
+            &coins(USDC_MINT_PRICE - 1, USDC),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "IncorrectPaymentAmount {}{} != {}{}",
+            USDC_MINT_PRICE - 1,
+            USDC,
+            USDC_MINT_PRICE,
+            USDC
+        )
+    );
+
+    // Mint fees are taken in the denom the buyer paid in
+    let res = app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &coins(USDC_MINT_PRICE, USDC),
+    );
+    assert!(res.is_ok());
+    let creator_balance = app.wrap().query_balance(&creator, USDC).unwrap();
+    assert_eq!(creator_balance.amount.u128(), USDC_MINT_PRICE * 9 / 10);
+    let liquidity_dao_balance = app
+        .wrap()
+        .query_balance(LIQUIDITY_DAO_ADDRESS, USDC)
+        .unwrap();
+    assert_eq!(
+        liquidity_dao_balance.amount.u128(),
+        USDC_MINT_PRICE / 10 / 5
+    );
+
+    // The mint price denom is still accepted
+    let res = app.execute_contract(
+        buyer,
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn invalid_additional_mint_prices() {
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    let (factory_addr, sg721_code_id) = setup_multi_denom_factory(&mut app, &creator);
+
+    // Denom without a floor set by governance
+    let init_msg = init_msg_with_prices(vec![coin(USDC_MINT_PRICE, "ibc/other")]);
+    let err =
+        create_minter(&mut app, &creator, &factory_addr, sg721_code_id, init_msg).unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "DenomMismatch");
+
+    // Below the floor for the denom
+    let init_msg = init_msg_with_prices(vec![coin(MIN_USDC_MINT_PRICE - 1, USDC)]);
+    let err =
+        create_minter(&mut app, &creator, &factory_addr, sg721_code_id, init_msg).unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "Minimum network mint price {} got {}",
+            MIN_USDC_MINT_PRICE,
+            MIN_USDC_MINT_PRICE - 1
+        )
+    );
+
+    // Same denom listed twice
+    let init_msg = init_msg_with_prices(vec![
+        coin(USDC_MINT_PRICE, USDC),
+        coin(USDC_MINT_PRICE, USDC),
+    ]);
+    let err =
+        create_minter(&mut app, &creator, &factory_addr, sg721_code_id, init_msg).unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!("Duplicate denom {USDC}")
+    );
+}
@@ -0,0 +1,456 @@
+use crate::common_setup::contract_boxes::{custom_mock_app, App};
+use crate::common_setup::mint_hooks::{contract_mint_hook, MockHookInstantiateMsg};
+use crate::common_setup::msg::{CodeIds, MinterCollectionResponse};
+use crate::common_setup::randomness_beacon::{contract_randomness_beacon, MockBeaconExecuteMsg};
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::constants::MIN_MINT_PRICE;
+use crate::common_setup::setup_minter::common::minter_params::minter_params_all;
+use crate::common_setup::setup_minter::vending_minter::mock_params::mock_init_extension;
+use crate::common_setup::setup_minter::vending_minter::setup::{
+    configure_minter, vending_minter_code_ids,
+};
+use cosmwasm_std::{coins, Addr, Empty, Event, HexBinary, Timestamp};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::{AppResponse, Executor};
+use sg2::tests::mock_collection_params_1;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::RandomnessSource;
+use vending_minter::contract::{BEACON_TIMEOUT_BLOCKS, REVEAL_WINDOW_BLOCKS};
+use vending_minter::msg::{ExecuteMsg, MintableNumTokensResponse, NoisCallback, QueryMsg};
+
+const NUM_TOKENS: u32 = 3;
+const REVEAL_DELAY_BLOCKS: u64 = 2;
+
+fn setup_minter(
+    app: &mut App,
+    creator: Addr,
+    code_ids: CodeIds,
+    randomness: RandomnessSource,
+) -> Vec<MinterCollectionResponse> {
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let mut init_msg = mock_init_extension(None, Some(start_time));
+    init_msg.num_tokens = NUM_TOKENS;
+    init_msg.randomness = randomness;
+    configure_minter(
+        app,
+        creator,
+        vec![mock_collection_params_1(Some(start_time))],
+        vec![minter_params_all(NUM_TOKENS, None, None, Some(init_msg))],
+        code_ids,
+    )
+}
+
+fn mint(app: &mut App, buyer: &Addr, minter_addr: &Addr, quantity: u32) -> AppResponse {
+    app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {
+            quantity: Some(quantity),
+        },
+        &coins(MIN_MINT_PRICE * quantity as u128, NATIVE_DENOM),
+    )
+    .unwrap()
+}
+
+fn owned_tokens(app: &App, collection_addr: &Addr, owner: &Addr) -> Vec<String> {
+    let res: TokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    res.tokens
+}
+
+fn mintable_num_tokens(app: &App, minter_addr: &Addr) -> u32 {
+    let res: MintableNumTokensResponse = app
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintableNumTokens {})
+        .unwrap();
+    res.count
+}
+
+#[test]
+fn commit_reveal_assigns_tokens_after_delay() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let code_ids = vending_minter_code_ids(&mut app);
+    let res = setup_minter(
+        &mut app,
+        creator.clone(),
+        code_ids,
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks: REVEAL_DELAY_BLOCKS,
+        },
+    );
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+    let commit_height = app.block_info().height;
+
+    // Minting only commits, the tokens are reserved but not assigned yet
+    let res = mint(&mut app, &buyer, &minter_addr, 2);
+    assert!(res.has_event(&Event::new("wasm").add_attribute("pending_mint_id", "1")));
+    assert!(owned_tokens(&app, &collection_addr, &buyer).is_empty());
+    assert_eq!(mintable_num_tokens(&app, &minter_addr), NUM_TOKENS - 2);
+
+    let reveal_msg = ExecuteMsg::RevealMint { id: 1 };
+    let err = app
+        .execute_contract(buyer.clone(), minter_addr.clone(), &reveal_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "Pending mint can be revealed at height {}",
+            commit_height + REVEAL_DELAY_BLOCKS
+        )
+    );
+
+    // Remaining tokens can't be burned while a pending mint still needs them
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::BurnRemaining {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Pending mints must be assigned first"
+    );
+
+    // Anyone can reveal once the delay passed, the tokens go to the buyer
+    setup_block_time(
+        &mut app,
+        GENESIS_MINT_START_TIME + 100,
+        Some(commit_height + REVEAL_DELAY_BLOCKS),
+    );
+    app.execute_contract(creator, minter_addr.clone(), &reveal_msg, &[])
+        .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 2);
+
+    let err = app
+        .execute_contract(buyer, minter_addr, &reveal_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Pending mint 1 not found"
+    );
+}
+
+#[test]
+fn beacon_delivers_randomness() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let (beacon_addr, res) = setup_beacon_minter(&mut app, creator);
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    mint(&mut app, &buyer, &minter_addr, 1);
+    assert!(owned_tokens(&app, &collection_addr, &buyer).is_empty());
+
+    // Only the beacon can deliver randomness
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::NoisReceive {
+                callback: NoisCallback {
+                    job_id: "1".to_string(),
+                    published: app.block_info().time,
+                    randomness: HexBinary::from([0u8; 32]),
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Unauthorized: Sender is not the randomness beacon"
+    );
+
+    app.execute_contract(
+        buyer.clone(),
+        beacon_addr,
+        &MockBeaconExecuteMsg::Publish {
+            randomness: HexBinary::from([7u8; 32]),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 1);
+    assert_eq!(mintable_num_tokens(&app, &minter_addr), NUM_TOKENS - 1);
+}
+
+fn setup_beacon_minter(app: &mut App, creator: Addr) -> (Addr, Vec<MinterCollectionResponse>) {
+    // Minter codes are stored first, the factory params expect their code ids
+    let code_ids = vending_minter_code_ids(app);
+    let beacon_code_id = app.store_code(contract_randomness_beacon());
+    let beacon_addr = app
+        .instantiate_contract(
+            beacon_code_id,
+            creator.clone(),
+            &Empty {},
+            &[],
+            "beacon",
+            None,
+        )
+        .unwrap();
+    let res = setup_minter(
+        app,
+        creator,
+        code_ids,
+        RandomnessSource::Beacon {
+            address: beacon_addr.to_string(),
+        },
+    );
+    (beacon_addr, res)
+}
+
+#[test]
+fn late_reveal_is_refunded() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let code_ids = vending_minter_code_ids(&mut app);
+    let res = setup_minter(
+        &mut app,
+        creator.clone(),
+        code_ids,
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks: REVEAL_DELAY_BLOCKS,
+        },
+    );
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    // The last block of the reveal window still reveals
+    let commit_height = app.block_info().height;
+    mint(&mut app, &buyer, &minter_addr, 1);
+    let window_end = commit_height + REVEAL_DELAY_BLOCKS + REVEAL_WINDOW_BLOCKS;
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 100, Some(window_end));
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::RevealMint { id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 1);
+
+    let commit_height = app.block_info().height;
+    mint(&mut app, &buyer, &minter_addr, 1);
+    let balance_after_mint = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    let window_end = commit_height + REVEAL_DELAY_BLOCKS + REVEAL_WINDOW_BLOCKS;
+
+    // Within the window the pending mint can only be revealed
+    let cancel_msg = ExecuteMsg::CancelPendingMint { id: 2 };
+    let err = app
+        .execute_contract(buyer.clone(), minter_addr.clone(), &cancel_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!("Pending mint can be canceled at height {}", window_end + 1)
+    );
+
+    // Past the window every input of the seed is known, so a late reveal can't pick the token
+    setup_block_time(
+        &mut app,
+        GENESIS_MINT_START_TIME + 200,
+        Some(window_end + 1),
+    );
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::RevealMint { id: 2 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Reveal window of pending mint 2 passed, it can only be canceled"
+    );
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 1);
+
+    // The buyer gets the payment back and the token is mintable again
+    app.execute_contract(buyer.clone(), minter_addr.clone(), &cancel_msg, &[])
+        .unwrap();
+    let balance = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        balance.amount.u128(),
+        balance_after_mint.amount.u128() + MIN_MINT_PRICE
+    );
+    assert_eq!(mintable_num_tokens(&app, &minter_addr), NUM_TOKENS - 1);
+    app.execute_contract(creator, minter_addr, &ExecuteMsg::BurnRemaining {}, &[])
+        .unwrap();
+}
+
+#[test]
+fn beacon_mint_expires() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let (_, res) = setup_beacon_minter(&mut app, creator.clone());
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+    let commit_height = app.block_info().height;
+    let balance_before = app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    mint(&mut app, &buyer, &minter_addr, 1);
+
+    // Beacon mints are never revealed from block data
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::RevealMint { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Invalid randomness source: minter does not use commit-reveal"
+    );
+
+    // The beacon has until the timeout to deliver randomness
+    let cancel_msg = ExecuteMsg::CancelPendingMint { id: 1 };
+    let err = app
+        .execute_contract(creator.clone(), minter_addr.clone(), &cancel_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!(
+            "Pending mint can be canceled at height {}",
+            commit_height + BEACON_TIMEOUT_BLOCKS + 1
+        )
+    );
+
+    // Then anyone can cancel the mint, the buyer is refunded and the remaining tokens can be
+    // burned again
+    setup_block_time(
+        &mut app,
+        GENESIS_MINT_START_TIME + 100,
+        Some(commit_height + BEACON_TIMEOUT_BLOCKS + 1),
+    );
+    app.execute_contract(creator.clone(), minter_addr.clone(), &cancel_msg, &[])
+        .unwrap();
+    assert!(owned_tokens(&app, &collection_addr, &buyer).is_empty());
+    assert_eq!(
+        app.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap(),
+        balance_before
+    );
+    assert_eq!(mintable_num_tokens(&app, &minter_addr), NUM_TOKENS);
+    app.execute_contract(creator, minter_addr, &ExecuteMsg::BurnRemaining {}, &[])
+        .unwrap();
+}
+
+#[test]
+fn pre_mint_hooks_run_at_commit() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let code_ids = vending_minter_code_ids(&mut app);
+    let res = setup_minter(
+        &mut app,
+        creator.clone(),
+        code_ids,
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks: REVEAL_DELAY_BLOCKS,
+        },
+    );
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    let hook_code_id = app.store_code(contract_mint_hook());
+    let mut hooks = vec![];
+    for fail in [false, true] {
+        let hook = app
+            .instantiate_contract(
+                hook_code_id,
+                creator.clone(),
+                &MockHookInstantiateMsg { fail },
+                &[],
+                "mint-hook",
+                None,
+            )
+            .unwrap();
+        hooks.push(hook);
+    }
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::AddPreMintHook {
+            hook: hooks[0].to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The hook runs when the buyer pays, not when the tokens are assigned
+    let commit_height = app.block_info().height;
+    let res = mint(&mut app, &buyer, &minter_addr, 1);
+    assert!(res.has_event(&Event::new("wasm").add_attribute("action", "pre_mint_hook")));
+    setup_block_time(
+        &mut app,
+        GENESIS_MINT_START_TIME + 100,
+        Some(commit_height + REVEAL_DELAY_BLOCKS),
+    );
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::RevealMint { id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert!(!res.has_event(&Event::new("wasm").add_attribute("action", "pre_mint_hook")));
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 1);
+
+    // A rejecting hook fails the mint itself, nothing is left pending
+    app.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::AddPreMintHook {
+            hook: hooks[1].to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(
+            buyer,
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MIN_MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "pre-mint hook failed");
+    assert_eq!(mintable_num_tokens(&app, &minter_addr), NUM_TOKENS - 1);
+}
+
+#[test]
+fn commit_reveal_needs_a_delay() {
+    let mut app = custom_mock_app();
+    let (creator, _) = setup_accounts(&mut app);
+    let code_ids = vending_minter_code_ids(&mut app);
+    let res = setup_minter(
+        &mut app,
+        creator,
+        code_ids,
+        RandomnessSource::CommitReveal {
+            reveal_delay_blocks: 0,
+        },
+    );
+    let err = res[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Invalid randomness source: reveal delay must be at least one block"
+    );
+}
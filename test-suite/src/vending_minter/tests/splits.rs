@@ -1,16 +1,23 @@
 use crate::common_setup::{
-    contract_boxes::{contract_splits, custom_mock_app, App},
+    contract_boxes::{contract_group, contract_splits, custom_mock_app, App},
     msg::MinterCollectionResponse,
     setup_accounts_and_block::{instantiate_group, setup_accounts, setup_block_time},
     setup_minter::{
-        common::minter_params::minter_params_all,
-        vending_minter::setup::{configure_minter, vending_minter_code_ids},
+        common::{constants::CREATION_FEE, minter_params::minter_params_all},
+        vending_minter::{
+            mock_params::{mock_create_minter, mock_params},
+            setup::{build_init_msg, configure_minter, vending_minter_code_ids},
+        },
     },
 };
-use cosmwasm_std::{coins, Addr, Coin, Timestamp};
+use cosmwasm_std::{
+    coins, Addr, Binary, Coin, Deps, DepsMut, Empty, Env, Response, StdError, StdResult, Timestamp,
+};
 use cw4::Member;
-use cw_multi_test::{next_block, Executor};
+use cw_multi_test::{next_block, AppResponse, Contract, ContractWrapper, Executor};
+use sg2::msg::{Sg2ExecuteMsg, SplitsParams};
 use sg2::tests::mock_collection_params_1;
+use sg2::SplitsCodeIds;
 
 use sg_splits::msg::{ExecuteMsg as SplitsExecuteMsg, Group};
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
@@ -30,19 +37,41 @@ pub fn member<T: Into<String>>(addr: T, weight: u64) -> Member {
 }
 
 #[track_caller]
-fn instantiate_splits(app: &mut App, group_addr: Addr) -> Addr {
+fn instantiate_splits(
+    app: &mut App,
+    group_addr: Addr,
+    distribution_threshold: Option<Vec<Coin>>,
+) -> Addr {
     let splits_id = app.store_code(contract_splits());
     println!("splits_id: {splits_id}");
+
+    // the group calls splits on member changes
+    let splits_addr = (0..)
+        .map(|i| Addr::unchecked(format!("contract{i}")))
+        .find(|addr| app.contract_data(addr).is_err())
+        .unwrap();
+    let hook_msg = cw4::Cw4ExecuteMsg::AddHook {
+        addr: splits_addr.to_string(),
+    };
+    app.execute_contract(Addr::unchecked(OWNER), group_addr.clone(), &hook_msg, &[])
+        .unwrap();
+
     let msg = sg_splits::msg::InstantiateMsg {
         group: Group::Cw4Address(group_addr.to_string()),
         admin: None,
+        distribution_threshold,
+        cw20_tokens: None,
     };
     app.instantiate_contract(splits_id, Addr::unchecked(OWNER), &msg, &[], "splits", None)
         .unwrap()
 }
 
 #[track_caller]
-fn setup_splits_test_case(app: &mut App, init_funds: Vec<Coin>) -> (Addr, Addr) {
+fn setup_splits_test_case(
+    app: &mut App,
+    init_funds: Vec<Coin>,
+    distribution_threshold: Option<Vec<Coin>>,
+) -> (Addr, Addr) {
     // 1. Instantiate group contract with members (and OWNER as admin)
     let members = vec![
         member(OWNER, 50),
@@ -54,7 +83,7 @@ fn setup_splits_test_case(app: &mut App, init_funds: Vec<Coin>) -> (Addr, Addr)
     app.update_block(next_block);
 
     // 2. Set up Splits backed by this group
-    let splits_addr = instantiate_splits(app, group_addr.clone());
+    let splits_addr = instantiate_splits(app, group_addr.clone(), distribution_threshold);
     app.update_block(next_block);
 
     // Bonus: set some funds on the splits contract for future proposals
@@ -69,7 +98,7 @@ fn setup_splits_test_case(app: &mut App, init_funds: Vec<Coin>) -> (Addr, Addr)
 fn mint_and_split() {
     let mut app = custom_mock_app();
 
-    let (splits_addr, _) = setup_splits_test_case(&mut app, vec![]);
+    let (splits_addr, _) = setup_splits_test_case(&mut app, vec![], None);
     let (creator, buyer) = setup_accounts(&mut app);
     let num_tokens = 2;
     let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
@@ -86,7 +115,7 @@ fn mint_and_split() {
     let minter_addr = minter_collection_response[0].minter.clone().unwrap();
     setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
 
-    let mint_msg = vending_minter::msg::ExecuteMsg::Mint {};
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
     let res = app.execute_contract(
         buyer,
         minter_addr,
@@ -95,7 +124,10 @@ fn mint_and_split() {
     );
     assert!(res.is_ok());
 
-    let dist_msg = SplitsExecuteMsg::Distribute { denom_list: None };
+    let dist_msg = SplitsExecuteMsg::Distribute {
+        denom_list: None,
+        cw20_list: None,
+    };
     let res = app.execute_contract(Addr::unchecked(OWNER), splits_addr, &dist_msg, &[]);
     assert!(res.is_ok());
 
@@ -108,3 +140,242 @@ fn mint_and_split() {
     let amount = app.wrap().query_balance(MEMBER3, NATIVE_DENOM).unwrap();
     assert_eq!(amount.amount.u128(), 4500000);
 }
+
+#[test]
+fn mint_and_split_on_threshold() {
+    let mut app = custom_mock_app();
+
+    let (splits_addr, _) =
+        setup_splits_test_case(&mut app, vec![], Some(coins(1_000_000, NATIVE_DENOM)));
+    let (creator, buyer) = setup_accounts(&mut app);
+    let num_tokens = 2;
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let minter_params = minter_params_all(num_tokens, Some(splits_addr.to_string()), None, None);
+    let collection_params = mock_collection_params_1(Some(start_time));
+    let code_ids = vending_minter_code_ids(&mut app);
+    let minter_collection_response: Vec<MinterCollectionResponse> = configure_minter(
+        &mut app,
+        creator,
+        vec![collection_params],
+        vec![minter_params],
+        code_ids,
+    );
+    let minter_addr = minter_collection_response[0].minter.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    // Proceeds are deposited and distributed without calling Distribute
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
+    let res = app.execute_contract(
+        buyer,
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let amount = app.wrap().query_balance(OWNER, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 45000000);
+    let amount = app.wrap().query_balance(MEMBER1, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 22500000);
+    let amount = app.wrap().query_balance(MEMBER2, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 18000000);
+    let amount = app.wrap().query_balance(MEMBER3, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 4500000);
+    let amount = app.wrap().query_balance(splits_addr, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 0);
+}
+
+// cw4 group whose member list can be made to fail with a sudo call
+fn contract_failing_group() -> Box<dyn Contract<Empty>> {
+    fn query(deps: Deps, env: Env, msg: cw4_group::msg::QueryMsg) -> StdResult<Binary> {
+        if deps.storage.get(b"failing").is_some()
+            && matches!(msg, cw4_group::msg::QueryMsg::ListMembers { .. })
+        {
+            return Err(StdError::generic_err("list members failed"));
+        }
+        cw4_group::contract::query(deps, env, msg)
+    }
+    fn sudo(deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+        deps.storage.set(b"failing", &[1]);
+        Ok(Response::new())
+    }
+
+    let contract = ContractWrapper::new_with_empty(
+        cw4_group::contract::execute,
+        cw4_group::contract::instantiate,
+        query,
+    )
+    .with_sudo_empty(sudo);
+    Box::new(contract)
+}
+
+#[test]
+fn mint_when_split_distribution_fails() {
+    let mut app = custom_mock_app();
+
+    let group_id = app.store_code(contract_failing_group());
+    let msg = cw4_group::msg::InstantiateMsg {
+        admin: Some(OWNER.into()),
+        members: vec![member(OWNER, 50), member(MEMBER1, 50)],
+    };
+    let group_addr = app
+        .instantiate_contract(group_id, Addr::unchecked(OWNER), &msg, &[], "group", None)
+        .unwrap();
+    let splits_addr = instantiate_splits(
+        &mut app,
+        group_addr.clone(),
+        Some(coins(1_000_000, NATIVE_DENOM)),
+    );
+    app.wasm_sudo(group_addr, &Empty {}).unwrap();
+
+    let (creator, buyer) = setup_accounts(&mut app);
+    let num_tokens = 2;
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let minter_params = minter_params_all(num_tokens, Some(splits_addr.to_string()), None, None);
+    let collection_params = mock_collection_params_1(Some(start_time));
+    let code_ids = vending_minter_code_ids(&mut app);
+    let minter_collection_response: Vec<MinterCollectionResponse> = configure_minter(
+        &mut app,
+        creator,
+        vec![collection_params],
+        vec![minter_params],
+        code_ids,
+    );
+    let minter_addr = minter_collection_response[0].minter.clone().unwrap();
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+
+    // The distribution fails but the proceeds are still deposited
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
+    let res = app.execute_contract(
+        buyer,
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let amount = app.wrap().query_balance(MEMBER1, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 0);
+    let amount = app.wrap().query_balance(splits_addr, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 90000000);
+}
+
+fn wasm_attribute(res: &AppResponse, key: &str) -> Addr {
+    let value = res
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm")
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == key)
+        .unwrap()
+        .value
+        .clone();
+    Addr::unchecked(value)
+}
+
+#[test]
+fn create_minter_with_splits() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let code_ids = vending_minter_code_ids(&mut app);
+    let splits_code_id = app.store_code(contract_splits());
+    let group_code_id = app.store_code(contract_group());
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+
+    let mut params = mock_params(None);
+    params.code_id = code_ids.minter_code_id;
+    let factory_addr = app
+        .instantiate_contract(
+            code_ids.factory_code_id,
+            creator.clone(),
+            &vending_factory::msg::InstantiateMsg {
+                params: params.clone(),
+            },
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let mut msg = mock_create_minter(None, mock_collection_params_1(Some(start_time)), None);
+    msg.init_msg = build_init_msg(None, msg.clone(), 2);
+    msg.collection_params.code_id = code_ids.sg721_code_id;
+    msg.collection_params.info.creator = creator.to_string();
+    msg.splits = Some(SplitsParams {
+        members: vec![
+            member(OWNER, 50),
+            member(MEMBER1, 25),
+            member(MEMBER2, 20),
+            member(MEMBER3, 5),
+        ],
+        admin: None,
+        distribution_threshold: Some(coins(1, NATIVE_DENOM)),
+    });
+    let creation_fee = coins(CREATION_FEE, NATIVE_DENOM);
+
+    // Splits can't be created until the factory knows the splits and group code ids
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &Sg2ExecuteMsg::CreateMinter(msg.clone()),
+            &creation_fee,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        base_factory::ContractError::SplitsNotEnabled {}.to_string()
+    );
+
+    let factory_addr = {
+        params.splits_code_ids = Some(SplitsCodeIds {
+            splits: splits_code_id,
+            group: group_code_id,
+        });
+        app.instantiate_contract(
+            code_ids.factory_code_id,
+            creator.clone(),
+            &vending_factory::msg::InstantiateMsg { params },
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap()
+    };
+
+    let res = app
+        .execute_contract(
+            creator,
+            factory_addr,
+            &Sg2ExecuteMsg::CreateMinter(msg),
+            &creation_fee,
+        )
+        .unwrap();
+    let splits_addr = wasm_attribute(&res, "splits");
+    let minter_addr = wasm_attribute(&res, "minter");
+
+    let info: sg1::SplitsInfoResponse = app
+        .wrap()
+        .query_wasm_smart(&splits_addr, &sg_splits::msg::QueryMsg::SplitsInfo {})
+        .unwrap();
+    assert_eq!(info.distribution_threshold, coins(1, NATIVE_DENOM));
+
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+    let mint_msg = vending_minter::msg::ExecuteMsg::Mint { quantity: None };
+    let res = app.execute_contract(
+        buyer,
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let amount = app.wrap().query_balance(OWNER, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 45000000);
+    let amount = app.wrap().query_balance(MEMBER1, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 22500000);
+    let amount = app.wrap().query_balance(MEMBER2, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 18000000);
+    let amount = app.wrap().query_balance(MEMBER3, NATIVE_DENOM).unwrap();
+    assert_eq!(amount.amount.u128(), 4500000);
+}
@@ -0,0 +1,259 @@
+use crate::common_setup::contract_boxes::{contract_tiered_whitelist, custom_mock_app, App};
+use crate::common_setup::setup_accounts_and_block::{setup_accounts, setup_block_time};
+use crate::common_setup::setup_minter::common::minter_params::minter_params_token;
+use crate::common_setup::setup_minter::vending_minter::setup::{
+    configure_minter, vending_minter_code_ids,
+};
+use cosmwasm_std::{coin, coins, Addr, Timestamp};
+use cw_multi_test::Executor;
+use sg2::msg::UpdateMinterParamsMsg;
+use sg2::tests::mock_collection_params_1;
+use sg_tiered_whitelist::msg::{
+    AddStageMsg, ExecuteMsg as TieredWhitelistExecuteMsg,
+    InstantiateMsg as TieredWhitelistInstantiateMsg, QueryMsg as TieredWhitelistQueryMsg,
+    RemoveStageMsg, StagesResponse,
+};
+use sg_tiered_whitelist::state::Stage;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::{SudoMsg, VendingUpdateParamsExtension};
+use vending_minter::msg::{ExecuteMsg, MintCountResponse, QueryMsg};
+
+const WHITELIST_PRICE: u128 = 60_000_000;
+const NUM_STAGES: u32 = 5;
+const STAGE_LENGTH: u64 = 1_000;
+const WL_START: u64 = GENESIS_MINT_START_TIME + 1_000;
+const MINTER_START: u64 = GENESIS_MINT_START_TIME + 10_000;
+
+fn stage_start(stage_id: u32) -> u64 {
+    WL_START + u64::from(stage_id - 1) * STAGE_LENGTH
+}
+
+fn stage(stage_id: u32, mint_count_limit: Option<u32>) -> Stage {
+    Stage {
+        name: format!("Stage {}", stage_id),
+        start_time: Timestamp::from_nanos(stage_start(stage_id)),
+        end_time: Timestamp::from_nanos(stage_start(stage_id) + STAGE_LENGTH),
+        mint_price: coin(WHITELIST_PRICE, NATIVE_DENOM),
+        per_address_limit: 2,
+        mint_count_limit,
+    }
+}
+
+fn mint(app: &mut App, buyer: &Addr, minter_addr: &Addr) -> anyhow::Result<()> {
+    app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint { quantity: None },
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    )?;
+    Ok(())
+}
+
+fn update_max_whitelist_stages(app: &mut App, factory: Addr, max_whitelist_stages: u32) {
+    let update_msg = UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        additional_min_mint_prices: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        fee_routing: None,
+        max_whitelist_stages: Some(max_whitelist_stages),
+        splits_code_ids: None,
+        extension: VendingUpdateParamsExtension {
+            max_token_limit: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            shuffle_fee: None,
+        },
+    };
+    app.wasm_sudo(factory, &SudoMsg::UpdateParams(Box::new(update_msg)))
+        .unwrap();
+}
+
+#[test]
+fn stages_above_governance_max() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    // Minter codes are stored first, the factory params expect their code ids
+    let code_ids = vending_minter_code_ids(&mut app);
+    let res = configure_minter(
+        &mut app,
+        creator.clone(),
+        vec![mock_collection_params_1(Some(start_time))],
+        vec![minter_params_token(10)],
+        code_ids,
+    );
+    let minter_addr = res[0].minter.clone().unwrap();
+    let factory_addr = res[0].factory.clone().unwrap();
+
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME - 1_000, None);
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateStartTime(Timestamp::from_nanos(MINTER_START)),
+        &[],
+    )
+    .unwrap();
+
+    // The last stage caps its total mints
+    let stages = (1..=NUM_STAGES)
+        .map(|stage_id| stage(stage_id, (stage_id == NUM_STAGES).then_some(1)))
+        .collect::<Vec<_>>();
+    let whitelist_code_id = app.store_code(contract_tiered_whitelist());
+    let whitelist_addr = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator.clone(),
+            &TieredWhitelistInstantiateMsg {
+                members: vec![vec![buyer.to_string()]; NUM_STAGES as usize],
+                stages,
+                member_limit: 10,
+                admins: vec![creator.to_string()],
+                admins_mutable: true,
+                factory: None,
+            },
+            &coins(100_000_000, NATIVE_DENOM),
+            "tiered-whitelist",
+            None,
+        )
+        .unwrap();
+    let set_whitelist_msg = ExecuteMsg::SetWhitelist {
+        whitelist: whitelist_addr.to_string(),
+    };
+
+    // The factory accepts 3 stages by default
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &set_whitelist_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: Whitelist has 5 stages, more than the maximum of 3 whitelist stages"
+    );
+    update_max_whitelist_stages(&mut app, factory_addr, NUM_STAGES);
+    app.execute_contract(creator, minter_addr.clone(), &set_whitelist_msg, &[])
+        .unwrap();
+
+    // Stage end times are inclusive, so each stage is entered one nanosecond after its start
+    setup_block_time(&mut app, stage_start(3) + 1, None);
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    setup_block_time(&mut app, stage_start(4) + 1, None);
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+
+    // Mints are counted per stage
+    setup_block_time(&mut app, stage_start(5) + 1, None);
+    mint(&mut app, &buyer, &minter_addr).unwrap();
+    let err = mint(&mut app, &buyer, &minter_addr).unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "WhitelistMintCountLimitReached"
+    );
+    let res: MintCountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::MintCount {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.count, 3);
+}
+
+#[test]
+fn remove_stage_keeps_other_stage_ids() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME - 1_000, None);
+    let whitelist_code_id = app.store_code(contract_tiered_whitelist());
+    let whitelist_addr = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator.clone(),
+            &TieredWhitelistInstantiateMsg {
+                members: vec![
+                    vec![buyer.to_string()],
+                    vec![buyer.to_string(), creator.to_string()],
+                    vec![creator.to_string()],
+                ],
+                stages: (1..=3).map(|stage_id| stage(stage_id, None)).collect(),
+                member_limit: 10,
+                admins: vec![creator.to_string()],
+                admins_mutable: true,
+                factory: None,
+            },
+            &coins(100_000_000, NATIVE_DENOM),
+            "tiered-whitelist",
+            None,
+        )
+        .unwrap();
+
+    // Removing the second stage leaves the stage after it untouched
+    app.execute_contract(
+        creator.clone(),
+        whitelist_addr.clone(),
+        &TieredWhitelistExecuteMsg::RemoveStage(RemoveStageMsg { stage_id: 1 }),
+        &[],
+    )
+    .unwrap();
+    let res: StagesResponse = app
+        .wrap()
+        .query_wasm_smart(whitelist_addr.clone(), &TieredWhitelistQueryMsg::Stages {})
+        .unwrap();
+    let stages = res
+        .stages
+        .iter()
+        .map(|stage| {
+            (
+                stage.stage_id,
+                stage.stage.name.as_str(),
+                stage.member_count,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(stages, vec![(0, "Stage 1", 1), (2, "Stage 3", 1)]);
+
+    // New stages get an id after the last one
+    app.execute_contract(
+        creator.clone(),
+        whitelist_addr.clone(),
+        &TieredWhitelistExecuteMsg::AddStage(AddStageMsg {
+            stage: stage(4, None),
+            members: vec![buyer.to_string()],
+        }),
+        &[],
+    )
+    .unwrap();
+    let res: StagesResponse = app
+        .wrap()
+        .query_wasm_smart(whitelist_addr.clone(), &TieredWhitelistQueryMsg::Stages {})
+        .unwrap();
+    let stage_ids = res
+        .stages
+        .iter()
+        .map(|stage| stage.stage_id)
+        .collect::<Vec<_>>();
+    assert_eq!(stage_ids, vec![0, 2, 3]);
+
+    // Started stages can't be removed
+    setup_block_time(&mut app, stage_start(3) + 1, None);
+    let err = app
+        .execute_contract(
+            creator,
+            whitelist_addr,
+            &TieredWhitelistExecuteMsg::RemoveStage(RemoveStageMsg { stage_id: 2 }),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "AlreadyStarted");
+}
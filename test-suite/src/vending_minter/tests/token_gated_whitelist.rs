@@ -0,0 +1,306 @@
+use crate::common_setup::contract_boxes::{contract_whitelist_token_gated, custom_mock_app, App};
+use crate::common_setup::setup_accounts_and_block::{
+    setup_accounts, setup_block_time, CREATION_FEE,
+};
+use crate::common_setup::setup_minter::common::minter_params::minter_params_token;
+use crate::common_setup::setup_minter::vending_minter::setup::{
+    configure_minter, vending_minter_code_ids,
+};
+use cosmwasm_std::{coin, coins, Addr, Timestamp};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use sg2::tests::{mock_collection_params_1, mock_collection_two};
+use sg_mint_hooks::pre::PreMintHookMsg;
+use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use sg_whitelist_token_gated::msg::{
+    ClaimsResponse, ExecuteMsg as WhitelistExecuteMsg, HasMemberResponse,
+    InstantiateMsg as WhitelistInstantiateMsg, QueryMsg as WhitelistQueryMsg,
+};
+use vending_minter::msg::ExecuteMsg;
+
+const MINT_PRICE: u128 = 100_000_000;
+const WHITELIST_PRICE: u128 = 60_000_000;
+const WL_START: u64 = GENESIS_MINT_START_TIME + 1_000;
+const WL_END: u64 = GENESIS_MINT_START_TIME + 5_000;
+const MINTER_START: u64 = GENESIS_MINT_START_TIME + 10_000;
+
+fn owned_tokens(app: &App, collection_addr: &Addr, owner: &Addr) -> Vec<String> {
+    let res: TokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    res.tokens
+}
+
+fn has_member(app: &App, whitelist_addr: &Addr, member: &Addr) -> bool {
+    let res: HasMemberResponse = app
+        .wrap()
+        .query_wasm_smart(
+            whitelist_addr,
+            &WhitelistQueryMsg::HasMember {
+                member: member.to_string(),
+            },
+        )
+        .unwrap();
+    res.has_member
+}
+
+#[test]
+fn holders_mint_through_token_gate() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    // Two minters are created, so the creator needs a second creation fee
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: creator.to_string(),
+        amount: coins(CREATION_FEE, NATIVE_DENOM),
+    }))
+    .unwrap();
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    // Minter codes are stored first, the factory params expect their code ids
+    let code_ids = vending_minter_code_ids(&mut app);
+    // The first collection is gated on holding a token of the second one
+    let res = configure_minter(
+        &mut app,
+        creator.clone(),
+        vec![
+            mock_collection_params_1(Some(start_time)),
+            mock_collection_two(Some(start_time)),
+        ],
+        vec![minter_params_token(10), minter_params_token(10)],
+        code_ids,
+    );
+    let minter_addr = res[0].minter.clone().unwrap();
+    let collection_addr = res[0].collection.clone().unwrap();
+    let holder_minter_addr = res[1].minter.clone().unwrap();
+    let holder_collection_addr = res[1].collection.clone().unwrap();
+
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME - 1_000, None);
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateStartTime(Timestamp::from_nanos(MINTER_START)),
+        &[],
+    )
+    .unwrap();
+
+    let whitelist_code_id = app.store_code(contract_whitelist_token_gated());
+    let mut instantiate_msg = WhitelistInstantiateMsg {
+        collections: vec![holder_collection_addr.to_string()],
+        mints_per_token: 2,
+        start_time: Timestamp::from_nanos(WL_START),
+        end_time: Timestamp::from_nanos(WL_END),
+        mint_price: coin(WHITELIST_PRICE, NATIVE_DENOM),
+        per_address_limit: 5,
+        // Claims can't be recorded without pre-mint hooks
+        minter: Some(collection_addr.to_string()),
+        admins: vec![creator.to_string()],
+        admins_mutable: true,
+    };
+    let err = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator.clone(),
+            &instantiate_msg,
+            &[],
+            "token-gated-whitelist",
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("MinterWithoutPreMintHooks: {collection_addr}")
+    );
+
+    instantiate_msg.minter = Some(minter_addr.to_string());
+    let whitelist_addr = app
+        .instantiate_contract(
+            whitelist_code_id,
+            creator.clone(),
+            &instantiate_msg,
+            &[],
+            "token-gated-whitelist",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetWhitelist {
+            whitelist: whitelist_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The buyer gets a token of the holder collection
+    setup_block_time(&mut app, GENESIS_MINT_START_TIME + 1, None);
+    assert!(!has_member(&app, &whitelist_addr, &buyer));
+    app.execute_contract(
+        buyer.clone(),
+        holder_minter_addr,
+        &ExecuteMsg::Mint { quantity: None },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    )
+    .unwrap();
+    let holder_token_id = owned_tokens(&app, &holder_collection_addr, &buyer)[0].clone();
+
+    // Holders pick the token their mints claim against, it must be theirs and of a gated collection
+    let set_claim_token = |collection: &Addr| WhitelistExecuteMsg::SetClaimToken {
+        collection: collection.to_string(),
+        token_id: holder_token_id.clone(),
+    };
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            whitelist_addr.clone(),
+            &set_claim_token(&holder_collection_addr),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("NotTokenOwner: {holder_collection_addr} {holder_token_id}")
+    );
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            whitelist_addr.clone(),
+            &set_claim_token(&collection_addr),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("CollectionNotGated: {collection_addr}")
+    );
+    assert!(!has_member(&app, &whitelist_addr, &buyer));
+    app.execute_contract(
+        buyer.clone(),
+        whitelist_addr.clone(),
+        &set_claim_token(&holder_collection_addr),
+        &[],
+    )
+    .unwrap();
+
+    // Holders only become members once their mints consume claims
+    assert!(!has_member(&app, &whitelist_addr, &buyer));
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::AddPreMintHook {
+            hook: whitelist_addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert!(has_member(&app, &whitelist_addr, &buyer));
+
+    setup_block_time(&mut app, WL_START, None);
+
+    // Addresses without a holder token can't mint while the gate is active
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(WHITELIST_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!("address not on whitelist: {}", creator)
+    );
+
+    // Admin mints aren't gated
+    app.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintTo {
+            recipient: creator.to_string(),
+            quantity: None,
+            royalty_info: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &creator).len(), 1);
+
+    // The holder token grants two mints at the whitelist price
+    app.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint { quantity: Some(2) },
+        &coins(WHITELIST_PRICE * 2, NATIVE_DENOM),
+    )
+    .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &buyer).len(), 2);
+    let res: ClaimsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &whitelist_addr,
+            &WhitelistQueryMsg::Claims {
+                collection: holder_collection_addr.to_string(),
+                token_id: holder_token_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!((res.claimed, res.remaining), (2, 0));
+    let res: Option<ClaimsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &whitelist_addr,
+            &WhitelistQueryMsg::ClaimToken {
+                member: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.unwrap().token_id, holder_token_id);
+
+    // Its claims are used up, even though the per address limit isn't reached
+    assert!(!has_member(&app, &whitelist_addr, &buyer));
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(WHITELIST_PRICE, NATIVE_DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        format!("address not on whitelist: {}", buyer)
+    );
+
+    // Only the minter can consume claims
+    let err = app
+        .execute_contract(
+            buyer.clone(),
+            whitelist_addr,
+            &WhitelistExecuteMsg::PreMintHook(PreMintHookMsg::new(
+                collection_addr.to_string(),
+                None,
+                buyer.to_string(),
+            )),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    // Public mints don't need a holder token once the gate ended
+    setup_block_time(&mut app, MINTER_START, None);
+    app.execute_contract(
+        creator.clone(),
+        minter_addr,
+        &ExecuteMsg::Mint { quantity: None },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    )
+    .unwrap();
+    assert_eq!(owned_tokens(&app, &collection_addr, &creator).len(), 2);
+}
@@ -32,7 +32,7 @@ fn before_start_time() {
     assert!(res.is_err());
 
     // Buyer can't mint before start_time
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -55,7 +55,7 @@ fn before_start_time() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 10_000_000, None);
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -1,8 +1,12 @@
-use cosmwasm_std::{coins, Empty};
+use cosmwasm_std::{coins, Addr, Empty};
 use cw721::{Cw721QueryMsg, NftInfoResponse, TokensResponse};
 use cw721_base::Extension;
 use cw_multi_test::Executor;
-use sg721_updatable::msg::ExecuteMsg as Sg721UpdatableExecMsg;
+use cw_utils::Expiration;
+use sg721_updatable::msg::{
+    ExecuteMsg as Sg721UpdatableExecMsg, FrozenTokenMetadataResponse, FrozenTokensResponse,
+    MetadataUpdaterResponse, QueryMsg as Sg721UpdatableQueryMsg, UpdatableExtensionMsg,
+};
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 use vending_minter::msg::ExecuteMsg;
 
@@ -23,7 +27,7 @@ fn update_token_metadata() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 10_000_000, None);
 
     // mint token
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr,
@@ -66,3 +70,194 @@ fn update_token_metadata() {
         .unwrap();
     assert_eq!(res.token_uri, token_uri);
 }
+
+#[test]
+fn update_token_metadata_through_extension() {
+    let vt = vending_minter_with_sg721_updatable(10);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 10_000_000, None);
+
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr,
+        &mint_msg,
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let token_id = res.tokens[0].to_string();
+
+    // update token metadata wrapped in the generic sg721 extension message
+    let token_uri = Some("ipfs://new_token_uri".to_string());
+    let msg = sg721::ExecuteMsg::<Empty, UpdatableExtensionMsg>::Extension {
+        msg: UpdatableExtensionMsg::UpdateTokenMetadata {
+            token_id: token_id.clone(),
+            token_uri: token_uri.clone(),
+        },
+    };
+    let res = router.execute_contract(buyer, collection_addr.clone(), &msg, &[]);
+    assert!(res.is_err());
+    let res = router.execute_contract(creator, collection_addr.clone(), &msg, &[]);
+    assert!(res.is_ok());
+
+    let res: NftInfoResponse<Extension> = router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Cw721QueryMsg::NftInfo { token_id })
+        .unwrap();
+    assert_eq!(res.token_uri, token_uri);
+}
+
+#[test]
+fn metadata_updater_and_token_freeze() {
+    let vt = vending_minter_with_sg721_updatable(10);
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let updater = Addr::unchecked("reveal_bot");
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 10_000_000, None);
+
+    for _ in 0..2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint { quantity: None },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+    let res: TokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Cw721QueryMsg::Tokens {
+                owner: buyer.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let (granted, other) = (res.tokens[0].clone(), res.tokens[1].clone());
+
+    // only the creator can grant an updater
+    let set_updater = Sg721UpdatableExecMsg::<Empty, Empty>::SetMetadataUpdater {
+        updater: updater.to_string(),
+        token_ids: Some(vec![granted.clone()]),
+        expires: Some(Expiration::AtTime(
+            router.block_info().time.plus_seconds(1_000),
+        )),
+    };
+    let res = router.execute_contract(buyer.clone(), collection_addr.clone(), &set_updater, &[]);
+    assert!(res.is_err());
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &set_updater, &[]);
+    assert!(res.is_ok());
+
+    let res: MetadataUpdaterResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721UpdatableQueryMsg::MetadataUpdater {},
+        )
+        .unwrap();
+    assert_eq!(res.updater.unwrap().address, updater);
+
+    // the updater can update granted tokens only
+    let update = |token_id: &str| Sg721UpdatableExecMsg::<Empty, Empty>::UpdateTokenMetadata {
+        token_id: token_id.to_string(),
+        token_uri: Some("ipfs://revealed".to_string()),
+    };
+    let res = router.execute_contract(
+        updater.clone(),
+        collection_addr.clone(),
+        &update(&granted),
+        &[],
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        updater.clone(),
+        collection_addr.clone(),
+        &update(&other),
+        &[],
+    );
+    assert!(res.is_err());
+
+    // frozen tokens can't be updated, other tokens still can
+    let freeze = Sg721UpdatableExecMsg::<Empty, Empty>::FreezeTokenMetadata {
+        token_ids: Some(vec![granted.clone()]),
+    };
+    let res = router.execute_contract(updater.clone(), collection_addr.clone(), &freeze, &[]);
+    assert!(res.is_err());
+    let res = router.execute_contract(creator.clone(), collection_addr.clone(), &freeze, &[]);
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        creator.clone(),
+        collection_addr.clone(),
+        &update(&granted),
+        &[],
+    );
+    assert!(res.is_err());
+    let res = router.execute_contract(
+        creator.clone(),
+        collection_addr.clone(),
+        &update(&other),
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: FrozenTokensResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721UpdatableQueryMsg::FrozenTokens {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens, vec![granted.clone()]);
+    let res: FrozenTokenMetadataResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr.clone(),
+            &Sg721UpdatableQueryMsg::FreezeTokenMetadata { token_id: None },
+        )
+        .unwrap();
+    assert!(!res.frozen);
+
+    // the grant stops working once expired
+    let set_updater = Sg721UpdatableExecMsg::<Empty, Empty>::SetMetadataUpdater {
+        updater: updater.to_string(),
+        token_ids: None,
+        expires: Some(Expiration::AtTime(
+            router.block_info().time.plus_seconds(1_000),
+        )),
+    };
+    let res = router.execute_contract(creator, collection_addr.clone(), &set_updater, &[]);
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        updater.clone(),
+        collection_addr.clone(),
+        &update(&other),
+        &[],
+    );
+    assert!(res.is_ok());
+    let expired = router.block_info().time.plus_seconds(1_001);
+    setup_block_time(&mut router, expired.nanos(), None);
+    let res = router.execute_contract(updater, collection_addr, &update(&other), &[]);
+    assert!(res.is_err());
+}
@@ -49,6 +49,10 @@ fn invalid_whitelist_instantiate() {
         mint_price: coin(MIN_MINT_PRICE, NATIVE_DENOM),
         per_address_limit: 3,
         whitelist: Some("invalid address".to_string()),
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
@@ -203,7 +207,7 @@ fn whitelist_mint_count_query() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME, Some(10));
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -226,7 +230,7 @@ fn whitelist_mint_count_query() {
     assert_eq!(res.address, buyer.to_string());
 
     // Mint fails, over whitelist per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let err = router
         .execute_contract(
             buyer.clone(),
@@ -244,7 +248,7 @@ fn whitelist_mint_count_query() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 20_000, Some(11));
 
     // Public mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -293,7 +297,7 @@ fn whitelist_mint_count_query() {
     assert!(res.is_ok());
 
     // Mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -316,7 +320,7 @@ fn whitelist_mint_count_query() {
     assert_eq!(res.address, buyer.to_string());
 
     // Public Mint
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let _res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -453,7 +457,7 @@ fn whitelist_access_len_add_remove_expiration() {
     );
 
     // Mint fails, buyer is not on whitelist
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -471,7 +475,7 @@ fn whitelist_access_len_add_remove_expiration() {
     assert!(res.is_ok());
 
     // Mint fails, not whitelist price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     router
         .execute_contract(
             buyer.clone(),
@@ -503,7 +507,7 @@ fn whitelist_access_len_add_remove_expiration() {
     );
 
     // Mint succeeds with whitelist price
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer.clone(),
         minter_addr.clone(),
@@ -513,7 +517,7 @@ fn whitelist_access_len_add_remove_expiration() {
     assert!(res.is_ok());
 
     // Mint fails, over whitelist per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let err = router
         .execute_contract(
             buyer.clone(),
@@ -541,7 +545,7 @@ fn whitelist_access_len_add_remove_expiration() {
     assert!(res.is_ok());
 
     // Mint fails, buyer exceeded per address limit
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let err = router
         .execute_contract(
             buyer.clone(),
@@ -562,7 +566,7 @@ fn whitelist_access_len_add_remove_expiration() {
     assert!(res.is_ok());
 
     // Mint fails
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(
         buyer,
         minter_addr,
@@ -36,6 +36,10 @@ fn zero_mint_price() {
         mint_price: coin(MINT_PRICE, NATIVE_DENOM),
         per_address_limit: 1,
         whitelist: Some("invalid address".to_string()),
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
@@ -91,7 +95,7 @@ fn zero_mint_price() {
     // Mint succeeds
     let minter_addr = Addr::unchecked("contract1");
     let sg721 = Addr::unchecked("contract2");
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer.clone(), minter_addr, &mint_msg, &[]);
     assert!(res.is_ok());
 
@@ -126,6 +130,10 @@ fn zero_wl_mint_price() {
         mint_price: coin(MINT_PRICE, NATIVE_DENOM),
         per_address_limit: 1,
         whitelist: Some("invalid address".to_string()),
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
@@ -201,7 +209,7 @@ fn zero_wl_mint_price() {
     setup_block_time(router, GENESIS_MINT_START_TIME + 100, None);
 
     // mint succeeds
-    let mint_msg = ExecuteMsg::Mint {};
+    let mint_msg = ExecuteMsg::Mint { quantity: None };
     let res = router.execute_contract(buyer, minter_addr, &mint_msg, &[]);
     assert!(res.is_ok());
 }
@@ -223,6 +231,10 @@ fn zero_wl_mint_errs_with_min_mint_factory() {
         mint_price: coin(min_mint_price, NATIVE_DENOM),
         per_address_limit: 1,
         whitelist: None,
+        dutch_auction: None,
+        additional_mint_prices: vec![],
+        randomness: Default::default(),
+        escrow: None,
     };
 
     let minter_params = minter_params_all(num_tokens, None, None, Some(init_msg));
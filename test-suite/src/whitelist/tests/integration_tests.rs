@@ -1,4 +1,4 @@
-use cosmwasm_std::{coin, coins, Addr, Timestamp};
+use cosmwasm_std::{coin, coins, to_json_binary, Addr, Timestamp, WasmMsg};
 use cw_multi_test::{BankSudo, Executor, SudoMsg as CWSudoMsg};
 
 use sg_utils::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
@@ -8,7 +8,10 @@ use sg_whitelist::{
     state::AdminList,
 };
 
-use crate::common_setup::contract_boxes::{contract_collection_whitelist, custom_mock_app, App};
+use crate::common_setup::contract_boxes::{
+    contract_collection_whitelist, contract_vending_factory, custom_mock_app, App,
+};
+use crate::common_setup::setup_minter::vending_minter::mock_params::mock_params;
 
 const COLLECTION_WHITELIST_ADDR: &str = "contract0";
 const ADMIN: &str = "admin";
@@ -32,6 +35,7 @@ fn instantiate_contract(admin_account: &str, app: &mut App) {
         member_limit: 1000,
         admins: vec![ADMIN.to_string(), SECOND_ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     app.sudo(CWSudoMsg::Bank({
         BankSudo::Mint {
@@ -257,3 +261,62 @@ fn test_freeze_admins() {
     );
     assert_eq!(res.unwrap_err().root_cause().to_string(), "Unauthorized");
 }
+
+#[test]
+fn test_fee_routing_from_factory() {
+    let mut app = custom_mock_app();
+    let factory_id = app.store_code(contract_vending_factory());
+    let mut params = mock_params(None);
+    params.fee_routing.burn_percent = 80;
+    let factory = app
+        .instantiate_contract(
+            factory_id,
+            Addr::unchecked(ADMIN),
+            &vending_factory::msg::InstantiateMsg { params },
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let creation_fee = 100_000_000;
+    app.sudo(CWSudoMsg::Bank(BankSudo::Mint {
+        to_address: ADMIN.to_string(),
+        amount: coins(creation_fee, NATIVE_DENOM),
+    }))
+    .unwrap();
+    let msg = InstantiateMsg {
+        members: vec![],
+        start_time: GENESIS_START_TIME,
+        end_time: END_TIME,
+        mint_price: coin(UNIT_AMOUNT, NATIVE_DENOM),
+        per_address_limit: 1,
+        member_limit: 1000,
+        admins: vec![ADMIN.to_string()],
+        admins_mutable: true,
+        factory: Some(factory.to_string()),
+    };
+    let whitelist_id = app.store_code(contract_collection_whitelist());
+    let res = app
+        .execute(
+            Addr::unchecked(ADMIN),
+            WasmMsg::Instantiate {
+                admin: None,
+                code_id: whitelist_id,
+                msg: to_json_binary(&msg).unwrap(),
+                funds: coins(creation_fee, NATIVE_DENOM),
+                label: "collection_whitelist".to_string(),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    // The creation fee is burned at the factory's rate instead of the default 50%
+    let burn_amount = res
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-fair-burn")
+        .and_then(|e| e.attributes.iter().find(|a| a.key == "burn_amount"))
+        .unwrap();
+    assert_eq!(burn_amount.value, (creation_fee * 4 / 5).to_string());
+}
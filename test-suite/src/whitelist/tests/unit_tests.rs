@@ -32,6 +32,7 @@ fn setup_contract(deps: DepsMut) {
         member_limit: 1000,
         admins: vec![ADMIN.to_string(), SECOND_ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let res = instantiate(deps, mock_env(), info, msg).unwrap();
@@ -56,6 +57,7 @@ fn not_ustars_denom() {
         member_limit: 1000,
         admins: vec![ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let res = instantiate(deps.as_mut(), mock_env(), info, msg);
@@ -74,6 +76,7 @@ fn improper_initialization_invalid_creation_fee() {
         member_limit: 3000,
         admins: vec![ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
@@ -99,6 +102,7 @@ fn improper_initialization_dedup() {
         member_limit: 1000,
         admins: vec![ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -117,6 +121,7 @@ fn check_start_time_after_end_time() {
         member_limit: 1000,
         admins: vec![ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let mut deps = mock_dependencies();
@@ -286,6 +291,7 @@ fn query_members_pagination() {
         member_limit: 1000,
         admins: vec![ADMIN.to_string()],
         admins_mutable: true,
+        factory: None,
     };
     let info = mock_info(ADMIN, &[coin(100_000_000, "ustars")]);
     let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -68,6 +68,7 @@ mod tests {
             per_address_limit,
             merkle_root,
             merkle_tree_uri: None,
+            factory: None,
         };
         let wl_id = app.store_code(contract_whitelist_merkletree());
         app.instantiate_contract(